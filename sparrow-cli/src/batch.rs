@@ -0,0 +1,48 @@
+//! Non-interactive modes: one-shot command and commands read from stdin.
+
+use crate::cli::Config;
+use crate::connection::Connection;
+use crate::errors::Result;
+use crate::format::format_data;
+use crate::input::{split_line, to_command};
+use sparrow_resp::Data;
+use std::io::BufRead;
+
+/// Run a single command and print its reply.
+///
+/// Return `false` if the reply is an error.
+///
+/// # Arguments
+/// * `config` - [Config] holding the Sparrow instance address and the command to run
+pub async fn run_command(config: &Config) -> Result<bool> {
+  let mut connection = Connection::connect(&config.address()).await?;
+  let args = config
+    .command
+    .iter()
+    .map(|arg| arg.as_bytes().to_vec())
+    .collect();
+  let reply = connection.request(&to_command(args)).await?;
+  println!("{}", format_data(&reply));
+  Ok(!matches!(reply, Data::Error(_)))
+}
+
+/// Run the commands read line by line from stdin and print their replies.
+///
+/// Each command waits for the previous reply. Return `false` if any reply is an error.
+///
+/// # Arguments
+/// * `config` - [Config] holding the Sparrow instance address
+pub async fn run_stdin(config: &Config) -> Result<bool> {
+  let mut connection = Connection::connect(&config.address()).await?;
+  let mut success = true;
+  for line in std::io::stdin().lock().lines() {
+    let args = split_line(&line?)?;
+    if args.is_empty() {
+      continue;
+    }
+    let reply = connection.request(&to_command(args)).await?;
+    println!("{}", format_data(&reply));
+    success &= !matches!(reply, Data::Error(_));
+  }
+  Ok(success)
+}
@@ -0,0 +1,110 @@
+//! Command Line Interface parameters.
+
+use crate::errors::Result;
+use getopts::{Options, ParsingStyle};
+use std::env;
+
+/// Default host of the Sparrow instance to connect to.
+const DEFAULT_HOST: &str = "127.0.0.1";
+/// Default port of the Sparrow instance to connect to.
+const DEFAULT_PORT: u16 = 3000;
+
+/// Config holding the values used to connect to a Sparrow instance.
+#[derive(Debug)]
+pub struct Config {
+  /// Host of the Sparrow instance.
+  pub host: String,
+  /// TCP port of the Sparrow instance.
+  pub port: u16,
+  /// Stream raw RESP data read from stdin.
+  pub pipe: bool,
+  /// Command to run before exiting, empty to read commands from stdin or the prompt.
+  pub command: Vec<String>,
+}
+
+impl Config {
+  /// Return the `host:port` address of the Sparrow instance.
+  ///
+  /// IPv6 hosts are enclosed in brackets, e.g. `[::1]:3000`.
+  pub fn address(&self) -> String {
+    if self.host.contains(':') && !self.host.starts_with('[') {
+      format!("[{}]:{}", self.host, self.port)
+    } else {
+      format!("{}:{}", self.host, self.port)
+    }
+  }
+}
+
+/// Parse the CLI parameters.
+///
+/// Return [None] if the `help` flag is present, after printing the help message.
+pub fn parse_cli() -> Result<Option<Config>> {
+  let args: Vec<String> = env::args().collect();
+  let program = args[0].clone();
+  let opts = get_opts();
+  let matches = opts.parse(&args[1..])?;
+
+  if matches.opt_present("help") {
+    let brief = format!("Usage: {} [options] [command [arg ...]]", program);
+    print!("{}", opts.usage(&brief));
+    return Ok(None);
+  }
+
+  let host = matches
+    .opt_str("host")
+    .unwrap_or_else(|| DEFAULT_HOST.to_string());
+  let port = match matches.opt_str("port") {
+    Some(port) => port.parse()?,
+    None => DEFAULT_PORT,
+  };
+  Ok(Some(Config {
+    host,
+    port,
+    pipe: matches.opt_present("pipe"),
+    command: matches.free,
+  }))
+}
+
+/// Return [Options] used to parse CLI parameters.
+///
+/// Options are only parsed before the command, so that its arguments may start with `-`.
+///
+/// [Options]: getopts::Options
+fn get_opts() -> Options {
+  let mut opts = Options::new();
+  opts.parsing_style(ParsingStyle::StopAtFirstFree);
+  opts.optopt(
+    "",
+    "host",
+    &format!("set server hostname (default: {})", DEFAULT_HOST),
+    "HOST",
+  );
+  opts.optopt(
+    "p",
+    "port",
+    &format!("set server port (default: {})", DEFAULT_PORT),
+    "PORT",
+  );
+  opts.optflag(
+    "",
+    "pipe",
+    "transfer raw RESP data from stdin to the server, pipelining requests",
+  );
+  opts.optflag("h", "help", "display this message");
+  opts
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::cli::get_opts;
+
+  #[test]
+  fn test_get_opts_negative_argument() {
+    let matches = get_opts()
+      .parse(["-p", "3001", "LRANGE", "list", "0", "-1", "--pipe"])
+      .unwrap();
+    assert_eq!(matches.opt_str("port"), Some("3001".to_string()));
+    assert!(!matches.opt_present("pipe"));
+    assert_eq!(matches.free, vec!["LRANGE", "list", "0", "-1", "--pipe"]);
+  }
+}
@@ -0,0 +1,44 @@
+//! TCP connection to a Sparrow instance.
+
+use crate::errors::Result;
+use async_std::io::{BufReader, BufWriter};
+use async_std::net::TcpStream;
+use async_std::prelude::*;
+use sparrow_resp::{decode, encode, Data};
+
+/// Connection sending commands to a Sparrow instance and reading its replies.
+pub struct Connection {
+  reader: BufReader<TcpStream>,
+  writer: BufWriter<TcpStream>,
+}
+
+impl Connection {
+  /// Connect to a Sparrow instance.
+  ///
+  /// # Arguments
+  /// * `address` - `host:port` address of the Sparrow instance
+  pub async fn connect(address: &str) -> Result<Connection> {
+    let stream = TcpStream::connect(address).await?;
+    Ok(Connection {
+      reader: BufReader::new(stream.clone()),
+      writer: BufWriter::new(stream),
+    })
+  }
+
+  /// Send a command and wait for its reply.
+  ///
+  /// # Arguments
+  /// * `data` - [Data] encoding the command
+  pub async fn request(&mut self, data: &Data) -> Result<Data> {
+    encode(data, &mut self.writer).await?;
+    self.writer.flush().await?;
+    Ok(decode(&mut self.reader).await?)
+  }
+
+  /// Split the connection into its reading and writing halves.
+  ///
+  /// Used to pipeline requests: commands are written without waiting for the previous replies.
+  pub fn into_split(self) -> (BufReader<TcpStream>, BufWriter<TcpStream>) {
+    (self.reader, self.writer)
+  }
+}
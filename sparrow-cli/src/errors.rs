@@ -0,0 +1,4 @@
+//! Error handling utilities for Sparrow CLI.
+
+/// Generic type used to type Result across Sparrow CLI codebase.
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync + 'static>>;
@@ -0,0 +1,120 @@
+//! Human readable formatting of Sparrow replies, similar to `redis-cli`.
+
+use sparrow_resp::Data;
+
+/// Format a reply [Data] to be displayed to the user.
+///
+/// # Examples
+/// ```text
+/// Data::Null                  => (nil)
+/// Data::Integer(3)            => (integer) 3
+/// Data::BulkString("value")   => "value"
+/// Data::Array([a, b])         => 1) "a"
+///                                2) "b"
+/// ```
+pub fn format_data(data: &Data) -> String {
+  format_indented(data, 0)
+}
+
+/// Format a [Data], indenting the lines following the first one.
+///
+/// # Arguments
+/// * `data` - [Data] to format
+/// * `indent` - Number of spaces prefixing every line but the first one
+fn format_indented(data: &Data, indent: usize) -> String {
+  match data {
+    Data::Array(array) if array.is_empty() => "(empty array)".to_string(),
+    Data::Array(array) => {
+      let width = array.len().to_string().len();
+      array
+        .iter()
+        .enumerate()
+        .map(|(i, data)| {
+          let prefix = format!("{:>width$}) ", i + 1, width = width);
+          let item = format_indented(data, indent + prefix.len());
+          match i {
+            0 => format!("{}{}", prefix, item),
+            _ => format!("{}{}{}", " ".repeat(indent), prefix, item),
+          }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+    }
+    Data::BulkString(bytes) => quote(bytes),
+    Data::Error(err) => format!("(error) {}", err),
+    Data::Integer(integer) => format!("(integer) {}", integer),
+    Data::Null | Data::NullArray => "(nil)".to_string(),
+    Data::SimpleString(string) => string.clone(),
+  }
+}
+
+/// Quote raw bytes, escaping non-printable characters.
+fn quote(bytes: &[u8]) -> String {
+  let mut quoted = String::from("\"");
+  for byte in bytes {
+    match byte {
+      b'\\' => quoted.push_str("\\\\"),
+      b'"' => quoted.push_str("\\\""),
+      b'\n' => quoted.push_str("\\n"),
+      b'\r' => quoted.push_str("\\r"),
+      b'\t' => quoted.push_str("\\t"),
+      0x07 => quoted.push_str("\\a"),
+      0x08 => quoted.push_str("\\b"),
+      byte if byte.is_ascii_graphic() || *byte == b' ' => quoted.push(*byte as char),
+      byte => quoted.push_str(&format!("\\x{:02x}", byte)),
+    }
+  }
+  quoted.push('"');
+  quoted
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::format::format_data;
+  use sparrow_resp::Data;
+
+  #[test]
+  fn test_format_data_scalars() {
+    assert_eq!(format_data(&Data::Null), "(nil)");
+    assert_eq!(format_data(&Data::NullArray), "(nil)");
+    assert_eq!(format_data(&Data::Integer(3)), "(integer) 3");
+    assert_eq!(format_data(&Data::SimpleString("OK".to_string())), "OK");
+    assert_eq!(
+      format_data(&Data::Error("Command not found: TOTO".to_string())),
+      "(error) Command not found: TOTO"
+    );
+  }
+
+  #[test]
+  fn test_format_data_bulk_string() {
+    assert_eq!(
+      format_data(&Data::BulkString("some value".into())),
+      "\"some value\""
+    );
+    assert_eq!(
+      format_data(&Data::BulkString(b"a\"b\n\x00\xff".to_vec())),
+      "\"a\\\"b\\n\\x00\\xff\""
+    );
+  }
+
+  #[test]
+  fn test_format_data_array() {
+    assert_eq!(format_data(&Data::Array(vec![])), "(empty array)");
+    assert_eq!(
+      format_data(&Data::Array(vec![
+        Data::BulkString("a".into()),
+        Data::Array(vec![Data::Integer(1), Data::Null]),
+        Data::BulkString("c".into()),
+      ])),
+      "1) \"a\"\n2) 1) (integer) 1\n   2) (nil)\n3) \"c\""
+    );
+  }
+
+  #[test]
+  fn test_format_data_array_alignment() {
+    let array = Data::Array((0..10).map(Data::Integer).collect());
+    let formatted = format_data(&array);
+    assert!(formatted.starts_with(" 1) (integer) 0\n"));
+    assert!(formatted.ends_with("\n10) (integer) 9"));
+  }
+}
@@ -0,0 +1,144 @@
+//! Parsing of commands typed by the user.
+
+use crate::errors::Result;
+use sparrow_resp::Data;
+
+/// Split a typed line into command arguments.
+///
+/// Arguments are separated by whitespaces. Like `redis-cli`, arguments can be quoted:
+/// - Double quotes support the escape sequences `\n`, `\r`, `\t`, `\b`, `\a`, `\\`, `\"` and `\xHH`.
+/// - Single quotes only support `\'`.
+///
+/// # Arguments
+/// * `line` - Line typed by the user
+pub fn split_line(line: &str) -> Result<Vec<Vec<u8>>> {
+  let mut args = vec![];
+  let mut chars = line.chars().peekable();
+  loop {
+    // Skip whitespaces between arguments
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+      chars.next();
+    }
+    if chars.peek().is_none() {
+      return Ok(args);
+    }
+
+    let mut arg = Vec::new();
+    let mut quote = None;
+    loop {
+      let c = match chars.next() {
+        Some(c) => c,
+        None if quote.is_some() => return Err("Invalid argument(s): unbalanced quotes".into()),
+        None => break,
+      };
+      match (quote, c) {
+        (None, c) if c.is_whitespace() => break,
+        (None, '"') | (None, '\'') if arg.is_empty() => quote = Some(c),
+        (Some('"'), '\\') => match chars.next() {
+          Some('n') => arg.push(b'\n'),
+          Some('r') => arg.push(b'\r'),
+          Some('t') => arg.push(b'\t'),
+          Some('b') => arg.push(0x08),
+          Some('a') => arg.push(0x07),
+          Some('x') => {
+            let hex: String = chars.by_ref().take(2).collect();
+            match u8::from_str_radix(&hex, 16) {
+              Ok(byte) if hex.len() == 2 => arg.push(byte),
+              _ => {
+                arg.extend_from_slice(b"\\x");
+                arg.extend_from_slice(hex.as_bytes());
+              }
+            }
+          }
+          Some(c) => push_char(&mut arg, c),
+          None => return Err("Invalid argument(s): unbalanced quotes".into()),
+        },
+        (Some('\''), '\\') if chars.peek() == Some(&'\'') => {
+          chars.next();
+          arg.push(b'\'');
+        }
+        (Some(q), c) if c == q => {
+          // Closing quote must be followed by a space or nothing
+          if chars.peek().is_some_and(|c| !c.is_whitespace()) {
+            return Err("Invalid argument(s): closing quote must be followed by a space".into());
+          }
+          break;
+        }
+        (_, c) => push_char(&mut arg, c),
+      }
+    }
+    args.push(arg);
+  }
+}
+
+/// Push the UTF-8 bytes of a char.
+fn push_char(bytes: &mut Vec<u8>, c: char) {
+  let mut buffer = [0; 4];
+  bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+}
+
+/// Build the [Data] sent to Sparrow from command arguments.
+///
+/// # Arguments
+/// * `args` - Command name followed by its arguments
+pub fn to_command(args: Vec<Vec<u8>>) -> Data {
+  Data::Array(args.into_iter().map(Data::BulkString).collect())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::input::{split_line, to_command};
+  use sparrow_resp::Data;
+
+  fn split(line: &str) -> Vec<String> {
+    split_line(line)
+      .unwrap()
+      .into_iter()
+      .map(|arg| String::from_utf8(arg).unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn test_split_line() {
+    assert_eq!(split("  SET   key value "), vec!["SET", "key", "value"]);
+    assert!(split("   ").is_empty());
+  }
+
+  #[test]
+  fn test_split_line_quotes() {
+    assert_eq!(
+      split(r#"SET "my key" 'some value'"#),
+      vec!["SET", "my key", "some value"]
+    );
+    assert_eq!(split(r#"SET key """#), vec!["SET", "key", ""]);
+    assert_eq!(
+      split(r#"SET key "a\"b\n" 'c\'d'"#),
+      vec!["SET", "key", "a\"b\n", "c'd"]
+    );
+  }
+
+  #[test]
+  fn test_split_line_hex() {
+    assert_eq!(
+      split_line(r#"SET key "\x00\xff""#).unwrap()[2],
+      vec![0x00, 0xff]
+    );
+  }
+
+  #[test]
+  fn test_split_line_invalid() {
+    assert!(split_line(r#"SET "key"#).is_err());
+    assert!(split_line(r#"SET "key"value"#).is_err());
+  }
+
+  #[test]
+  fn test_to_command() {
+    assert_eq!(
+      to_command(vec![b"GET".to_vec(), b"key".to_vec()]),
+      Data::Array(vec![
+        Data::BulkString("GET".into()),
+        Data::BulkString("key".into())
+      ])
+    );
+  }
+}
@@ -1,3 +1,72 @@
+//! Sparrow CLI is the command line client of Sparrow.
+//!
+//! # Usage
+//!
+//! Sparrow CLI connects to a Sparrow instance over TCP and runs an interactive prompt:
+//! typed commands are encoded using [sparrow_resp] and replies are pretty-printed
+//! the same way `redis-cli` does.
+//!
+//! ```text
+//! $ sparrow-cli --host 127.0.0.1 --port 3000
+//! 127.0.0.1:3000> SET key "some value"
+//! OK
+//! 127.0.0.1:3000> GET key
+//! "some value"
+//! ```
+//!
+//! Commands can also be run without the prompt:
+//! - `sparrow-cli GET key` runs a single command and exits with status 1 on error replies.
+//! - `sparrow-cli < commands.txt` runs the commands read line by line from stdin.
+//! - `sparrow-cli --pipe < commands.resp` streams raw RESP data, pipelining requests.
+mod batch;
+mod cli;
+mod connection;
+mod errors;
+mod format;
+mod input;
+mod pipe;
+mod repl;
+
+use crate::batch::{run_command, run_stdin};
+use crate::cli::{parse_cli, Config};
+use crate::errors::Result;
+use crate::pipe::run_pipe;
+use crate::repl::run_repl;
+use async_std::task;
+use std::io::IsTerminal;
+
+/// Sparrow CLI entrypoint.
+///
+/// Run Sparrow CLI and handles propagated errors.
 fn main() {
-  println!("Hello Sparrow CLI");
+  match parse_cli() {
+    Ok(Some(config)) => match run(&config) {
+      Ok(true) => {}
+      Ok(false) => std::process::exit(1),
+      Err(err) => {
+        eprintln!("{}", err);
+        std::process::exit(1);
+      }
+    },
+    Ok(None) => {}
+    Err(err) => {
+      eprintln!("{}", err);
+      std::process::exit(1);
+    }
+  }
+}
+
+/// Run Sparrow CLI in the mode selected by the [Config].
+///
+/// Return `false` if the server replied with an error in a non-interactive mode.
+fn run(config: &Config) -> Result<bool> {
+  if config.pipe {
+    task::block_on(run_pipe(config))
+  } else if !config.command.is_empty() {
+    task::block_on(run_command(config))
+  } else if !std::io::stdin().is_terminal() {
+    task::block_on(run_stdin(config))
+  } else {
+    run_repl(config).map(|_| true)
+  }
 }
@@ -0,0 +1,56 @@
+//! Raw pipe mode streaming RESP data from stdin, used for bulk loading.
+
+use crate::cli::Config;
+use crate::connection::Connection;
+use crate::errors::Result;
+use async_std::channel::unbounded;
+use async_std::io::{stdin, BufReader};
+use async_std::prelude::*;
+use async_std::task;
+use sparrow_resp::{decode, encode, Data};
+use std::io::ErrorKind;
+
+/// Transfer the RESP data read from stdin to the server.
+///
+/// Requests are pipelined: they are written while replies are read concurrently.
+/// Error replies are printed to stderr and a summary is printed once every reply is received.
+/// Return `false` if any reply is an error.
+///
+/// # Arguments
+/// * `config` - [Config] holding the Sparrow instance address
+pub async fn run_pipe(config: &Config) -> Result<bool> {
+  let connection = Connection::connect(&config.address()).await?;
+  let (mut reader, mut writer) = connection.into_split();
+  // Every request sent is notified so that the reader knows how many replies to wait for
+  let (sender, receiver) = unbounded();
+
+  let write_task = task::spawn(async move {
+    let mut input = BufReader::new(stdin());
+    loop {
+      match decode(&mut input).await {
+        Ok(data) => {
+          encode(&data, &mut writer).await?;
+          sender.send(()).await?;
+        }
+        Err(err) if err.kind() == ErrorKind::BrokenPipe => break,
+        Err(err) => return Err(format!("Cannot decode input: {}", err).into()),
+      }
+    }
+    writer.flush().await?;
+    Result::Ok(())
+  });
+
+  let mut replies = 0;
+  let mut errors = 0;
+  while receiver.recv().await.is_ok() {
+    if let Data::Error(err) = decode(&mut reader).await? {
+      eprintln!("(error) {}", err);
+      errors += 1;
+    }
+    replies += 1;
+  }
+  write_task.await?;
+
+  println!("errors: {}, replies: {}", errors, replies);
+  Ok(errors == 0)
+}
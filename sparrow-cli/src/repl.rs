@@ -0,0 +1,93 @@
+//! Interactive Read-Eval-Print Loop.
+
+use crate::cli::Config;
+use crate::connection::Connection;
+use crate::errors::Result;
+use crate::format::format_data;
+use crate::input::{split_line, to_command};
+use async_std::task;
+use rustyline::error::ReadlineError;
+use rustyline::Editor;
+use std::env;
+use std::path::PathBuf;
+
+/// Name of the history file stored in the user home directory.
+const HISTORY_FILENAME: &str = ".sparrow_history";
+
+/// Run the interactive REPL until the user exits.
+///
+/// The connection is opened lazily and re-opened on the next command if it is lost.
+///
+/// # Arguments
+/// * `config` - [Config] holding the Sparrow instance address
+pub fn run_repl(config: &Config) -> Result<()> {
+  let address = config.address();
+  let history = history_filepath();
+  let mut editor = Editor::<()>::new();
+  if let Some(history) = &history {
+    // The history file does not exist on first run
+    let _ = editor.load_history(history);
+  }
+
+  let mut connection = task::block_on(Connection::connect(&address))
+    .map_err(|err| eprintln!("Could not connect to Sparrow at {}: {}", address, err))
+    .ok();
+
+  loop {
+    let prompt = match connection {
+      Some(_) => format!("{}> ", address),
+      None => "not connected> ".to_string(),
+    };
+    let line = match editor.readline(&prompt) {
+      Ok(line) => line,
+      Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+      Err(err) => return Err(err.into()),
+    };
+    if line.trim().is_empty() {
+      continue;
+    }
+    editor.add_history_entry(line.as_str());
+
+    let args = match split_line(&line) {
+      Ok(args) => args,
+      Err(err) => {
+        eprintln!("{}", err);
+        continue;
+      }
+    };
+    if let [name] = args.as_slice() {
+      if name.eq_ignore_ascii_case(b"quit") || name.eq_ignore_ascii_case(b"exit") {
+        break;
+      }
+    }
+
+    if connection.is_none() {
+      match task::block_on(Connection::connect(&address)) {
+        Ok(new_connection) => connection = Some(new_connection),
+        Err(err) => {
+          eprintln!("Could not connect to Sparrow at {}: {}", address, err);
+          continue;
+        }
+      }
+    }
+    if let Some(conn) = connection.as_mut() {
+      match task::block_on(conn.request(&to_command(args))) {
+        Ok(reply) => println!("{}", format_data(&reply)),
+        Err(err) => {
+          eprintln!("Connection to Sparrow lost: {}", err);
+          connection = None;
+        }
+      }
+    }
+  }
+
+  if let Some(history) = &history {
+    editor.save_history(history)?;
+  }
+  Ok(())
+}
+
+/// Return the history file path, if the user home directory is known.
+fn history_filepath() -> Option<PathBuf> {
+  env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILENAME))
+}
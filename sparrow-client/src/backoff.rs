@@ -0,0 +1,61 @@
+//! Reconnection policy.
+
+use std::time::Duration;
+
+/// Exponential backoff used when (re)connecting to the server.
+///
+/// The delay between two attempts starts at `initial_delay` and is doubled after every
+/// failed attempt, up to `max_delay`.
+#[derive(Clone, Debug)]
+pub struct Backoff {
+  /// Delay before the first retry.
+  pub initial_delay: Duration,
+  /// Upper bound of the delay between two attempts.
+  pub max_delay: Duration,
+  /// Number of retries after the first failed attempt.
+  pub max_retries: u32,
+}
+
+impl Default for Backoff {
+  fn default() -> Self {
+    Backoff {
+      initial_delay: Duration::from_millis(50),
+      max_delay: Duration::from_secs(5),
+      max_retries: 5,
+    }
+  }
+}
+
+impl Backoff {
+  /// Return the delay to wait before a given retry.
+  ///
+  /// # Arguments
+  /// * `retry` - Index of the retry, starting at 0
+  pub fn delay(&self, retry: u32) -> Duration {
+    let factor = 2u32.checked_pow(retry).unwrap_or(u32::MAX);
+    self
+      .initial_delay
+      .checked_mul(factor)
+      .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::backoff::Backoff;
+  use std::time::Duration;
+
+  #[test]
+  fn test_backoff_delay() {
+    let backoff = Backoff {
+      initial_delay: Duration::from_millis(100),
+      max_delay: Duration::from_secs(1),
+      max_retries: 10,
+    };
+    assert_eq!(backoff.delay(0), Duration::from_millis(100));
+    assert_eq!(backoff.delay(1), Duration::from_millis(200));
+    assert_eq!(backoff.delay(3), Duration::from_millis(800));
+    assert_eq!(backoff.delay(4), Duration::from_secs(1));
+    assert_eq!(backoff.delay(64), Duration::from_secs(1));
+  }
+}
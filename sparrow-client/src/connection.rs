@@ -0,0 +1,356 @@
+//! Asynchronous connection to a Sparrow instance.
+
+use crate::backoff::Backoff;
+use crate::error::{into_result, Error, Result};
+use crate::pipeline::{command, Pipeline};
+use async_std::io::{BufReader, BufWriter};
+use async_std::net::TcpStream;
+use async_std::prelude::*;
+use async_std::task;
+use sparrow_resp::{decode, encode, Data};
+
+/// Commands changing the state of a connection on the server: selected database,
+/// open transaction or watched keys.
+const SESSION_COMMANDS: [&[u8]; 3] = [b"SELECT", b"MULTI", b"WATCH"];
+
+/// Connection to a Sparrow instance.
+///
+/// If the connection is lost, the failing request returns an [Error::Io] and the next request
+/// reconnects following the [Backoff] policy. Failed requests are never re-sent as they might have
+/// been executed by the server.
+pub struct Connection {
+  address: String,
+  backoff: Backoff,
+  stream: Option<(BufReader<TcpStream>, BufWriter<TcpStream>)>,
+  /// Whether a command changing the state of the connection on the server has been sent.
+  session_changed: bool,
+}
+
+impl Connection {
+  /// Connect to a Sparrow instance using the default [Backoff] policy.
+  ///
+  /// # Arguments
+  /// * `address` - `host:port` address of the Sparrow instance
+  ///
+  /// # Examples
+  /// ```rust,no_run
+  /// # fn main() -> sparrow_client::Result<()> { async_std::task::block_on(async {
+  /// use sparrow_client::Connection;
+  ///
+  /// let mut connection = Connection::connect("127.0.0.1:3000").await?;
+  /// connection.set("key", "value").await?;
+  ///
+  /// assert_eq!(connection.get("key").await?, Some(b"value".to_vec()));
+  /// # Ok(()) }) }
+  /// ```
+  pub async fn connect(address: &str) -> Result<Connection> {
+    Connection::connect_with_backoff(address, Backoff::default()).await
+  }
+
+  /// Connect to a Sparrow instance using a given [Backoff] policy.
+  ///
+  /// # Arguments
+  /// * `address` - `host:port` address of the Sparrow instance
+  /// * `backoff` - Policy used to (re)connect to the instance
+  pub async fn connect_with_backoff(address: &str, backoff: Backoff) -> Result<Connection> {
+    let mut connection = Connection {
+      address: address.to_string(),
+      backoff,
+      stream: None,
+      session_changed: false,
+    };
+    connection.reconnect().await?;
+    Ok(connection)
+  }
+
+  /// Return `true` if the connection is currently open.
+  pub fn is_connected(&self) -> bool {
+    self.stream.is_some()
+  }
+
+  /// Return `true` if a command changing the state of the connection on the server
+  /// (`SELECT`, `MULTI` or `WATCH`) has been sent since the connection was opened.
+  pub fn has_session_state(&self) -> bool {
+    self.session_changed
+  }
+
+  /// Send a raw command and wait for its reply.
+  ///
+  /// # Arguments
+  /// * `args` - Command name followed by its arguments
+  pub async fn request<I, A>(&mut self, args: I) -> Result<Data>
+  where
+    I: IntoIterator<Item = A>,
+    A: AsRef<[u8]>,
+  {
+    let replies = self.send(&[command(args)]).await?;
+    into_result(replies.into_iter().next().unwrap_or(Data::Null))
+  }
+
+  /// Send every command of a [Pipeline] before reading their replies.
+  ///
+  /// Replies are returned in the order of the commands. [Data::Error] replies are returned as
+  /// [Error::Server] without failing the whole pipeline.
+  ///
+  /// # Arguments
+  /// * `pipeline` - [Pipeline] holding the commands to send
+  pub async fn pipeline(&mut self, pipeline: &Pipeline) -> Result<Vec<Result<Data>>> {
+    let replies = self.send(pipeline.commands()).await?;
+    Ok(replies.into_iter().map(into_result).collect())
+  }
+
+  /// Get the value of a key, [None] if the key does not exist.
+  pub async fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>> {
+    match self.request([&b"GET"[..], key.as_bytes()]).await? {
+      Data::BulkString(value) => Ok(Some(value)),
+      Data::Null => Ok(None),
+      data => Err(Error::UnexpectedReply(data)),
+    }
+  }
+
+  /// Set the value of a key, removing any previous expiration.
+  pub async fn set<V: AsRef<[u8]>>(&mut self, key: &str, value: V) -> Result<()> {
+    let reply = self
+      .request(&[&b"SET"[..], key.as_bytes(), value.as_ref()])
+      .await?;
+    expect_ok(reply)
+  }
+
+  /// Set the value of a key expiring after a given number of seconds.
+  pub async fn set_ex<V: AsRef<[u8]>>(&mut self, key: &str, value: V, seconds: u64) -> Result<()> {
+    let seconds = seconds.to_string();
+    let reply = self
+      .request([
+        &b"SET"[..],
+        key.as_bytes(),
+        value.as_ref(),
+        b"EX",
+        seconds.as_bytes(),
+      ])
+      .await?;
+    expect_ok(reply)
+  }
+
+  /// Remove a key.
+  ///
+  /// Return `false` if the key does not exist.
+  pub async fn rem(&mut self, key: &str) -> Result<bool> {
+    let reply = self.request([&b"REM"[..], key.as_bytes()]).await?;
+    expect_integer(reply).map(|integer| integer == 1)
+  }
+
+  /// Set a key to expire after a given number of seconds.
+  ///
+  /// Return `false` if the key does not exist.
+  pub async fn expire(&mut self, key: &str, seconds: i64) -> Result<bool> {
+    let seconds = seconds.to_string();
+    let reply = self
+      .request(&[&b"EXPIRE"[..], key.as_bytes(), seconds.as_bytes()])
+      .await?;
+    expect_integer(reply).map(|integer| integer == 1)
+  }
+
+  /// Set a key to expire after a given number of milliseconds.
+  ///
+  /// Return `false` if the key does not exist.
+  pub async fn pexpire(&mut self, key: &str, milliseconds: i64) -> Result<bool> {
+    let milliseconds = milliseconds.to_string();
+    let reply = self
+      .request(&[&b"PEXPIRE"[..], key.as_bytes(), milliseconds.as_bytes()])
+      .await?;
+    expect_integer(reply).map(|integer| integer == 1)
+  }
+
+  /// Return the remaining time to live of a key in seconds.
+  ///
+  /// Like the `TTL` command, return -2 if the key does not exist and -1 if it has no expiration.
+  pub async fn ttl(&mut self, key: &str) -> Result<i64> {
+    let reply = self.request([&b"TTL"[..], key.as_bytes()]).await?;
+    expect_integer(reply)
+  }
+
+  /// Remove the expiration of a key.
+  ///
+  /// Return `false` if the key does not exist or has no expiration.
+  pub async fn persist(&mut self, key: &str) -> Result<bool> {
+    let reply = self.request([&b"PERSIST"[..], key.as_bytes()]).await?;
+    expect_integer(reply).map(|integer| integer == 1)
+  }
+
+  /// Write commands, flush them at once and read their replies.
+  ///
+  /// The connection is reopened if needed, and dropped on I/O errors.
+  async fn send(&mut self, commands: &[Data]) -> Result<Vec<Data>> {
+    if self.stream.is_none() {
+      self.reconnect().await?;
+    }
+    self.session_changed |= commands.iter().any(changes_session);
+    let result = match self.stream.as_mut() {
+      Some((reader, writer)) => exchange(reader, writer, commands).await,
+      None => unreachable!(),
+    };
+    if result.is_err() {
+      self.stream = None;
+    }
+    Ok(result?)
+  }
+
+  /// Open the TCP connection, retrying following the [Backoff] policy.
+  async fn reconnect(&mut self) -> Result<()> {
+    let mut retry = 0;
+    loop {
+      match TcpStream::connect(&self.address).await {
+        Ok(stream) => {
+          self.stream = Some((BufReader::new(stream.clone()), BufWriter::new(stream)));
+          self.session_changed = false;
+          return Ok(());
+        }
+        Err(err) if retry >= self.backoff.max_retries => return Err(Error::Io(err)),
+        Err(_) => {
+          task::sleep(self.backoff.delay(retry)).await;
+          retry += 1;
+        }
+      }
+    }
+  }
+}
+
+/// Write commands, flush them at once and read their replies in order.
+async fn exchange(
+  reader: &mut BufReader<TcpStream>,
+  writer: &mut BufWriter<TcpStream>,
+  commands: &[Data],
+) -> std::io::Result<Vec<Data>> {
+  for command in commands {
+    encode(command, writer).await?;
+  }
+  writer.flush().await?;
+  let mut replies = Vec::with_capacity(commands.len());
+  for _ in commands {
+    replies.push(decode(reader).await?);
+  }
+  Ok(replies)
+}
+
+/// Return whether a command changes the state of the connection on the server.
+fn changes_session(command: &Data) -> bool {
+  let name = match command {
+    Data::Array(args) => args.first().and_then(Data::as_bytes),
+    _ => None,
+  };
+  name.is_some_and(|name| {
+    SESSION_COMMANDS
+      .iter()
+      .any(|session_command| name.eq_ignore_ascii_case(session_command))
+  })
+}
+
+/// Check that a reply is the `OK` simple string.
+fn expect_ok(reply: Data) -> Result<()> {
+  match reply {
+    Data::SimpleString(string) if string == "OK" => Ok(()),
+    data => Err(Error::UnexpectedReply(data)),
+  }
+}
+
+/// Check that a reply is an integer.
+fn expect_integer(reply: Data) -> Result<i64> {
+  match reply {
+    Data::Integer(integer) => Ok(integer),
+    data => Err(Error::UnexpectedReply(data)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::backoff::Backoff;
+  use crate::error::Error;
+  use crate::mock::run_mock_server;
+  use crate::{Connection, Pipeline};
+  use sparrow_resp::Data;
+  use std::time::Duration;
+
+  #[async_std::test]
+  async fn test_connection_commands() {
+    let address = run_mock_server().await;
+    let mut connection = Connection::connect(&address).await.unwrap();
+
+    assert_eq!(connection.get("key").await.unwrap(), None);
+    connection.set("key", "some value").await.unwrap();
+    assert_eq!(
+      connection.get("key").await.unwrap(),
+      Some(b"some value".to_vec())
+    );
+    assert_eq!(connection.ttl("key").await.unwrap(), -1);
+    assert!(connection.rem("key").await.unwrap());
+    assert_eq!(connection.get("key").await.unwrap(), None);
+    assert!(!connection.rem("key").await.unwrap());
+  }
+
+  #[async_std::test]
+  async fn test_connection_server_error() {
+    let address = run_mock_server().await;
+    let mut connection = Connection::connect(&address).await.unwrap();
+
+    match connection.request(["TOTO"]).await {
+      Err(Error::Server(err)) => assert_eq!(err, "Command not found: TOTO"),
+      other => panic!("Unexpected result: {:?}", other),
+    }
+    // The connection is still usable after an error reply
+    assert!(connection.is_connected());
+    assert_eq!(connection.get("key").await.unwrap(), None);
+  }
+
+  #[async_std::test]
+  async fn test_connection_pipeline() {
+    let address = run_mock_server().await;
+    let mut connection = Connection::connect(&address).await.unwrap();
+
+    let replies = connection
+      .pipeline(Pipeline::new().set("key", "value").cmd(["TOTO"]).get("key"))
+      .await
+      .unwrap();
+    assert_eq!(replies.len(), 3);
+    assert_eq!(
+      replies[0].as_ref().unwrap(),
+      &Data::SimpleString("OK".to_string())
+    );
+    assert!(matches!(replies[1], Err(Error::Server(_))));
+    assert_eq!(
+      replies[2].as_ref().unwrap(),
+      &Data::BulkString("value".into())
+    );
+  }
+
+  #[async_std::test]
+  async fn test_connection_reconnect() {
+    let address = run_mock_server().await;
+    let mut connection = Connection::connect(&address).await.unwrap();
+    connection.set("key", "value").await.unwrap();
+
+    // The server closes the connection without replying
+    assert!(matches!(
+      connection.request(["CLOSE"]).await,
+      Err(Error::Io(_))
+    ));
+    assert!(!connection.is_connected());
+
+    assert_eq!(
+      connection.get("key").await.unwrap(),
+      Some(b"value".to_vec())
+    );
+    assert!(connection.is_connected());
+  }
+
+  #[async_std::test]
+  async fn test_connection_refused() {
+    let backoff = Backoff {
+      initial_delay: Duration::from_millis(1),
+      max_delay: Duration::from_millis(1),
+      max_retries: 2,
+    };
+    // Nothing listens on the port 1
+    let result = Connection::connect_with_backoff("127.0.0.1:1", backoff).await;
+    assert!(matches!(result, Err(Error::Io(_))));
+  }
+}
@@ -0,0 +1,69 @@
+//! Error type returned by Sparrow client.
+
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Generic type used to type Result across Sparrow client codebase.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error returned by Sparrow client.
+#[derive(Debug)]
+pub enum Error {
+  /// The connection to the server failed.
+  Io(std::io::Error),
+  /// The server replied with a [Data::Error].
+  Server(String),
+  /// The server reply does not have the type expected by the command.
+  UnexpectedReply(Data),
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Error::Io(err) => write!(f, "Connection error: {}", err),
+      Error::Server(err) => write!(f, "Server error: {}", err),
+      Error::UnexpectedReply(data) => write!(f, "Unexpected reply: {:?}", data),
+    }
+  }
+}
+
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Error::Io(err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+impl From<std::io::Error> for Error {
+  fn from(err: std::io::Error) -> Self {
+    Error::Io(err)
+  }
+}
+
+/// Turn a [Data::Error] reply into an [Error::Server].
+///
+/// # Arguments
+/// * `data` - Reply sent by the server
+pub fn into_result(data: Data) -> Result<Data> {
+  match data {
+    Data::Error(err) => Err(Error::Server(err)),
+    data => Ok(data),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::error::{into_result, Error};
+  use sparrow_resp::Data;
+
+  #[test]
+  fn test_into_result() {
+    assert_eq!(into_result(Data::Integer(1)).unwrap(), Data::Integer(1));
+    match into_result(Data::Error("Command not found: TOTO".to_string())) {
+      Err(Error::Server(err)) => assert_eq!(err, "Command not found: TOTO"),
+      other => panic!("Unexpected result: {:?}", other),
+    }
+  }
+}
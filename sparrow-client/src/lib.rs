@@ -0,0 +1,34 @@
+//! Asynchronous Rust client of Sparrow, built on top of [sparrow_resp].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # fn main() -> sparrow_client::Result<()> { async_std::task::block_on(async {
+//! use sparrow_client::{Pipeline, Pool};
+//!
+//! let pool = Pool::new("127.0.0.1:3000", 16);
+//! let mut connection = pool.get().await?;
+//!
+//! connection.set("key", "value").await?;
+//! assert_eq!(connection.get("key").await?, Some(b"value".to_vec()));
+//!
+//! let replies = connection
+//!   .pipeline(Pipeline::new().set("other", "value").get("other"))
+//!   .await?;
+//! # Ok(()) }) }
+//! ```
+
+mod backoff;
+mod connection;
+mod error;
+#[cfg(test)]
+mod mock;
+mod pipeline;
+mod pool;
+
+pub use backoff::Backoff;
+pub use connection::Connection;
+pub use error::{Error, Result};
+pub use pipeline::Pipeline;
+pub use pool::{Pool, PooledConnection};
+pub use sparrow_resp::Data;
@@ -0,0 +1,68 @@
+//! In-memory mock of a Sparrow instance used by tests.
+
+use async_std::io::{BufReader, BufWriter};
+use async_std::net::{TcpListener, TcpStream};
+use async_std::prelude::*;
+use async_std::task;
+use sparrow_resp::{decode, encode, Data};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+type Store = Arc<Mutex<HashMap<Vec<u8>, Vec<u8>>>>;
+
+/// Run a mock server supporting `GET`, `SET`, `REM` and `TTL` and return its address.
+///
+/// The `CLOSE` command closes the connection without replying.
+pub async fn run_mock_server() -> String {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let address = listener.local_addr().unwrap().to_string();
+  let store = Store::default();
+  task::spawn(async move {
+    let mut incoming = listener.incoming();
+    while let Some(Ok(stream)) = incoming.next().await {
+      task::spawn(handle_connection(stream, store.clone()));
+    }
+  });
+  address
+}
+
+async fn handle_connection(stream: TcpStream, store: Store) {
+  let mut reader = BufReader::new(stream.clone());
+  let mut writer = BufWriter::new(stream);
+  while let Ok(Data::Array(array)) = decode(&mut reader).await {
+    let args: Vec<Vec<u8>> = array
+      .into_iter()
+      .filter_map(|data| data.as_bytes().map(|bytes| bytes.to_vec()))
+      .collect();
+    let reply = match execute(&args, &store) {
+      Some(reply) => reply,
+      None => return,
+    };
+    if encode(&reply, &mut writer).await.is_err() || writer.flush().await.is_err() {
+      return;
+    }
+  }
+}
+
+/// Execute a command, return [None] to close the connection.
+fn execute(args: &[Vec<u8>], store: &Store) -> Option<Data> {
+  let mut store = store.lock().unwrap();
+  let reply = match args {
+    [name] if name == b"CLOSE" => return None,
+    [name, key] if name == b"GET" => store
+      .get(key)
+      .map_or(Data::Null, |value| Data::BulkString(value.clone())),
+    [name, key, value, ..] if name == b"SET" => {
+      store.insert(key.clone(), value.clone());
+      Data::SimpleString("OK".to_string())
+    }
+    [name, key] if name == b"REM" => Data::Integer(store.remove(key).is_some() as i64),
+    [name, key] if name == b"TTL" => Data::Integer(if store.contains_key(key) { -1 } else { -2 }),
+    [name, ..] => Data::Error(format!(
+      "Command not found: {}",
+      String::from_utf8_lossy(name)
+    )),
+    [] => Data::Error("Cannot parse command: empty command".to_string()),
+  };
+  Some(reply)
+}
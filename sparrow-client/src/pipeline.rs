@@ -0,0 +1,110 @@
+//! Batch of commands sent at once.
+
+use sparrow_resp::Data;
+
+/// Build a [Data::Array] of [Data::BulkString] encoding a command.
+///
+/// # Arguments
+/// * `args` - Command name followed by its arguments
+pub fn command<I, A>(args: I) -> Data
+where
+  I: IntoIterator<Item = A>,
+  A: AsRef<[u8]>,
+{
+  Data::Array(
+    args
+      .into_iter()
+      .map(|arg| Data::BulkString(arg.as_ref().to_vec()))
+      .collect(),
+  )
+}
+
+/// Commands sent to the server without waiting for the previous replies.
+///
+/// See [Connection::pipeline].
+///
+/// [Connection::pipeline]: crate::Connection::pipeline
+#[derive(Clone, Debug, Default)]
+pub struct Pipeline {
+  commands: Vec<Data>,
+}
+
+impl Pipeline {
+  /// Return a new empty [Pipeline].
+  pub fn new() -> Pipeline {
+    Pipeline::default()
+  }
+
+  /// Queue a raw command.
+  ///
+  /// # Arguments
+  /// * `args` - Command name followed by its arguments
+  pub fn cmd<I, A>(&mut self, args: I) -> &mut Pipeline
+  where
+    I: IntoIterator<Item = A>,
+    A: AsRef<[u8]>,
+  {
+    self.commands.push(command(args));
+    self
+  }
+
+  /// Queue a `GET` command.
+  pub fn get(&mut self, key: &str) -> &mut Pipeline {
+    self.cmd([&b"GET"[..], key.as_bytes()])
+  }
+
+  /// Queue a `SET` command.
+  pub fn set<V: AsRef<[u8]>>(&mut self, key: &str, value: V) -> &mut Pipeline {
+    self.cmd([&b"SET"[..], key.as_bytes(), value.as_ref()])
+  }
+
+  /// Queue a `REM` command.
+  pub fn rem(&mut self, key: &str) -> &mut Pipeline {
+    self.cmd([&b"REM"[..], key.as_bytes()])
+  }
+
+  /// Return the queued commands.
+  pub fn commands(&self) -> &[Data] {
+    &self.commands
+  }
+
+  /// Return the number of queued commands.
+  pub fn len(&self) -> usize {
+    self.commands.len()
+  }
+
+  /// Return `true` if no command is queued.
+  pub fn is_empty(&self) -> bool {
+    self.commands.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::pipeline::Pipeline;
+  use sparrow_resp::Data;
+
+  #[test]
+  fn test_pipeline() {
+    let mut pipeline = Pipeline::new();
+    assert!(pipeline.is_empty());
+
+    pipeline.set("key", "value").get("key").cmd(["TTL", "key"]);
+    assert_eq!(pipeline.len(), 3);
+    assert_eq!(
+      pipeline.commands()[0],
+      Data::Array(vec![
+        Data::BulkString("SET".into()),
+        Data::BulkString("key".into()),
+        Data::BulkString("value".into()),
+      ])
+    );
+    assert_eq!(
+      pipeline.commands()[2],
+      Data::Array(vec![
+        Data::BulkString("TTL".into()),
+        Data::BulkString("key".into()),
+      ])
+    );
+  }
+}
@@ -0,0 +1,201 @@
+//! Pool of connections shared between tasks.
+
+use crate::backoff::Backoff;
+use crate::connection::Connection;
+use crate::error::Result;
+use async_std::channel::{bounded, Receiver, Sender};
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+
+/// Pool of [Connection] to a Sparrow instance.
+///
+/// At most `max_size` connections are handed out at the same time, [Pool::get] waits for a
+/// connection to be released otherwise. Released connections are kept open to be reused,
+/// unless they hold a state on the server, see [Connection::has_session_state].
+/// The pool is cheap to clone: clones share the same connections.
+#[derive(Clone)]
+pub struct Pool {
+  inner: Arc<PoolInner>,
+}
+
+struct PoolInner {
+  address: String,
+  backoff: Backoff,
+  idle: Mutex<Vec<Connection>>,
+  // Bounded channel used as a semaphore: a permit is sent when a connection is handed out
+  // and received back when it is released.
+  permits: (Sender<()>, Receiver<()>),
+}
+
+impl Pool {
+  /// Return a new [Pool] using the default [Backoff] policy.
+  ///
+  /// Connections are opened lazily.
+  ///
+  /// # Arguments
+  /// * `address` - `host:port` address of the Sparrow instance
+  /// * `max_size` - Maximum number of connections handed out at the same time
+  ///
+  /// # Examples
+  /// ```rust,no_run
+  /// # fn main() -> sparrow_client::Result<()> { async_std::task::block_on(async {
+  /// use sparrow_client::Pool;
+  ///
+  /// let pool = Pool::new("127.0.0.1:3000", 16);
+  /// let mut connection = pool.get().await?;
+  /// connection.set("key", "value").await?;
+  /// # Ok(()) }) }
+  /// ```
+  pub fn new(address: &str, max_size: usize) -> Pool {
+    Pool::with_backoff(address, max_size, Backoff::default())
+  }
+
+  /// Return a new [Pool] using a given [Backoff] policy.
+  ///
+  /// # Arguments
+  /// * `address` - `host:port` address of the Sparrow instance
+  /// * `max_size` - Maximum number of connections handed out at the same time
+  /// * `backoff` - Policy used to (re)connect to the instance
+  pub fn with_backoff(address: &str, max_size: usize, backoff: Backoff) -> Pool {
+    Pool {
+      inner: Arc::new(PoolInner {
+        address: address.to_string(),
+        backoff,
+        idle: Mutex::new(Vec::new()),
+        permits: bounded(max_size.max(1)),
+      }),
+    }
+  }
+
+  /// Get a connection from the pool, opening a new one if none is idle.
+  ///
+  /// The connection goes back to the pool when the returned [PooledConnection] is dropped.
+  pub async fn get(&self) -> Result<PooledConnection> {
+    // The sender is never closed as the pool owns the receiver
+    let _ = self.inner.permits.0.send(()).await;
+    // The permit is released if connecting fails or if this future is dropped meanwhile
+    let permit = Permit(self.clone());
+    let idle = self.inner.idle.lock().unwrap().pop();
+    let connection = match idle {
+      Some(connection) => connection,
+      None => {
+        Connection::connect_with_backoff(&self.inner.address, self.inner.backoff.clone()).await?
+      }
+    };
+    Ok(PooledConnection {
+      pool: self.clone(),
+      connection: Some(connection),
+      _permit: permit,
+    })
+  }
+
+  /// Return the number of idle connections.
+  pub fn idle_connections(&self) -> usize {
+    self.inner.idle.lock().unwrap().len()
+  }
+}
+
+/// Permit to hand out a connection, released when dropped.
+struct Permit(Pool);
+
+impl Drop for Permit {
+  fn drop(&mut self) {
+    let _ = self.0.inner.permits.1.try_recv();
+  }
+}
+
+/// [Connection] borrowed from a [Pool].
+///
+/// Dereferences to [Connection] and goes back to the pool when dropped.
+pub struct PooledConnection {
+  pool: Pool,
+  connection: Option<Connection>,
+  // Dropped after the connection went back to the pool
+  _permit: Permit,
+}
+
+impl Deref for PooledConnection {
+  type Target = Connection;
+
+  fn deref(&self) -> &Connection {
+    self.connection.as_ref().unwrap()
+  }
+}
+
+impl DerefMut for PooledConnection {
+  fn deref_mut(&mut self) -> &mut Connection {
+    self.connection.as_mut().unwrap()
+  }
+}
+
+impl Drop for PooledConnection {
+  fn drop(&mut self) {
+    if let Some(connection) = self.connection.take() {
+      // Lost connections are not kept: a new one is opened on the next get.
+      // Nor are connections with a selected database, an open transaction or watched keys.
+      if connection.is_connected() && !connection.has_session_state() {
+        self.pool.inner.idle.lock().unwrap().push(connection);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::backoff::Backoff;
+  use crate::mock::run_mock_server;
+  use crate::Pool;
+  use async_std::future::timeout;
+  use std::time::Duration;
+
+  #[async_std::test]
+  async fn test_pool_reuse_connections() {
+    let address = run_mock_server().await;
+    let pool = Pool::new(&address, 2);
+    assert_eq!(pool.idle_connections(), 0);
+
+    {
+      let mut first = pool.get().await.unwrap();
+      let mut second = pool.get().await.unwrap();
+      first.set("key", "value").await.unwrap();
+      assert_eq!(second.get("key").await.unwrap(), Some(b"value".to_vec()));
+    }
+    assert_eq!(pool.idle_connections(), 2);
+
+    let _connection = pool.get().await.unwrap();
+    assert_eq!(pool.idle_connections(), 1);
+  }
+
+  #[async_std::test]
+  async fn test_pool_max_size() {
+    let address = run_mock_server().await;
+    let pool = Pool::new(&address, 1);
+
+    let connection = pool.get().await.unwrap();
+    assert!(timeout(Duration::from_millis(50), pool.get())
+      .await
+      .is_err());
+    drop(connection);
+    assert!(timeout(Duration::from_millis(50), pool.get()).await.is_ok());
+  }
+
+  #[async_std::test]
+  async fn test_pool_get_cancelled() {
+    // Nothing listens on this address, so connecting keeps retrying
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    drop(listener);
+    let backoff = Backoff {
+      initial_delay: Duration::from_secs(1),
+      ..Backoff::default()
+    };
+    let pool = Pool::with_backoff(&address, 1, backoff);
+
+    for _ in 0..2 {
+      assert!(timeout(Duration::from_millis(50), pool.get())
+        .await
+        .is_err());
+    }
+    assert_eq!(pool.inner.permits.1.len(), 0);
+  }
+}
@@ -1,13 +1,91 @@
 //! Rust enum representation of data types used by the RESP protocol.
 
 /// Enum representation of RESP data types.
-#[derive(Debug, PartialEq)]
+///
+/// Bulk strings are binary safe and carry raw bytes.
+/// Use [Data::as_str] or [Data::to_string_lossy] to read them as UTF-8 text.
+#[derive(Clone, Debug, PartialEq)]
 pub enum Data {
   Array(Vec<Data>),
-  BulkString(String),
+  BulkString(Vec<u8>),
   Error(String),
   Integer(i64),
   Null,
   NullArray,
   SimpleString(String),
 }
+
+impl Data {
+  /// Return the raw bytes of a [Data::BulkString].
+  ///
+  /// Return [None] if the data is not a bulk string.
+  pub fn as_bytes(&self) -> Option<&[u8]> {
+    match self {
+      Data::BulkString(bytes) => Some(bytes),
+      _ => None,
+    }
+  }
+
+  /// Return the content of a [Data::BulkString] or a [Data::SimpleString] as a UTF-8 string slice.
+  ///
+  /// Return [None] if the data is not a string or if a bulk string is not valid UTF-8.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use sparrow_resp::Data;
+  ///
+  /// assert_eq!(Data::BulkString("Hello".into()).as_str(), Some("Hello"));
+  /// assert_eq!(Data::BulkString(vec![0xff, 0xfe]).as_str(), None);
+  /// assert_eq!(Data::Integer(1).as_str(), None);
+  /// ```
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      Data::BulkString(bytes) => std::str::from_utf8(bytes).ok(),
+      Data::SimpleString(string) => Some(string),
+      _ => None,
+    }
+  }
+
+  /// Return the content of a [Data::BulkString] or a [Data::SimpleString] as a UTF-8 [String].
+  ///
+  /// Invalid UTF-8 sequences of bulk strings are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+  /// Return [None] if the data is not a string.
+  pub fn to_string_lossy(&self) -> Option<String> {
+    match self {
+      Data::BulkString(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+      Data::SimpleString(string) => Some(string.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::Data;
+
+  #[test]
+  fn test_as_bytes() {
+    assert_eq!(
+      Data::BulkString(vec![0, 159, 146, 150]).as_bytes(),
+      Some(&[0, 159, 146, 150][..])
+    );
+    assert_eq!(Data::SimpleString("OK".to_string()).as_bytes(), None);
+  }
+
+  #[test]
+  fn test_as_str() {
+    assert_eq!(Data::BulkString("OK".into()).as_str(), Some("OK"));
+    assert_eq!(Data::SimpleString("OK".to_string()).as_str(), Some("OK"));
+    assert_eq!(Data::BulkString(vec![0, 159, 146, 150]).as_str(), None);
+    assert_eq!(Data::Null.as_str(), None);
+  }
+
+  #[test]
+  fn test_to_string_lossy() {
+    assert_eq!(
+      Data::BulkString(b"Hello \xF0\x90\x80World".to_vec()).to_string_lossy(),
+      Some("Hello \u{FFFD}World".to_string())
+    );
+    assert_eq!(Data::Integer(1).to_string_lossy(), None);
+  }
+}
@@ -21,7 +21,7 @@ use std::io::{Error, ErrorKind, Result};
 /// let input = String::from("$14\r\nHello Sparrow!\r\n");
 ///
 /// let actual = decode_string(input).await?;
-/// let expected = Data::BulkString(String::from("Hello Sparrow!"));
+/// let expected = Data::BulkString("Hello Sparrow!".into());
 ///
 /// assert_eq!(actual, expected);
 /// #
@@ -44,7 +44,7 @@ pub async fn decode_string(content: String) -> Result<Data> {
 /// let mut input = BufReader::new(input.as_bytes());
 ///
 /// let actual = decode(&mut input).await?;
-/// let expected = Data::BulkString(String::from("Hello Sparrow!"));
+/// let expected = Data::BulkString("Hello Sparrow!".into());
 ///
 /// assert_eq!(actual, expected);
 /// #
@@ -100,7 +100,7 @@ fn decode_inner<R: Read + Unpin + Send>(
           return Ok(Data::NullArray);
         }
 
-        if n_bytes < -1 || n_bytes > RESPONSE_MAX_SIZE {
+        if !(-1..=RESPONSE_MAX_SIZE).contains(&n_bytes) {
           return Err(Error::new(
             ErrorKind::InvalidData,
             format!("Data is too large: {} > {}", n_bytes, RESPONSE_MAX_SIZE),
@@ -121,29 +121,31 @@ fn decode_inner<R: Read + Unpin + Send>(
           return Ok(Data::Null);
         }
 
-        if n_bytes < -1 || n_bytes > RESPONSE_MAX_SIZE {
+        if !(-1..=RESPONSE_MAX_SIZE).contains(&n_bytes) {
           return Err(Error::new(
             ErrorKind::InvalidData,
             format!("Data is too large: {} > {}", n_bytes, RESPONSE_MAX_SIZE),
           ));
         }
 
-        let mut string_buff: Vec<u8> = vec![0; n_bytes as usize + 2];
-        reader.read_exact(string_buff.as_mut_slice()).await?;
+        let mut bytes_buff: Vec<u8> = vec![0; n_bytes as usize + 2];
+        reader.read_exact(bytes_buff.as_mut_slice()).await?;
         if !is_crlf(
-          string_buff[string_buff.len() - 2],
-          string_buff[string_buff.len() - 1],
+          bytes_buff[bytes_buff.len() - 2],
+          bytes_buff[bytes_buff.len() - 1],
         ) {
           return Err(Error::new(
             ErrorKind::InvalidInput,
             format!(
               "Invalid CRLF: {}{}",
-              buff[buff.len() - 2],
-              buff[buff.len() - 1]
+              bytes_buff[bytes_buff.len() - 2],
+              bytes_buff[bytes_buff.len() - 1]
             ),
           ));
         }
-        parse_string(&string_buff[..string_buff.len() - 2]).map(Data::BulkString)
+        // Bulk strings are binary safe: their content is kept as raw bytes
+        bytes_buff.truncate(n_bytes as usize);
+        Ok(Data::BulkString(bytes_buff))
       }
       ERROR_FIRST_BYTE => parse_string(bytes).map(Data::Error),
       INTEGER_FIRST_BYTE => parse_integer(bytes).map(Data::Integer),
@@ -187,7 +189,8 @@ fn parse_string(bytes: &[u8]) -> Result<String> {
 #[cfg(test)]
 mod tests {
   use crate::data::Data;
-  use crate::deserialize::decode_string;
+  use crate::deserialize::{decode, decode_string};
+  use async_std::io::BufReader;
 
   #[async_std::test]
   async fn test_decode_array() {
@@ -209,7 +212,7 @@ mod tests {
       .unwrap(),
       Data::Array(vec![
         Data::SimpleString("OK".to_string()),
-        Data::BulkString("Hi sparrow, how are you?".to_string()),
+        Data::BulkString("Hi sparrow, how are you?".into()),
         Data::Array(vec![
           Data::SimpleString("OK".to_string()),
           Data::Null,
@@ -236,7 +239,7 @@ mod tests {
   async fn test_decode_bulk_string() {
     assert_eq!(
       decode_string("$2\r\nOK\r\n".to_string()).await.unwrap(),
-      Data::BulkString("OK".to_string())
+      Data::BulkString("OK".into())
     );
   }
 
@@ -246,7 +249,16 @@ mod tests {
       decode_string("$24\r\nHi sparrow, how are you?\r\n".to_string())
         .await
         .unwrap(),
-      Data::BulkString("Hi sparrow, how are you?".to_string())
+      Data::BulkString("Hi sparrow, how are you?".into())
+    );
+  }
+
+  #[async_std::test]
+  async fn test_decode_bulk_string_binary() {
+    let mut input = BufReader::new(&b"$6\r\n\x00\xff\r\n\x89P\r\n"[..]);
+    assert_eq!(
+      decode(&mut input).await.unwrap(),
+      Data::BulkString(vec![0x00, 0xff, b'\r', b'\n', 0x89, b'P'])
     );
   }
 
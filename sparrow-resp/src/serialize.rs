@@ -37,7 +37,7 @@ pub async fn encode_string<W>(content: String, writer: &mut BufWriter<W>) -> Res
 where
   W: Write + Unpin + Send,
 {
-  encode(&Data::BulkString(content), writer).await
+  encode(&Data::BulkString(content.into_bytes()), writer).await
 }
 
 /// Encode a given [Data] enum member by writing it to a [BufWriter].
@@ -83,48 +83,54 @@ where
   Box::pin(async move {
     match data {
       Data::Array(array) => {
-        writer.write(ARRAY_FIRST_BYTE).await?;
-        writer.write(array.len().to_string().as_bytes()).await?;
-        writer.write(CRLF_BYTES).await?;
+        writer.write_all(ARRAY_FIRST_BYTE).await?;
+        writer.write_all(array.len().to_string().as_bytes()).await?;
+        writer.write_all(CRLF_BYTES).await?;
         for data in array.iter() {
           encode_inner(data, writer).await?;
         }
       }
       Data::BulkString(data) => {
-        writer.write(BULK_STRING_FIRST_BYTE).await?;
-        writer
-          .write(data.as_bytes().len().to_string().as_bytes())
-          .await?;
-        writer.write(CRLF_BYTES).await?;
-        writer.write(data.as_bytes()).await?;
-        writer.write(CRLF_BYTES).await?;
+        writer.write_all(BULK_STRING_FIRST_BYTE).await?;
+        writer.write_all(data.len().to_string().as_bytes()).await?;
+        writer.write_all(CRLF_BYTES).await?;
+        writer.write_all(data).await?;
+        writer.write_all(CRLF_BYTES).await?;
       }
       Data::Error(err) => {
-        writer.write(ERROR_FIRST_BYTE).await?;
-        writer.write(err.to_string().as_bytes()).await?;
-        writer.write(CRLF_BYTES).await?;
+        writer.write_all(ERROR_FIRST_BYTE).await?;
+        writer.write_all(single_line(err).as_bytes()).await?;
+        writer.write_all(CRLF_BYTES).await?;
       }
       Data::Integer(data) => {
-        writer.write(INTEGER_FIRST_BYTE).await?;
-        writer.write(data.to_string().as_bytes()).await?;
-        writer.write(CRLF_BYTES).await?;
+        writer.write_all(INTEGER_FIRST_BYTE).await?;
+        writer.write_all(data.to_string().as_bytes()).await?;
+        writer.write_all(CRLF_BYTES).await?;
       }
       Data::Null => {
-        writer.write(NULL_BYTES).await?;
+        writer.write_all(NULL_BYTES).await?;
       }
       Data::NullArray => {
-        writer.write(NULL_ARRAY_BYTES).await?;
+        writer.write_all(NULL_ARRAY_BYTES).await?;
       }
       Data::SimpleString(data) => {
-        writer.write(SIMPLE_STRING_FIRST_BYTE).await?;
-        writer.write(data.to_string().as_bytes()).await?;
-        writer.write(CRLF_BYTES).await?;
+        writer.write_all(SIMPLE_STRING_FIRST_BYTE).await?;
+        writer.write_all(single_line(data).as_bytes()).await?;
+        writer.write_all(CRLF_BYTES).await?;
       }
     };
     Ok(())
   })
 }
 
+/// Return a text with its CR and LF characters replaced by spaces.
+///
+/// Errors and simple strings are terminated by a CRLF, so they must not contain one,
+/// e.g. when they are built from client input.
+fn single_line(text: &str) -> String {
+  text.replace(['\r', '\n'], " ")
+}
+
 #[cfg(test)]
 mod tests {
   use crate::data::Data;
@@ -137,7 +143,7 @@ mod tests {
     encode(
       &Data::Array(vec![
         Data::SimpleString("OK".to_string()),
-        Data::BulkString("Hi sparrow, how are you?".to_string()),
+        Data::BulkString("Hi sparrow, how are you?".into()),
         Data::Array(vec![
           Data::SimpleString("OK".to_string()),
           Data::Null,
@@ -178,10 +184,25 @@ mod tests {
     assert_eq!(writer.buffer(), "-An error occurred\r\n".as_bytes());
   }
 
+  #[async_std::test]
+  async fn test_encode_error_crlf() {
+    let mut writer = BufWriter::new(Vec::<u8>::new());
+    encode(
+      &Data::Error("Command not found: A\r\n+OK\r\nB".into()),
+      &mut writer,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+      writer.buffer(),
+      "-Command not found: A  +OK  B\r\n".as_bytes()
+    );
+  }
+
   #[async_std::test]
   async fn test_encode_bulk_string() {
     let mut writer = BufWriter::new(Vec::<u8>::new());
-    encode(&Data::BulkString("OK".to_string()), &mut writer)
+    encode(&Data::BulkString("OK".into()), &mut writer)
       .await
       .unwrap();
     assert_eq!(writer.buffer(), "$2\r\nOK\r\n".as_bytes());
@@ -191,7 +212,7 @@ mod tests {
   async fn test_encode_bulk_string_large() {
     let mut writer = BufWriter::new(Vec::<u8>::new());
     encode(
-      &Data::BulkString("Hi sparrow, how are you?".to_string()),
+      &Data::BulkString("Hi sparrow, how are you?".into()),
       &mut writer,
     )
     .await
@@ -202,6 +223,18 @@ mod tests {
     );
   }
 
+  #[async_std::test]
+  async fn test_encode_bulk_string_binary() {
+    let mut writer = BufWriter::new(Vec::<u8>::new());
+    encode(
+      &Data::BulkString(vec![0x00, 0xff, b'\r', b'\n', 0x89, b'P']),
+      &mut writer,
+    )
+    .await
+    .unwrap();
+    assert_eq!(writer.buffer(), &b"$6\r\n\x00\xff\r\n\x89P\r\n"[..]);
+  }
+
   #[async_std::test]
   async fn test_encode_integer() {
     let mut writer = BufWriter::new(Vec::<u8>::new());
@@ -231,4 +264,13 @@ mod tests {
       .unwrap();
     assert_eq!(writer.buffer(), "+OK\r\n".as_bytes());
   }
+
+  #[async_std::test]
+  async fn test_encode_simple_string_crlf() {
+    let mut writer = BufWriter::new(Vec::<u8>::new());
+    encode(&Data::SimpleString("O\nK\r".to_string()), &mut writer)
+      .await
+      .unwrap();
+    assert_eq!(writer.buffer(), "+O K \r\n".as_bytes());
+  }
 }
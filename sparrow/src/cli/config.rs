@@ -1,9 +1,14 @@
 //! Config struct used to parse environment variable and CLI parameters.
 
-use crate::cli::constants::TCP_SERVER_PORT;
+use crate::cli::constants::{
+  AOF_ENABLED, AOF_FILEPATH, AOF_FSYNC, DATABASES, SNAPSHOT_FILEPATH, TCP_SERVER_BIND,
+  TCP_SERVER_MAX_CONNECTIONS, TCP_SERVER_PORT,
+};
+use crate::persistence::FsyncPolicy;
 use getopts::Matches;
 use std::env;
 use std::error::Error;
+use std::net::{IpAddr, SocketAddr};
 
 /// Config that holds values used to parameterize
 /// Sparrow's Engine and Network Interface.
@@ -11,6 +16,20 @@ use std::error::Error;
 pub struct Config {
   /// TCP listening port of Sparrow's Network Interface.
   pub tcp_server_port: u16,
+  /// IP addresses the TCP server listens on.
+  pub tcp_server_bind: Vec<IpAddr>,
+  /// Maximum number of simultaneous client connections.
+  pub tcp_server_max_connections: usize,
+  /// Number of logical databases, selected by clients with `SELECT`.
+  pub databases: usize,
+  /// Whether mutating commands are logged into an append-only file.
+  pub aof_enabled: bool,
+  /// Path of the append-only file.
+  pub aof_filepath: String,
+  /// Fsync policy of the append-only file.
+  pub aof_fsync: FsyncPolicy,
+  /// Path of the snapshot file.
+  pub snapshot_filepath: String,
 }
 
 impl Config {
@@ -36,19 +55,139 @@ impl Config {
 
     // Parse environment variables here
     let tcp_server_port: u16 = env::var(TCP_SERVER_PORT.evar_name)?.parse()?;
+    let tcp_server_bind = parse_bind_addresses(&env::var(TCP_SERVER_BIND.evar_name)?)?;
+    let tcp_server_max_connections: usize =
+      env::var(TCP_SERVER_MAX_CONNECTIONS.evar_name)?.parse()?;
+    let databases = parse_databases(&env::var(DATABASES.evar_name)?)?;
+    let aof_enabled: bool = env::var(AOF_ENABLED.evar_name)?.parse()?;
+    let aof_filepath = env::var(AOF_FILEPATH.evar_name)?;
+    let aof_fsync: FsyncPolicy = env::var(AOF_FSYNC.evar_name)?.parse()?;
+    let snapshot_filepath = env::var(SNAPSHOT_FILEPATH.evar_name)?;
 
-    Ok(Config { tcp_server_port })
+    Ok(Config {
+      tcp_server_port,
+      tcp_server_bind,
+      tcp_server_max_connections,
+      databases,
+      aof_enabled,
+      aof_filepath,
+      aof_fsync,
+      snapshot_filepath,
+    })
   }
 }
 
 impl Config {
+  /// Return the socket addresses the TCP server listens on.
+  pub fn tcp_server_addresses(&self) -> Vec<SocketAddr> {
+    self
+      .tcp_server_bind
+      .iter()
+      .map(|ip| SocketAddr::new(*ip, self.tcp_server_port))
+      .collect()
+  }
+
   /// Override the config with the given CLI parameters.
   pub fn update_with_cli_params(&mut self, matches: Matches) -> Result<(), Box<dyn Error>> {
     // Parse cli parameters here
     if let Some(tcp_server_port) = matches.opt_str(TCP_SERVER_PORT.long_name) {
       self.tcp_server_port = tcp_server_port.parse()?;
     };
+    if let Some(tcp_server_bind) = matches.opt_str(TCP_SERVER_BIND.long_name) {
+      self.tcp_server_bind = parse_bind_addresses(&tcp_server_bind)?;
+    };
+    if let Some(tcp_server_max_connections) = matches.opt_str(TCP_SERVER_MAX_CONNECTIONS.long_name)
+    {
+      self.tcp_server_max_connections = tcp_server_max_connections.parse()?;
+    };
+    if let Some(databases) = matches.opt_str(DATABASES.long_name) {
+      self.databases = parse_databases(&databases)?;
+    };
+    if let Some(aof_enabled) = matches.opt_str(AOF_ENABLED.long_name) {
+      self.aof_enabled = aof_enabled.parse()?;
+    };
+    if let Some(aof_filepath) = matches.opt_str(AOF_FILEPATH.long_name) {
+      self.aof_filepath = aof_filepath;
+    };
+    if let Some(aof_fsync) = matches.opt_str(AOF_FSYNC.long_name) {
+      self.aof_fsync = aof_fsync.parse()?;
+    };
+    if let Some(snapshot_filepath) = matches.opt_str(SNAPSHOT_FILEPATH.long_name) {
+      self.snapshot_filepath = snapshot_filepath;
+    };
 
     Ok(())
   }
 }
+
+/// Parse a comma-separated list of IPv4 or IPv6 addresses.
+///
+/// # Arguments
+/// * `addresses` - Addresses to parse, e.g. `127.0.0.1,::1`
+fn parse_bind_addresses(addresses: &str) -> Result<Vec<IpAddr>, Box<dyn Error>> {
+  let addresses = addresses
+    .split(',')
+    .map(|address| {
+      address
+        .trim()
+        .parse()
+        .map_err(|err| format!("Invalid bind address '{}': {}", address.trim(), err))
+    })
+    .collect::<Result<Vec<IpAddr>, String>>()?;
+  Ok(addresses)
+}
+
+/// Parse a number of logical databases, which must be at least 1.
+///
+/// # Arguments
+/// * `databases` - Number to parse, e.g. `16`
+fn parse_databases(databases: &str) -> Result<usize, Box<dyn Error>> {
+  match databases.trim().parse() {
+    Ok(databases) if databases > 0 => Ok(databases),
+    _ => Err(format!("Invalid number of databases '{}'", databases.trim()).into()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::cli::config::{parse_bind_addresses, parse_databases};
+  use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+  #[test]
+  fn test_parse_bind_addresses() {
+    assert_eq!(
+      parse_bind_addresses("127.0.0.1").unwrap(),
+      vec![IpAddr::V4(Ipv4Addr::LOCALHOST)]
+    );
+    assert_eq!(
+      parse_bind_addresses("0.0.0.0, ::1").unwrap(),
+      vec![
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V6(Ipv6Addr::LOCALHOST)
+      ]
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid bind address 'localhost'")]
+  fn test_parse_bind_addresses_invalid() {
+    parse_bind_addresses("127.0.0.1,localhost").unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid bind address ''")]
+  fn test_parse_bind_addresses_empty() {
+    parse_bind_addresses("").unwrap();
+  }
+
+  #[test]
+  fn test_parse_databases() {
+    assert_eq!(parse_databases("16").unwrap(), 16);
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid number of databases '0'")]
+  fn test_parse_databases_zero() {
+    parse_databases("0").unwrap();
+  }
+}
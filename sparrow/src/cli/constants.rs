@@ -16,5 +16,55 @@ pub const TCP_SERVER_PORT: CliOpt = CliOpt::new(
   "PORT",
   "TCP_SERVER_PORT",
 );
+pub const TCP_SERVER_BIND: CliOpt = CliOpt::new(
+  "b",
+  "bind",
+  "set tcp server bind addresses, comma-separated (e.g. 127.0.0.1,::1)",
+  "ADDRESSES",
+  "TCP_SERVER_BIND",
+);
+pub const TCP_SERVER_MAX_CONNECTIONS: CliOpt = CliOpt::new(
+  "",
+  "max-connections",
+  "set maximum number of simultaneous client connections",
+  "NUMBER",
+  "TCP_SERVER_MAX_CONNECTIONS",
+);
+pub const DATABASES: CliOpt = CliOpt::new(
+  "",
+  "databases",
+  "set number of logical databases",
+  "NUMBER",
+  "DATABASES",
+);
+
+pub const AOF_ENABLED: CliOpt = CliOpt::new(
+  "",
+  "aof",
+  "enable append-only file persistence (true, false)",
+  "BOOL",
+  "AOF_ENABLED",
+);
+pub const AOF_FILEPATH: CliOpt = CliOpt::new(
+  "",
+  "aof-file",
+  "set append-only filepath",
+  "FILEPATH",
+  "AOF_FILEPATH",
+);
+pub const AOF_FSYNC: CliOpt = CliOpt::new(
+  "",
+  "aof-fsync",
+  "set append-only file fsync policy (always, everysec, never)",
+  "POLICY",
+  "AOF_FSYNC",
+);
+pub const SNAPSHOT_FILEPATH: CliOpt = CliOpt::new(
+  "",
+  "snapshot-file",
+  "set snapshot filepath",
+  "FILEPATH",
+  "SNAPSHOT_FILEPATH",
+);
 
 pub const HELP: CliFlag = CliFlag::new("h", "help", "display this message");
@@ -6,7 +6,10 @@ mod parameters;
 
 pub use crate::cli::config::Config;
 
-use crate::cli::constants::{ENV_FILEPATH, HELP, TCP_SERVER_PORT};
+use crate::cli::constants::{
+  AOF_ENABLED, AOF_FILEPATH, AOF_FSYNC, DATABASES, ENV_FILEPATH, HELP, SNAPSHOT_FILEPATH,
+  TCP_SERVER_BIND, TCP_SERVER_MAX_CONNECTIONS, TCP_SERVER_PORT,
+};
 use getopts::Options;
 use std::env;
 
@@ -65,7 +68,17 @@ pub fn run_cli() -> Result<Option<Config>, Box<dyn std::error::Error>> {
 fn get_opts() -> Options {
   let mut opts = Options::new();
   // Add options to parse here
-  for option in vec![ENV_FILEPATH, TCP_SERVER_PORT] {
+  for option in [
+    ENV_FILEPATH,
+    TCP_SERVER_PORT,
+    TCP_SERVER_BIND,
+    TCP_SERVER_MAX_CONNECTIONS,
+    DATABASES,
+    AOF_ENABLED,
+    AOF_FILEPATH,
+    AOF_FSYNC,
+    SNAPSHOT_FILEPATH,
+  ] {
     opts.optopt(
       option.short_name,
       option.long_name,
@@ -74,7 +87,7 @@ fn get_opts() -> Options {
     );
   }
   // Add flags to parse here
-  for flag in vec![HELP] {
+  for flag in [HELP] {
     opts.optflag(flag.short_name, flag.long_name, flag.description);
   }
   opts
@@ -0,0 +1,133 @@
+//! Engine APPEND command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, MAX_STRING_LENGTH, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine APPEND command.
+#[derive(Clone, Debug)]
+pub struct AppendCommand {
+  key: String,
+  value: Vec<u8>,
+}
+
+impl AppendCommand {
+  /// Return a new [AppendCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, value).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::AppendCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"value"[..]];
+  /// let cmd = AppendCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "APPEND key value");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<AppendCommand> {
+    check_args_len("APPEND", args, 2)?;
+    Ok(AppendCommand {
+      key: parse_string(args.first().unwrap())?,
+      value: args.get(1).unwrap().to_vec(),
+    })
+  }
+}
+
+/// Return whether a string of `current` bytes can grow by `extra` bytes without exceeding `limit`.
+fn can_grow(current: usize, extra: usize, limit: usize) -> bool {
+  current
+    .checked_add(extra)
+    .is_some_and(|length| length <= limit)
+}
+
+impl fmt::Display for AppendCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "APPEND {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.value)
+    )
+  }
+}
+
+impl Command for AppendCommand {
+  /// Execute the `APPEND key value` command on a given [Nest].
+  ///
+  /// Missing keys are created empty before appending. Return the new length of the value.
+  /// The value cannot grow beyond [MAX_STRING_LENGTH].
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::String(Vec::new()));
+    match egg.value_mut().as_string_mut() {
+      Some(bytes) if !can_grow(bytes.len(), self.value.len(), MAX_STRING_LENGTH) => {
+        Data::Error("String exceeds maximum allowed size".to_string())
+      }
+      Some(bytes) => {
+        bytes.extend_from_slice(&self.value);
+        Data::Integer(bytes.len() as i64)
+      }
+      None => Data::Error(WRONG_TYPE_ERROR.to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::append_command::{can_grow, AppendCommand};
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::{MAX_STRING_LENGTH, WRONG_TYPE_ERROR};
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new("set", HashSet::new()));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = AppendCommand::new(&[TEST_KEY.as_bytes(), b"Hello"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(5));
+    let command = AppendCommand::new(&[TEST_KEY.as_bytes(), b" World"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(11));
+    assert_eq!(
+      nest.get(TEST_KEY).unwrap().value().as_string(),
+      Some(&b"Hello World"[..])
+    );
+
+    let command = AppendCommand::new(&[b"set", b"value"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+
+  #[test]
+  fn test_can_grow() {
+    assert!(can_grow(0, 0, 0));
+    assert!(can_grow(3, 2, 5));
+    assert!(!can_grow(3, 3, 5));
+    assert!(!can_grow(usize::MAX, 1, usize::MAX));
+    assert!(!can_grow(MAX_STRING_LENGTH, 1, MAX_STRING_LENGTH));
+  }
+}
@@ -0,0 +1,137 @@
+//! Command arguments parsing utilities.
+
+use crate::errors::Result;
+
+/// Parse a raw command argument as a UTF-8 [String].
+///
+/// Keys and command options are text while values are kept as raw bytes.
+///
+/// # Arguments
+/// * `arg` - Raw argument bytes
+pub fn parse_string(arg: &[u8]) -> Result<String> {
+  String::from_utf8(arg.to_vec()).map_err(|err| format!("Invalid UTF-8 argument: {}", err).into())
+}
+
+/// Parse a raw command argument as an [i64].
+///
+/// # Arguments
+/// * `arg` - Raw argument bytes
+pub fn parse_integer(arg: &[u8]) -> Result<i64> {
+  std::str::from_utf8(arg)
+    .ok()
+    .and_then(|arg| arg.parse::<i64>().ok())
+    .ok_or_else(|| "Value is not an integer or out of range".into())
+}
+
+/// Parse a raw command argument as an [f64].
+///
+/// # Arguments
+/// * `arg` - Raw argument bytes
+pub fn parse_float(arg: &[u8]) -> Result<f64> {
+  std::str::from_utf8(arg)
+    .ok()
+    .and_then(|arg| arg.parse::<f64>().ok())
+    .filter(|value| !value.is_nan())
+    .ok_or_else(|| "Value is not a valid float".into())
+}
+
+/// Check that a command received the expected number of arguments.
+///
+/// # Arguments
+/// * `name` - Name of the command, used in the error message
+/// * `args` - Arguments of the command
+/// * `expected` - Expected number of arguments
+pub fn check_args_len(name: &str, args: &[&[u8]], expected: usize) -> Result<()> {
+  match args.len() {
+    n if n == expected => Ok(()),
+    n => Err(
+      format!(
+        "Cannot parse {} command arguments: Wrong number of arguments. Expected {}, got {}.",
+        name, expected, n
+      )
+      .into(),
+    ),
+  }
+}
+
+/// Check that a command received at least a given number of arguments.
+///
+/// # Arguments
+/// * `name` - Name of the command, used in the error message
+/// * `args` - Arguments of the command
+/// * `min` - Minimum number of arguments
+pub fn check_min_args_len(name: &str, args: &[&[u8]], min: usize) -> Result<()> {
+  match args.len() {
+    n if n >= min => Ok(()),
+    n => Err(
+      format!(
+        "Cannot parse {} command arguments: Wrong number of arguments. Expected at least {}, got {}.",
+        name, min, n
+      )
+      .into(),
+    ),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::args::{
+    check_args_len, check_min_args_len, parse_float, parse_integer, parse_string,
+  };
+
+  #[test]
+  fn test_parse_string() {
+    assert_eq!(parse_string(b"key").unwrap(), "key");
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid UTF-8 argument")]
+  fn test_parse_string_invalid_utf8() {
+    parse_string(&[0xff, 0xfe]).unwrap();
+  }
+
+  #[test]
+  fn test_parse_integer() {
+    assert_eq!(parse_integer(b"-42").unwrap(), -42);
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is not an integer or out of range")]
+  fn test_parse_integer_invalid() {
+    parse_integer(b"4.2").unwrap();
+  }
+
+  #[test]
+  fn test_parse_float() {
+    assert_eq!(parse_float(b"4.2").unwrap(), 4.2);
+    assert_eq!(parse_float(b"-3").unwrap(), -3.0);
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is not a valid float")]
+  fn test_parse_float_invalid() {
+    parse_float(b"nan").unwrap();
+  }
+
+  #[test]
+  fn test_check_args_len() {
+    assert!(check_args_len("GET", &[b"key"], 1).is_ok());
+    assert!(check_min_args_len("HDEL", &[b"key", b"field", b"other"], 2).is_ok());
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse GET command arguments: Wrong number of arguments. Expected 1, got 2."
+  )]
+  fn test_check_args_len_invalid() {
+    check_args_len("GET", &[b"key", b"other"], 1).unwrap();
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HDEL command arguments: Wrong number of arguments. Expected at least 2, got 1."
+  )]
+  fn test_check_min_args_len_invalid() {
+    check_min_args_len("HDEL", &[b"key"], 2).unwrap();
+  }
+}
@@ -0,0 +1,143 @@
+//! Engine BITCOUNT command.
+
+use crate::core::commands::args::{check_min_args_len, parse_integer, parse_string};
+use crate::core::commands::bitmap::{count_bits, BitUnit};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine BITCOUNT command.
+#[derive(Clone, Debug)]
+pub struct BitCountCommand {
+  key: String,
+  range: Option<(i64, i64, BitUnit)>,
+}
+
+impl BitCountCommand {
+  /// Return a new [BitCountCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key),
+  ///   3 arguments (key, start, end) or 4 arguments (key, start, end, `BYTE` or `BIT`).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::BitCountCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"0"[..], &b"-1"[..], &b"bit"[..]];
+  /// let cmd = BitCountCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "BITCOUNT key 0 -1 BIT");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<BitCountCommand> {
+    check_min_args_len("BITCOUNT", args, 1)?;
+    let range = match args.len() {
+      1 => None,
+      3 | 4 => Some((
+        parse_integer(args.get(1).unwrap())?,
+        parse_integer(args.get(2).unwrap())?,
+        match args.get(3) {
+          Some(arg) => BitUnit::parse("BITCOUNT", arg)?,
+          None => BitUnit::Byte,
+        },
+      )),
+      _ => {
+        return Err(
+          format!(
+            "Cannot parse BITCOUNT command arguments: Syntax error near {}.",
+            parse_string(args.last().unwrap())?
+          )
+          .into(),
+        )
+      }
+    };
+    Ok(BitCountCommand {
+      key: parse_string(args.first().unwrap())?,
+      range,
+    })
+  }
+}
+
+impl fmt::Display for BitCountCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "BITCOUNT {}", self.key)?;
+    if let Some((start, end, unit)) = self.range {
+      write!(f, " {} {} {}", start, end, unit)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for BitCountCommand {
+  /// Execute the `BITCOUNT key [start end [BYTE|BIT]]` command on a given [Nest].
+  ///
+  /// Return the number of bits set to `1` in the value, or in the given range of the value.
+  /// Negative indices count from the end of the value.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let bytes = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(bytes)) => bytes,
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::Integer(0),
+    };
+    let range = match self.range {
+      Some((start, end, unit)) => unit.resolve_range(start, end, bytes.len()),
+      None if bytes.is_empty() => None,
+      None => Some((0, bytes.len() * 8 - 1)),
+    };
+    match range {
+      Some((start, end)) => Data::Integer(count_bits(bytes, start, end) as i64),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::bitcount_command::BitCountCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &b"foobar"[..]));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse BITCOUNT command arguments: Syntax error near 0.")]
+  fn test_command_new_missing_end() {
+    BitCountCommand::new(&[TEST_KEY.as_bytes(), b"0"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse BITCOUNT command arguments: Syntax error near WORD.")]
+  fn test_command_new_invalid_unit() {
+    BitCountCommand::new(&[TEST_KEY.as_bytes(), b"0", b"1", b"word"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    for (args, expected) in [
+      (&[TEST_KEY.as_bytes()][..], 26),
+      (&[TEST_KEY.as_bytes(), b"0", b"0"], 4),
+      (&[TEST_KEY.as_bytes(), b"1", b"1"], 6),
+      (&[TEST_KEY.as_bytes(), b"1", b"1", b"BYTE"], 6),
+      (&[TEST_KEY.as_bytes(), b"5", b"30", b"BIT"], 17),
+      (&[TEST_KEY.as_bytes(), b"-2", b"-1"], 7),
+      (&[TEST_KEY.as_bytes(), b"2", b"1"], 0),
+      (&[b"missing"], 0),
+    ] {
+      let command = BitCountCommand::new(args).unwrap();
+      assert_eq!(command.execute(&mut nest), Data::Integer(expected));
+    }
+  }
+}
@@ -0,0 +1,504 @@
+//! Engine BITFIELD command.
+
+use crate::core::commands::args::{check_min_args_len, parse_integer, parse_string};
+use crate::core::commands::bitmap::{read_bits, write_bits};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, MAX_STRING_LENGTH, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Integer type of a bitfield, e.g. `i5` or `u16`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BitFieldType {
+  signed: bool,
+  bits: u8,
+}
+
+impl BitFieldType {
+  /// Parse a bitfield type, from `i1` to `i64` or from `u1` to `u63`.
+  fn parse(arg: &[u8]) -> Result<BitFieldType> {
+    let (signed, bits) = match arg {
+      [b'i' | b'I', bits @ ..] => (true, bits),
+      [b'u' | b'U', bits @ ..] => (false, bits),
+      _ => (false, &b""[..]),
+    };
+    let max_bits = if signed { 64 } else { 63 };
+    match parse_integer(bits) {
+      Ok(bits) if (1..=max_bits).contains(&bits) => Ok(BitFieldType {
+        signed,
+        bits: bits as u8,
+      }),
+      _ => Err(
+        "Invalid bitfield type. Use something like i16 u8. Note that u64 is not supported but i64 is."
+          .into(),
+      ),
+    }
+  }
+
+  /// Return the minimum and maximum integers of this type.
+  fn bounds(self) -> (i128, i128) {
+    match self.signed {
+      true => (-(1 << (self.bits - 1)), (1 << (self.bits - 1)) - 1),
+      false => (0, (1 << self.bits) - 1),
+    }
+  }
+
+  /// Read an integer of this type at a bit offset.
+  fn read(self, bytes: &[u8], offset: usize) -> i64 {
+    let raw = read_bits(bytes, offset, self.bits);
+    match self.signed {
+      // Move the sign bit to the most significant bit, then shift back to extend it
+      true => ((raw << (64 - self.bits)) as i64) >> (64 - self.bits),
+      false => raw as i64,
+    }
+  }
+
+  /// Write an integer of this type at a bit offset.
+  fn write(self, bytes: &mut Vec<u8>, offset: usize, value: i64) {
+    write_bits(bytes, offset, self.bits, value as u64);
+  }
+}
+
+impl fmt::Display for BitFieldType {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}{}", if self.signed { "i" } else { "u" }, self.bits)
+  }
+}
+
+/// Behavior of a [BitFieldCommand] when a written integer does not fit in its type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Overflow {
+  /// Keep the least significant bits of the integer, the default.
+  Wrap,
+  /// Clamp the integer to the minimum or maximum of the type.
+  Sat,
+  /// Write nothing and return [Data::Null].
+  Fail,
+}
+
+impl Overflow {
+  /// Parse a `WRAP`, `SAT` or `FAIL` command argument into an [Overflow].
+  fn parse(arg: &[u8]) -> Result<Overflow> {
+    match parse_string(arg)?.to_uppercase().as_str() {
+      "WRAP" => Ok(Overflow::Wrap),
+      "SAT" => Ok(Overflow::Sat),
+      "FAIL" => Ok(Overflow::Fail),
+      _ => Err("Invalid OVERFLOW type specified".into()),
+    }
+  }
+
+  /// Fit an integer in a type. Return [None] if it does not fit and overflows fail.
+  fn apply(self, value: i128, field_type: BitFieldType) -> Option<i64> {
+    let (min, max) = field_type.bounds();
+    if (min..=max).contains(&value) {
+      return Some(value as i64);
+    }
+    match self {
+      Overflow::Wrap => Some(((value - min).rem_euclid(max - min + 1) + min) as i64),
+      Overflow::Sat => Some(value.clamp(min, max) as i64),
+      Overflow::Fail => None,
+    }
+  }
+}
+
+impl fmt::Display for Overflow {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Overflow::Wrap => write!(f, "WRAP"),
+      Overflow::Sat => write!(f, "SAT"),
+      Overflow::Fail => write!(f, "FAIL"),
+    }
+  }
+}
+
+/// Subcommand of a [BitFieldCommand].
+#[derive(Clone, Debug, PartialEq)]
+pub enum BitFieldOperation {
+  /// Read an integer at a bit offset.
+  Get(BitFieldType, usize),
+  /// Write an integer at a bit offset.
+  Set(BitFieldType, usize, i64),
+  /// Increment the integer at a bit offset.
+  IncrBy(BitFieldType, usize, i64),
+  /// Change the [Overflow] behavior of the following subcommands.
+  Overflow(Overflow),
+}
+
+impl fmt::Display for BitFieldOperation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      BitFieldOperation::Get(field_type, offset) => write!(f, "GET {} {}", field_type, offset),
+      BitFieldOperation::Set(field_type, offset, value) => {
+        write!(f, "SET {} {} {}", field_type, offset, value)
+      }
+      BitFieldOperation::IncrBy(field_type, offset, increment) => {
+        write!(f, "INCRBY {} {} {}", field_type, offset, increment)
+      }
+      BitFieldOperation::Overflow(overflow) => write!(f, "OVERFLOW {}", overflow),
+    }
+  }
+}
+
+/// Parse a bitfield offset, multiplied by the width of the type when prefixed by `#`.
+fn parse_offset(arg: &[u8], field_type: BitFieldType) -> Result<usize> {
+  let offset = match arg.strip_prefix(b"#") {
+    Some(index) => parse_integer(index).map(|index| index.checked_mul(field_type.bits as i64)),
+    None => parse_integer(arg).map(Some),
+  };
+  match offset {
+    Ok(Some(offset))
+      if offset >= 0 && offset as usize + field_type.bits as usize <= MAX_STRING_LENGTH * 8 =>
+    {
+      Ok(offset as usize)
+    }
+    _ => Err("Bit offset is not an integer or out of range".into()),
+  }
+}
+
+/// Engine BITFIELD command.
+#[derive(Clone, Debug)]
+pub struct BitFieldCommand {
+  key: String,
+  operations: Vec<BitFieldOperation>,
+}
+
+impl BitFieldCommand {
+  /// Return a new [BitFieldCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 1 argument
+  ///   (key, `GET` type offset, `SET` type offset value, `INCRBY` type offset increment,
+  ///   `OVERFLOW` `WRAP`, `SAT` or `FAIL`...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::BitFieldCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"incrby"[..], &b"u8"[..], &b"#1"[..], &b"5"[..]];
+  /// let cmd = BitFieldCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "BITFIELD key INCRBY u8 8 5");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<BitFieldCommand> {
+    check_min_args_len("BITFIELD", args, 1)?;
+    let mut operations = Vec::new();
+    let mut index = 1;
+    while index < args.len() {
+      let name = parse_string(args[index])?.to_uppercase();
+      let len = match name.as_str() {
+        "GET" => 2,
+        "OVERFLOW" => 1,
+        "SET" | "INCRBY" => 3,
+        _ => 0,
+      };
+      if len == 0 || index + len >= args.len() {
+        return Err(
+          format!(
+            "Cannot parse BITFIELD command arguments: Syntax error near {}.",
+            name
+          )
+          .into(),
+        );
+      }
+      let operation = match name.as_str() {
+        "OVERFLOW" => BitFieldOperation::Overflow(Overflow::parse(args[index + 1])?),
+        name => {
+          let field_type = BitFieldType::parse(args[index + 1])?;
+          let offset = parse_offset(args[index + 2], field_type)?;
+          match name {
+            "GET" => BitFieldOperation::Get(field_type, offset),
+            "SET" => BitFieldOperation::Set(field_type, offset, parse_integer(args[index + 3])?),
+            _ => BitFieldOperation::IncrBy(field_type, offset, parse_integer(args[index + 3])?),
+          }
+        }
+      };
+      operations.push(operation);
+      index += len + 1;
+    }
+    Ok(BitFieldCommand {
+      key: parse_string(args.first().unwrap())?,
+      operations,
+    })
+  }
+
+  /// Return whether a subcommand may write to the value.
+  fn is_writing(&self) -> bool {
+    self.operations.iter().any(|operation| {
+      matches!(
+        operation,
+        BitFieldOperation::Set(..) | BitFieldOperation::IncrBy(..)
+      )
+    })
+  }
+}
+
+impl fmt::Display for BitFieldCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "BITFIELD {}", self.key)?;
+    for operation in &self.operations {
+      write!(f, " {}", operation)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for BitFieldCommand {
+  /// Execute the `BITFIELD key [GET type offset] [SET type offset value]
+  /// [INCRBY type offset increment] [OVERFLOW WRAP|SAT|FAIL]` command on a given [Nest].
+  ///
+  /// Return the result of each `GET`, `SET` and `INCRBY` subcommand in order:
+  /// the read integer, the previous integer, and the incremented integer respectively.
+  /// The value is padded with zero bytes when an integer is written past its end.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(_)) | None => {}
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+    }
+    if !self.is_writing() {
+      let bytes = nest
+        .get(&self.key)
+        .and_then(|egg| egg.value().as_string())
+        .unwrap_or_default();
+      return Data::Array(
+        self
+          .operations
+          .iter()
+          .filter_map(|operation| match operation {
+            BitFieldOperation::Get(field_type, offset) => {
+              Some(Data::Integer(field_type.read(bytes, *offset)))
+            }
+            _ => None,
+          })
+          .collect(),
+      );
+    }
+
+    let egg = nest.get_or_insert_with(&self.key, || Value::String(Vec::new()));
+    let bytes = match egg.value_mut().as_string_mut() {
+      Some(bytes) => bytes,
+      None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+    };
+    let mut overflow = Overflow::Wrap;
+    let mut results = Vec::with_capacity(self.operations.len());
+    for operation in &self.operations {
+      let result = match *operation {
+        BitFieldOperation::Get(field_type, offset) => Some(field_type.read(bytes, offset)),
+        BitFieldOperation::Set(field_type, offset, value) => {
+          let previous = field_type.read(bytes, offset);
+          overflow.apply(value as i128, field_type).map(|value| {
+            field_type.write(bytes, offset, value);
+            previous
+          })
+        }
+        BitFieldOperation::IncrBy(field_type, offset, increment) => {
+          let value = field_type.read(bytes, offset) as i128 + increment as i128;
+          overflow.apply(value, field_type).inspect(|&value| {
+            field_type.write(bytes, offset, value);
+          })
+        }
+        BitFieldOperation::Overflow(value) => {
+          overflow = value;
+          continue;
+        }
+      };
+      results.push(result.map_or(Data::Null, Data::Integer));
+    }
+    if bytes.is_empty() {
+      nest.rem(&self.key);
+    }
+    Data::Array(results)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Array(_) if self.is_writing() => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::bitfield_command::BitFieldCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &[0xff, 0x00][..]));
+    nest
+  }
+
+  /// Return the integers of an array, [None] for null elements.
+  fn integers(output: Data) -> Vec<Option<i64>> {
+    match output {
+      Data::Array(elements) => elements
+        .into_iter()
+        .map(|element| match element {
+          Data::Integer(integer) => Some(integer),
+          Data::Null => None,
+          element => panic!("Unexpected element {:?}", element),
+        })
+        .collect(),
+      output => panic!("Unexpected output {:?}", output),
+    }
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Invalid bitfield type. Use something like i16 u8. Note that u64 is not supported but i64 is."
+  )]
+  fn test_command_new_u64() {
+    BitFieldCommand::new(&[TEST_KEY.as_bytes(), b"GET", b"u64", b"0"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse BITFIELD command arguments: Syntax error near SET.")]
+  fn test_command_new_missing_value() {
+    BitFieldCommand::new(&[TEST_KEY.as_bytes(), b"SET", b"u8", b"0"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid OVERFLOW type specified")]
+  fn test_command_new_invalid_overflow() {
+    BitFieldCommand::new(&[TEST_KEY.as_bytes(), b"OVERFLOW", b"LOOP"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute_get(mut nest: Nest) {
+    let args = &[
+      TEST_KEY.as_bytes(),
+      b"GET",
+      b"u4",
+      b"0",
+      b"GET",
+      b"i4",
+      b"#1",
+      b"GET",
+      b"i64",
+      b"4",
+      b"GET",
+      b"u8",
+      b"12",
+    ];
+    let command = BitFieldCommand::new(args).unwrap();
+    assert_eq!(
+      integers(command.execute(&mut nest)),
+      vec![Some(15), Some(-1), Some(-1 << 60), Some(0)]
+    );
+
+    let command = BitFieldCommand::new(&[b"missing", b"GET", b"u8", b"0"]).unwrap();
+    assert_eq!(integers(command.execute(&mut nest)), vec![Some(0)]);
+    assert!(nest.get("missing").is_none());
+  }
+
+  #[rstest]
+  fn test_command_execute_set_and_incrby(mut nest: Nest) {
+    let args = &[
+      TEST_KEY.as_bytes(),
+      b"SET",
+      b"u8",
+      b"#1",
+      b"200",
+      b"INCRBY",
+      b"u8",
+      b"#1",
+      b"100",
+      b"OVERFLOW",
+      b"SAT",
+      b"INCRBY",
+      b"i8",
+      b"#1",
+      b"-200",
+      b"OVERFLOW",
+      b"FAIL",
+      b"INCRBY",
+      b"i8",
+      b"#1",
+      b"-1",
+      b"SET",
+      b"u2",
+      b"20",
+      b"3",
+    ];
+    let command = BitFieldCommand::new(args).unwrap();
+    assert_eq!(
+      integers(command.execute(&mut nest)),
+      vec![Some(0), Some(44), Some(-128), None, Some(0)]
+    );
+    assert_eq!(
+      nest.get(TEST_KEY).unwrap().value().as_string(),
+      Some(&[0xff, 0x80, 0x0c][..])
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_negative_wide_integers(mut nest: Nest) {
+    let i63_min = (-(1i64 << 62)).to_string();
+    let i64_min = i64::MIN.to_string();
+    let args = &[
+      TEST_KEY.as_bytes(),
+      b"SET",
+      b"i63",
+      b"0",
+      b"-1",
+      b"GET",
+      b"i63",
+      b"0",
+      b"SET",
+      b"i63",
+      b"64",
+      i63_min.as_bytes(),
+      b"GET",
+      b"i63",
+      b"64",
+      b"SET",
+      b"i64",
+      b"128",
+      i64_min.as_bytes(),
+      b"GET",
+      b"i64",
+      b"128",
+      b"GET",
+      b"i64",
+      b"0",
+    ];
+    let command = BitFieldCommand::new(args).unwrap();
+    assert_eq!(
+      integers(command.execute(&mut nest)),
+      vec![
+        Some(-(1 << 55)),
+        Some(-1),
+        Some(0),
+        Some(-(1 << 62)),
+        Some(0),
+        Some(i64::MIN),
+        Some(-2),
+      ]
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_fail_on_missing_key(mut nest: Nest) {
+    let args = &[
+      &b"missing"[..],
+      b"OVERFLOW",
+      b"FAIL",
+      b"SET",
+      b"u2",
+      b"0",
+      b"4",
+    ];
+    let command = BitFieldCommand::new(args).unwrap();
+    assert_eq!(integers(command.execute(&mut nest)), vec![None]);
+    assert!(nest.get("missing").is_none());
+  }
+}
@@ -0,0 +1,217 @@
+//! Utilities shared by bitmap commands.
+//!
+//! Bitmaps are string values addressed bit by bit: bit `0` is the most significant bit
+//! of the first byte. Bits past the end of a value are read as `0`.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::list::resolve_range;
+use crate::core::value::MAX_STRING_LENGTH;
+use crate::errors::Result;
+use std::fmt;
+
+/// Parse a raw command argument as a bit offset.
+///
+/// # Arguments
+/// * `arg` - Raw argument bytes
+pub fn parse_bit_offset(arg: &[u8]) -> Result<usize> {
+  parse_integer(arg)
+    .ok()
+    .filter(|offset| (0..(MAX_STRING_LENGTH * 8) as i64).contains(offset))
+    .map(|offset| offset as usize)
+    .ok_or_else(|| "Bit offset is not an integer or out of range".into())
+}
+
+/// Parse a raw command argument as a bit value, `0` or `1`.
+///
+/// # Arguments
+/// * `arg` - Raw argument bytes
+pub fn parse_bit(arg: &[u8]) -> Result<u8> {
+  match arg {
+    b"0" => Ok(0),
+    b"1" => Ok(1),
+    _ => Err("The bit argument must be 1 or 0".into()),
+  }
+}
+
+/// Return the bit at a given offset, `0` past the end of the value.
+pub fn get_bit(bytes: &[u8], offset: usize) -> u8 {
+  bytes
+    .get(offset / 8)
+    .map_or(0, |byte| (byte >> (7 - offset % 8)) & 1)
+}
+
+/// Set the bit at a given offset, padding the value with zero bytes if needed.
+/// Return the previous bit.
+pub fn set_bit(bytes: &mut Vec<u8>, offset: usize, bit: u8) -> u8 {
+  let index = offset / 8;
+  if bytes.len() <= index {
+    bytes.resize(index + 1, 0);
+  }
+  let mask = 0x80 >> (offset % 8);
+  let previous = (bytes[index] & mask != 0) as u8;
+  match bit {
+    0 => bytes[index] &= !mask,
+    _ => bytes[index] |= mask,
+  }
+  previous
+}
+
+/// Return the mask of the bits of a byte that are in an inclusive range of bit offsets.
+fn byte_mask(index: usize, start: usize, end: usize) -> u8 {
+  let mut mask = 0xff;
+  if index == start / 8 {
+    mask &= 0xff >> (start % 8);
+  }
+  if index == end / 8 {
+    mask &= 0xff << (7 - end % 8);
+  }
+  mask
+}
+
+/// Return the number of bits set to `1` in an inclusive range of bit offsets.
+pub fn count_bits(bytes: &[u8], start: usize, end: usize) -> usize {
+  (start / 8..=end / 8)
+    .filter_map(|index| Some(bytes.get(index)? & byte_mask(index, start, end)))
+    .map(|byte| byte.count_ones() as usize)
+    .sum()
+}
+
+/// Return the offset of the first bit equal to `bit` in an inclusive range of bit offsets.
+pub fn find_bit(bytes: &[u8], bit: u8, start: usize, end: usize) -> Option<usize> {
+  (start / 8..=end / 8).find_map(|index| {
+    let byte = if bit == 1 {
+      bytes[index]
+    } else {
+      !bytes[index]
+    };
+    match byte & byte_mask(index, start, end) {
+      0 => None,
+      byte => Some(index * 8 + byte.leading_zeros() as usize),
+    }
+  })
+}
+
+/// Read an unsigned integer of `bits` bits at a bit offset, most significant bit first.
+pub fn read_bits(bytes: &[u8], offset: usize, bits: u8) -> u64 {
+  (0..bits as usize).fold(0, |value, index| {
+    (value << 1) | get_bit(bytes, offset + index) as u64
+  })
+}
+
+/// Write the `bits` least significant bits of an integer at a bit offset, most significant bit first.
+pub fn write_bits(bytes: &mut Vec<u8>, offset: usize, bits: u8, value: u64) {
+  for index in 0..bits as usize {
+    let bit = (value >> (bits as usize - 1 - index)) & 1;
+    set_bit(bytes, offset + index, bit as u8);
+  }
+}
+
+/// Unit of the `start` and `end` indices of a bitmap range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BitUnit {
+  /// Indices of bytes, the default.
+  Byte,
+  /// Indices of bits.
+  Bit,
+}
+
+impl BitUnit {
+  /// Parse a `BYTE` or `BIT` command argument into a [BitUnit].
+  ///
+  /// # Arguments
+  /// * `name` - Name of the command, used in the error message
+  /// * `arg` - Raw argument bytes
+  pub fn parse(name: &str, arg: &[u8]) -> Result<BitUnit> {
+    match parse_string(arg)?.to_uppercase().as_str() {
+      "BYTE" => Ok(BitUnit::Byte),
+      "BIT" => Ok(BitUnit::Bit),
+      arg => Err(
+        format!(
+          "Cannot parse {} command arguments: Syntax error near {}.",
+          name, arg
+        )
+        .into(),
+      ),
+    }
+  }
+
+  /// Resolve an inclusive range of indices in this unit into an inclusive range of bit offsets.
+  ///
+  /// Negative indices count from the end of the value. Return [None] if the range is empty.
+  ///
+  /// # Arguments
+  /// * `start` - Index of the first byte or bit of the range
+  /// * `end` - Index of the last byte or bit of the range
+  /// * `len` - Length of the value in bytes
+  pub fn resolve_range(self, start: i64, end: i64, len: usize) -> Option<(usize, usize)> {
+    match self {
+      BitUnit::Byte => resolve_range(start, end, len).map(|(start, end)| (start * 8, end * 8 + 7)),
+      BitUnit::Bit => resolve_range(start, end, len * 8),
+    }
+  }
+}
+
+impl fmt::Display for BitUnit {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      BitUnit::Byte => write!(f, "BYTE"),
+      BitUnit::Bit => write!(f, "BIT"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::bitmap::{
+    count_bits, find_bit, get_bit, parse_bit_offset, read_bits, set_bit, write_bits, BitUnit,
+  };
+
+  #[test]
+  fn test_get_and_set_bit() {
+    let mut bytes = vec![0b1000_0001];
+    assert_eq!(get_bit(&bytes, 0), 1);
+    assert_eq!(get_bit(&bytes, 1), 0);
+    assert_eq!(get_bit(&bytes, 7), 1);
+    assert_eq!(get_bit(&bytes, 100), 0);
+
+    assert_eq!(set_bit(&mut bytes, 0, 0), 1);
+    assert_eq!(set_bit(&mut bytes, 17, 1), 0);
+    assert_eq!(bytes, vec![0b0000_0001, 0, 0b0100_0000]);
+  }
+
+  #[test]
+  fn test_parse_bit_offset() {
+    assert_eq!(parse_bit_offset(b"7").unwrap(), 7);
+    assert!(parse_bit_offset(b"-1").is_err());
+    assert!(parse_bit_offset(b"4294967296").is_err());
+  }
+
+  #[test]
+  fn test_count_and_find_bits() {
+    let bytes = [0b0000_1111, 0b1111_0000];
+    assert_eq!(count_bits(&bytes, 0, 15), 8);
+    assert_eq!(count_bits(&bytes, 6, 9), 4);
+    assert_eq!(count_bits(&bytes, 0, 3), 0);
+    assert_eq!(find_bit(&bytes, 1, 0, 15), Some(4));
+    assert_eq!(find_bit(&bytes, 0, 4, 15), Some(12));
+    assert_eq!(find_bit(&bytes, 1, 12, 15), None);
+  }
+
+  #[test]
+  fn test_read_and_write_bits() {
+    let mut bytes = Vec::new();
+    write_bits(&mut bytes, 4, 8, 0xab);
+    assert_eq!(bytes, vec![0x0a, 0xb0]);
+    assert_eq!(read_bits(&bytes, 4, 8), 0xab);
+    assert_eq!(read_bits(&bytes, 0, 4), 0);
+    assert_eq!(read_bits(&bytes, 12, 64), 0);
+  }
+
+  #[test]
+  fn test_bit_unit_resolve_range() {
+    assert_eq!(BitUnit::Byte.resolve_range(0, -1, 2), Some((0, 15)));
+    assert_eq!(BitUnit::Byte.resolve_range(1, 1, 2), Some((8, 15)));
+    assert_eq!(BitUnit::Bit.resolve_range(-3, -1, 2), Some((13, 15)));
+    assert_eq!(BitUnit::Bit.resolve_range(3, 1, 2), None);
+  }
+}
@@ -0,0 +1,243 @@
+//! Engine BITOP command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Bitwise operation combining the values of a [BitOpCommand].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BitOperation {
+  /// Bits set in every value.
+  And,
+  /// Bits set in any value.
+  Or,
+  /// Bits set in an odd number of values.
+  Xor,
+  /// Bits not set in a single value.
+  Not,
+}
+
+impl BitOperation {
+  /// Parse an `AND`, `OR`, `XOR` or `NOT` command argument into a [BitOperation].
+  fn parse(arg: &[u8]) -> Result<BitOperation> {
+    match parse_string(arg)?.to_uppercase().as_str() {
+      "AND" => Ok(BitOperation::And),
+      "OR" => Ok(BitOperation::Or),
+      "XOR" => Ok(BitOperation::Xor),
+      "NOT" => Ok(BitOperation::Not),
+      arg => Err(
+        format!(
+          "Cannot parse BITOP command arguments: Syntax error near {}.",
+          arg
+        )
+        .into(),
+      ),
+    }
+  }
+
+  /// Combine two bytes, `NOT` ignoring the left one.
+  fn apply(self, left: u8, right: u8) -> u8 {
+    match self {
+      BitOperation::And => left & right,
+      BitOperation::Or => left | right,
+      BitOperation::Xor => left ^ right,
+      BitOperation::Not => !right,
+    }
+  }
+}
+
+impl fmt::Display for BitOperation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      BitOperation::And => write!(f, "AND"),
+      BitOperation::Or => write!(f, "OR"),
+      BitOperation::Xor => write!(f, "XOR"),
+      BitOperation::Not => write!(f, "NOT"),
+    }
+  }
+}
+
+/// Engine BITOP command.
+#[derive(Clone, Debug)]
+pub struct BitOpCommand {
+  operation: BitOperation,
+  destination: String,
+  keys: Vec<String>,
+}
+
+impl BitOpCommand {
+  /// Return a new [BitOpCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 3 arguments
+  ///   (`AND`, `OR`, `XOR` or `NOT`, destination, key...). `NOT` takes a single key.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::BitOpCommand;
+  ///
+  /// let args = &[&b"and"[..], &b"destination"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = BitOpCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "BITOP AND destination a b");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<BitOpCommand> {
+    check_min_args_len("BITOP", args, 3)?;
+    let operation = BitOperation::parse(args.first().unwrap())?;
+    if operation == BitOperation::Not && args.len() != 3 {
+      return Err("BITOP NOT must be called with a single source key".into());
+    }
+    let keys = args[2..]
+      .iter()
+      .map(|key| parse_string(key))
+      .collect::<Result<Vec<_>>>()?;
+    Ok(BitOpCommand {
+      operation,
+      destination: parse_string(args.get(1).unwrap())?,
+      keys,
+    })
+  }
+
+  /// Combine the values of the command keys, missing keys being empty values.
+  ///
+  /// Shorter values are padded with zero bytes up to the length of the longest value.
+  fn combine(&self, nest: &mut Nest) -> std::result::Result<Vec<u8>, Data> {
+    let mut result: Option<Vec<u8>> = None;
+    for key in &self.keys {
+      let bytes = match nest.get(key).map(|egg| egg.value()) {
+        Some(Value::String(bytes)) => &bytes[..],
+        Some(_) => return Err(Data::Error(WRONG_TYPE_ERROR.to_string())),
+        None => &[],
+      };
+      result = Some(match (result, self.operation) {
+        (None, BitOperation::Not) => bytes
+          .iter()
+          .map(|byte| self.operation.apply(0, *byte))
+          .collect(),
+        (None, _) => bytes.to_vec(),
+        (Some(mut result), operation) => {
+          if result.len() < bytes.len() {
+            result.resize(bytes.len(), 0);
+          }
+          for (index, byte) in result.iter_mut().enumerate() {
+            *byte = operation.apply(*byte, bytes.get(index).copied().unwrap_or(0));
+          }
+          result
+        }
+      });
+    }
+    Ok(result.unwrap_or_default())
+  }
+}
+
+impl fmt::Display for BitOpCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "BITOP {} {} {}",
+      self.operation,
+      self.destination,
+      self.keys.join(" ")
+    )
+  }
+}
+
+impl Command for BitOpCommand {
+  /// Execute the `BITOP AND|OR|XOR|NOT destination key [key ...]` command on a given [Nest].
+  ///
+  /// Store the result at the destination key and return its length in bytes.
+  /// The destination key is overwritten, or removed if the result is empty.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let result = match self.combine(nest) {
+      Ok(result) => result,
+      Err(error) => return error,
+    };
+    let len = result.len();
+    match len {
+      0 => {
+        nest.rem(&self.destination);
+      }
+      _ => nest.set(Egg::new(&self.destination, result)),
+    }
+    Data::Integer(len as i64)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::bitop_command::BitOpCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new("a", &[0b1100_1100, 0xff][..]));
+    nest.set(Egg::new("b", &[0b1010_1010][..]));
+    nest.set(Egg::new("set", HashSet::new()));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "BITOP NOT must be called with a single source key")]
+  fn test_command_new_not_2_keys() {
+    BitOpCommand::new(&[b"NOT", b"destination", b"a", b"b"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse BITOP command arguments: Syntax error near NAND.")]
+  fn test_command_new_invalid_operation() {
+    BitOpCommand::new(&[b"nand", b"destination", b"a", b"b"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    for (args, expected) in [
+      (
+        &[&b"AND"[..], b"destination", b"a", b"b"][..],
+        &[0b1000_1000, 0x00][..],
+      ),
+      (&[b"OR", b"destination", b"a", b"b"], &[0b1110_1110, 0xff]),
+      (&[b"XOR", b"destination", b"a", b"b"], &[0b0110_0110, 0xff]),
+      (&[b"NOT", b"destination", b"b"], &[0b0101_0101]),
+      (&[b"AND", b"destination", b"a", b"missing"], &[0x00, 0x00]),
+    ] {
+      let command = BitOpCommand::new(args).unwrap();
+      assert_eq!(
+        command.execute(&mut nest),
+        Data::Integer(expected.len() as i64)
+      );
+      assert_eq!(
+        nest.get("destination").unwrap().value().as_string(),
+        Some(expected)
+      );
+    }
+
+    let command = BitOpCommand::new(&[b"OR", b"destination", b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert!(nest.get("destination").is_none());
+
+    let command = BitOpCommand::new(&[b"OR", b"destination", b"a", b"set"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -0,0 +1,142 @@
+//! Engine BITPOS command.
+
+use crate::core::commands::args::{check_min_args_len, parse_integer, parse_string};
+use crate::core::commands::bitmap::{find_bit, parse_bit, BitUnit};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine BITPOS command.
+#[derive(Clone, Debug)]
+pub struct BitPosCommand {
+  key: String,
+  bit: u8,
+  start: i64,
+  end: Option<i64>,
+  unit: BitUnit,
+}
+
+impl BitPosCommand {
+  /// Return a new [BitPosCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be from 2 to 5 arguments
+  ///   (key, bit, optional start, optional end, optional `BYTE` or `BIT`).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::BitPosCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"1"[..], &b"2"[..]];
+  /// let cmd = BitPosCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "BITPOS key 1 2");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<BitPosCommand> {
+    check_min_args_len("BITPOS", args, 2)?;
+    if args.len() > 5 {
+      return Err(
+        format!(
+          "Cannot parse BITPOS command arguments: Syntax error near {}.",
+          parse_string(args.get(5).unwrap())?
+        )
+        .into(),
+      );
+    }
+    Ok(BitPosCommand {
+      key: parse_string(args.first().unwrap())?,
+      bit: parse_bit(args.get(1).unwrap())?,
+      start: args.get(2).map_or(Ok(0), |arg| parse_integer(arg))?,
+      end: args.get(3).map(|arg| parse_integer(arg)).transpose()?,
+      unit: match args.get(4) {
+        Some(arg) => BitUnit::parse("BITPOS", arg)?,
+        None => BitUnit::Byte,
+      },
+    })
+  }
+}
+
+impl fmt::Display for BitPosCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "BITPOS {} {} {}", self.key, self.bit, self.start)?;
+    if let Some(end) = self.end {
+      write!(f, " {} {}", end, self.unit)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for BitPosCommand {
+  /// Execute the `BITPOS key bit [start [end [BYTE|BIT]]]` command on a given [Nest].
+  ///
+  /// Return the offset of the first bit equal to `bit` in the given range of the value, or `-1`.
+  /// When looking for a `0` without an `end`, the value is considered padded with zeros
+  /// and the offset following the value is returned if every bit is set.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let bytes = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(bytes)) => bytes,
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::Integer(if self.bit == 0 { 0 } else { -1 }),
+    };
+    let end = self.end.unwrap_or(-1);
+    let (start, end) = match self.unit.resolve_range(self.start, end, bytes.len()) {
+      Some(range) => range,
+      None => return Data::Integer(-1),
+    };
+    match find_bit(bytes, self.bit, start, end) {
+      Some(offset) => Data::Integer(offset as i64),
+      None if self.bit == 0 && self.end.is_none() => Data::Integer(end as i64 + 1),
+      None => Data::Integer(-1),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::bitpos_command::BitPosCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+  const FULL_KEY: &str = "Full key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &[0x00, 0xff, 0xf0][..]));
+    nest.set(Egg::new(FULL_KEY, &[0xff, 0xff][..]));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "The bit argument must be 1 or 0")]
+  fn test_command_new_invalid_bit() {
+    BitPosCommand::new(&[TEST_KEY.as_bytes(), b"2"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    for (args, expected) in [
+      (&[TEST_KEY.as_bytes(), b"1"][..], 8),
+      (&[TEST_KEY.as_bytes(), b"0"], 0),
+      (&[TEST_KEY.as_bytes(), b"0", b"1"], 20),
+      (&[TEST_KEY.as_bytes(), b"1", b"2", b"-1"], 16),
+      (&[TEST_KEY.as_bytes(), b"1", b"7", b"15", b"BIT"], 8),
+      (&[TEST_KEY.as_bytes(), b"1", b"0", b"0"], -1),
+      (&[TEST_KEY.as_bytes(), b"1", b"3", b"-1"], -1),
+      (&[FULL_KEY.as_bytes(), b"0"], 16),
+      (&[FULL_KEY.as_bytes(), b"0", b"0", b"-1"], -1),
+      (&[b"missing", b"0"], 0),
+      (&[b"missing", b"1"], -1),
+    ] {
+      let command = BitPosCommand::new(args).unwrap();
+      assert_eq!(command.execute(&mut nest), Data::Integer(expected));
+    }
+  }
+}
@@ -0,0 +1,368 @@
+//! Engine BLPOP, BRPOP and BLMOVE commands.
+
+use crate::core::commands::args::{check_args_len, check_min_args_len, parse_float, parse_string};
+use crate::core::commands::command::command_data;
+use crate::core::commands::list::ListEnd;
+use crate::core::commands::lmove_command::move_element;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+use std::time::Duration;
+
+/// Longest timeout of a [BlockingCommand], so that its deadline can be computed without overflowing.
+const MAX_TIMEOUT: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+/// Operation performed by a [BlockingCommand] once an element is available.
+#[derive(Clone, Debug, PartialEq)]
+enum BlockingOperation {
+  /// Pop an element from the first non-empty list.
+  Pop(ListEnd),
+  /// Pop an element from the source list and push it to a destination list.
+  Move {
+    destination: String,
+    from: ListEnd,
+    to: ListEnd,
+  },
+}
+
+/// Engine BLPOP, BRPOP and BLMOVE commands.
+///
+/// These commands block the client until an element is available in one of their lists,
+/// or until their timeout is reached. Blocking is handled by the engine: executing the command
+/// on a [Nest] never blocks and returns the timeout reply if no element is available.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockingCommand {
+  keys: Vec<String>,
+  operation: BlockingOperation,
+  timeout: Duration,
+}
+
+impl BlockingCommand {
+  /// Return a new [BlockingCommand] popping an element (`BLPOP` or `BRPOP`).
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key..., timeout).
+  /// * `end` - [ListEnd] where elements are popped
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{BlockingCommand, ListEnd};
+  ///
+  /// let args = &[&b"key"[..], &b"other"[..], &b"1.5"[..]];
+  /// let cmd = BlockingCommand::new_pop(args, ListEnd::Left).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "BLPOP key other 1.5");
+  /// ```
+  pub fn new_pop(args: &[&[u8]], end: ListEnd) -> Result<BlockingCommand> {
+    let operation = BlockingOperation::Pop(end);
+    let name = command_name(&operation);
+    check_min_args_len(name, args, 2)?;
+    let (timeout, keys) = args.split_last().unwrap();
+    Ok(BlockingCommand {
+      keys: keys
+        .iter()
+        .map(|key| parse_string(key))
+        .collect::<Result<_>>()?,
+      timeout: parse_timeout(name, timeout)?,
+      operation,
+    })
+  }
+
+  /// Return a new [BlockingCommand] moving an element (`BLMOVE`).
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 5 arguments
+  ///   (source, destination, `LEFT` or `RIGHT`, `LEFT` or `RIGHT`, timeout).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::BlockingCommand;
+  ///
+  /// let args = &[&b"source"[..], &b"destination"[..], &b"right"[..], &b"left"[..], &b"0"[..]];
+  /// let cmd = BlockingCommand::new_move(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "BLMOVE source destination RIGHT LEFT 0");
+  /// ```
+  pub fn new_move(args: &[&[u8]]) -> Result<BlockingCommand> {
+    check_args_len("BLMOVE", args, 5)?;
+    Ok(BlockingCommand {
+      keys: vec![parse_string(args.first().unwrap())?],
+      operation: BlockingOperation::Move {
+        destination: parse_string(args.get(1).unwrap())?,
+        from: ListEnd::parse("BLMOVE", args.get(2).unwrap())?,
+        to: ListEnd::parse("BLMOVE", args.get(3).unwrap())?,
+      },
+      timeout: parse_timeout("BLMOVE", args.get(4).unwrap())?,
+    })
+  }
+
+  /// Return the keys of the lists this command is waiting on.
+  pub fn keys(&self) -> &[String] {
+    &self.keys
+  }
+
+  /// Return how long this command may block, [None] if it may block indefinitely.
+  pub fn timeout(&self) -> Option<Duration> {
+    (!self.timeout.is_zero()).then_some(self.timeout)
+  }
+
+  /// Return the reply sent to the client once the timeout is reached.
+  pub fn timeout_output(&self) -> Data {
+    match self.operation {
+      BlockingOperation::Pop(_) => Data::NullArray,
+      BlockingOperation::Move { .. } => Data::Null,
+    }
+  }
+
+  /// Try to execute the command on the first list having an element.
+  ///
+  /// Return [None] if every list is empty, meaning that the client should block.
+  ///
+  /// # Arguments
+  /// * `nest` - The nest containing in-memory data
+  pub fn try_execute(&self, nest: &mut Nest) -> Option<Data> {
+    self
+      .keys
+      .iter()
+      .find_map(|key| self.try_execute_key(nest, key))
+  }
+
+  /// Try to execute the command on a single list.
+  ///
+  /// Return [None] if the list is empty, meaning that the client should keep blocking.
+  ///
+  /// # Arguments
+  /// * `nest` - The nest containing in-memory data
+  /// * `key` - Key of the list, one of [BlockingCommand::keys]
+  pub fn try_execute_key(&self, nest: &mut Nest, key: &str) -> Option<Data> {
+    match &self.operation {
+      BlockingOperation::Pop(end) => {
+        let list = match nest.get_mut(key)?.value_mut().as_list_mut() {
+          Some(list) => list,
+          None => return Some(Data::Error(WRONG_TYPE_ERROR.to_string())),
+        };
+        let element = end.pop(list)?;
+        if list.is_empty() {
+          nest.rem(key);
+        }
+        Some(Data::Array(vec![
+          Data::BulkString(key.into()),
+          Data::BulkString(element),
+        ]))
+      }
+      BlockingOperation::Move {
+        destination,
+        from,
+        to,
+      } => match move_element(nest, key, destination, *from, *to) {
+        Data::Null => None,
+        output => Some(output),
+      },
+    }
+  }
+}
+
+/// Return the name of the command performing the given [BlockingOperation].
+fn command_name(operation: &BlockingOperation) -> &'static str {
+  match operation {
+    BlockingOperation::Pop(ListEnd::Left) => "BLPOP",
+    BlockingOperation::Pop(ListEnd::Right) => "BRPOP",
+    BlockingOperation::Move { .. } => "BLMOVE",
+  }
+}
+
+/// Parse a timeout in seconds, `0` meaning no timeout.
+fn parse_timeout(name: &str, arg: &[u8]) -> Result<Duration> {
+  let error = || {
+    format!(
+      "Cannot parse {} command arguments: Timeout is not a float or out of range.",
+      name
+    )
+  };
+  let timeout = parse_float(arg).map_err(|_| error())?;
+  if timeout < 0.0 {
+    return Err(
+      format!(
+        "Cannot parse {} command arguments: Timeout is negative.",
+        name
+      )
+      .into(),
+    );
+  }
+  match Duration::try_from_secs_f64(timeout) {
+    Ok(timeout) if timeout <= MAX_TIMEOUT => Ok(timeout),
+    _ => Err(
+      format!(
+        "Cannot parse {} command arguments: Timeout is out of range.",
+        name
+      )
+      .into(),
+    ),
+  }
+}
+
+impl fmt::Display for BlockingCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} {}",
+      command_name(&self.operation),
+      self.keys.join(" ")
+    )?;
+    if let BlockingOperation::Move {
+      destination,
+      from,
+      to,
+    } = &self.operation
+    {
+      write!(f, " {} {} {}", destination, from, to)?;
+    }
+    write!(f, " {}", self.timeout.as_secs_f64())
+  }
+}
+
+impl Command for BlockingCommand {
+  /// Execute the command on a given [Nest] without blocking.
+  ///
+  /// Return the timeout reply if no element is available.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    self
+      .try_execute(nest)
+      .unwrap_or_else(|| self.timeout_output())
+  }
+
+  /// Rewrite the command as the equivalent non-blocking `LPOP`, `RPOP` or `LMOVE` command.
+  fn to_aof(&self, _input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match (&self.operation, output) {
+      (BlockingOperation::Pop(end), Data::Array(items)) => match items.first() {
+        Some(Data::BulkString(key)) => {
+          let name = match end {
+            ListEnd::Left => "LPOP",
+            ListEnd::Right => "RPOP",
+          };
+          Some(command_data(vec![name.into(), key.clone()]))
+        }
+        _ => None,
+      },
+      (
+        BlockingOperation::Move {
+          destination,
+          from,
+          to,
+        },
+        Data::BulkString(_),
+      ) => Some(command_data(vec![
+        "LMOVE".into(),
+        self.keys.first()?.clone().into_bytes(),
+        destination.clone().into_bytes(),
+        from.to_string().into_bytes(),
+        to.to_string().into_bytes(),
+      ])),
+      _ => None,
+    }
+  }
+
+  fn pushed_keys(&self) -> Vec<&str> {
+    match &self.operation {
+      BlockingOperation::Pop(_) => Vec::new(),
+      BlockingOperation::Move { destination, .. } => vec![destination],
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::blocking_command::BlockingCommand;
+  use crate::core::commands::command::command_data;
+  use crate::core::commands::list::ListEnd;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+  use std::time::Duration;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  #[test]
+  fn test_command_new_timeout() {
+    let command = BlockingCommand::new_pop(&[TEST_KEY.as_bytes(), b"0.5"], ListEnd::Left).unwrap();
+    assert_eq!(command.timeout(), Some(Duration::from_millis(500)));
+    let command = BlockingCommand::new_pop(&[TEST_KEY.as_bytes(), b"0"], ListEnd::Left).unwrap();
+    assert_eq!(command.timeout(), None);
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse BRPOP command arguments: Timeout is negative.")]
+  fn test_command_new_negative_timeout() {
+    BlockingCommand::new_pop(&[TEST_KEY.as_bytes(), b"-1"], ListEnd::Right).unwrap();
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse BLPOP command arguments: Timeout is not a float or out of range."
+  )]
+  fn test_command_new_invalid_timeout() {
+    BlockingCommand::new_pop(&[TEST_KEY.as_bytes(), b"soon"], ListEnd::Left).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse BLPOP command arguments: Timeout is out of range.")]
+  fn test_command_new_huge_timeout() {
+    BlockingCommand::new_pop(&[TEST_KEY.as_bytes(), b"1e19"], ListEnd::Left).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_try_execute_pop(mut nest: Nest) {
+    let args = &[b"missing", TEST_KEY.as_bytes(), b"0"];
+    let command = BlockingCommand::new_pop(args, ListEnd::Right).unwrap();
+    let output = Data::Array(vec![
+      Data::BulkString(TEST_KEY.into()),
+      Data::BulkString("b".into()),
+    ]);
+    assert_eq!(command.try_execute(&mut nest), Some(output.clone()));
+    assert_eq!(
+      command.to_aof(&Data::Null, &output, &nest),
+      Some(command_data(vec!["RPOP".into(), TEST_KEY.into()]))
+    );
+
+    assert!(command.try_execute(&mut nest).is_some());
+    assert_eq!(command.try_execute(&mut nest), None);
+    assert_eq!(command.execute(&mut nest), Data::NullArray);
+  }
+
+  #[rstest]
+  fn test_command_try_execute_move(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), b"other", b"LEFT", b"RIGHT", b"0"];
+    let command = BlockingCommand::new_move(args).unwrap();
+    assert_eq!(
+      command.try_execute(&mut nest),
+      Some(Data::BulkString("a".into()))
+    );
+    assert_eq!(command.pushed_keys(), vec!["other"]);
+    assert_eq!(
+      command.to_aof(&Data::Null, &Data::BulkString("a".into()), &nest),
+      Some(command_data(vec![
+        "LMOVE".into(),
+        TEST_KEY.into(),
+        "other".into(),
+        "LEFT".into(),
+        "RIGHT".into()
+      ]))
+    );
+
+    assert_eq!(command.try_execute_key(&mut nest, "missing"), None);
+  }
+}
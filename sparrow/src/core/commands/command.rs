@@ -1,12 +1,26 @@
 //! Generic engine command interface.
 
-use crate::core::commands::get_command::GetCommand;
-use crate::core::commands::rem_command::RemCommand;
-use crate::core::commands::set_command::SetCommand;
+use crate::core::commands::args::parse_string;
+use crate::core::commands::engine_command::{parse_engine_command, EngineCommand};
+use crate::core::commands::transaction_command::{parse_transaction_command, TransactionCommand};
+use crate::core::commands::{
+  AppendCommand, BitCountCommand, BitFieldCommand, BitOpCommand, BitPosCommand, CopyCommand,
+  DbSizeCommand, ExistsCommand, Expiration, ExpireCommand, GetBitCommand, GetCommand,
+  GetDelCommand, GetExCommand, GetRangeCommand, GetSetCommand, HDelCommand, HExistsCommand,
+  HGetAllCommand, HGetCommand, HIncrByCommand, HLenCommand, HMGetCommand, HSetCommand, HashPart,
+  IncrByFloatCommand, IncrCommand, IncrOperation, KeysCommand, LIndexCommand, LInsertCommand,
+  LLenCommand, LMoveCommand, LRangeCommand, LRemCommand, LSetCommand, LTrimCommand, ListEnd,
+  MGetCommand, MSetCommand, PersistCommand, PopCommand, PushCommand, RandomKeyCommand, RemCommand,
+  RenameCommand, SAddCommand, SCardCommand, SIsMemberCommand, SMIsMemberCommand, SMembersCommand,
+  SPopCommand, SRandMemberCommand, SRemCommand, ScanCommand, ScoreEnd, SetBitCommand, SetCommand,
+  SetNxCommand, SetOperation, SetOperationCommand, SetRangeCommand, StrLenCommand, TimeUnit,
+  TtlCommand, TypeCommand, ZAddCommand, ZCardCommand, ZCountCommand, ZIncrByCommand, ZPopCommand,
+  ZRangeCommand, ZRankCommand, ZRemCommand, ZScoreCommand,
+};
 use crate::core::nest::Nest;
 use crate::errors::Result;
 use sparrow_resp::Data;
-use std::fmt::{Debug, Display};
+use std::fmt::{self, Debug, Display};
 
 /// Trait shared by all engine commands.
 pub trait Command: Send + Sync + Display + Debug {
@@ -21,80 +35,450 @@ pub trait Command: Send + Sync + Display + Debug {
   /// use crate::core::nest::Nest;
   ///
   /// let nest = Nest::new();
-  /// let command = GetCommand::new(&vec!["key"]);
+  /// let command = GetCommand::new(&[&b"key"[..]]).unwrap();
   /// command.execute(&mut self.nest)
   /// ```
   fn execute(&self, nest: &mut Nest) -> Data;
+
+  /// Return the [Data] to append to the append-only file once the command has been executed.
+  ///
+  /// Commands that do not mutate the [Nest] return [None], which is the default.
+  /// Commands whose replay would not be deterministic (e.g. relative expiration times)
+  /// return a rewritten command, built from the state they left the [Nest] in.
+  ///
+  /// # Arguments
+  /// * `input` - The [Data] this command has been parsed from
+  /// * `output` - The [Data] returned by [Command::execute]
+  /// * `nest` - The [Nest] the command has just been executed on
+  fn to_aof(&self, _input: &Data, _output: &Data, _nest: &Nest) -> Option<Data> {
+    None
+  }
+
+  /// Return the keys of the lists this command may have pushed elements to.
+  ///
+  /// Clients blocked on these keys are served once the command has been executed.
+  /// Commands that do not push elements to lists return no key, which is the default.
+  fn pushed_keys(&self) -> Vec<&str> {
+    Vec::new()
+  }
 }
 
-pub fn parse_command(input: &Data) -> Result<Box<dyn Command>> {
-  match input {
-    Data::BulkString(input) => parse_string_command(input),
-    _ => Err("Cannot parse command: data is not a bulk string".into()),
+/// Build a [Data::Array] of [Data::BulkString] encoding a command.
+///
+/// # Arguments
+/// * `inputs` - Command name followed by its arguments
+pub fn command_data(inputs: Vec<Vec<u8>>) -> Data {
+  Data::Array(inputs.into_iter().map(Data::BulkString).collect())
+}
+
+/// Command parsed from an input, sorted by what it is executed on.
+#[derive(Debug)]
+pub enum ParsedCommand {
+  /// Command controlling the transaction of the client.
+  Transaction(TransactionCommand),
+  /// Command executed by the engine itself.
+  Engine(EngineCommand),
+  /// Command executed on the [Nest] of the selected database.
+  Nest(Box<dyn Command>),
+}
+
+impl Display for ParsedCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParsedCommand::Transaction(command) => write!(f, "{}", command),
+      ParsedCommand::Engine(command) => write!(f, "{}", command),
+      ParsedCommand::Nest(command) => write!(f, "{}", command),
+    }
   }
 }
-/// Parse a string slice into a command.
+
+/// Parse a [Data] into a [ParsedCommand].
+///
+/// The main wire form is a [Data::Array] of [Data::BulkString], as sent by standard Redis clients:
+/// the first element is the command name and the following ones are its arguments.
+/// A single space-separated [Data::BulkString] is still accepted as a fallback.
 ///
-/// This function returns an [Option::Some] containing the Command or [Option::None] if the parsed string slice is `"EXIT"`.
+/// Command names are matched case-insensitively.
 ///
 /// # Arguments
-/// * `input` - Input string slice to be parsed
+/// * `input` - Input data to be parsed
 ///
 /// # Examples
 /// ```rust
 /// use crate::core::commands::parse_command;
+/// use sparrow_resp::Data;
 ///
-/// let cmd = parse_command("GET key").unwrap();
+/// let input = Data::Array(vec![
+///   Data::BulkString("GET".into()),
+///   Data::BulkString("key".into()),
+/// ]);
+/// let cmd = parse_command(&input).unwrap();
 ///
-/// assert_eq!(format!("{}", cmd), "GET {key}");
+/// assert_eq!(format!("{}", cmd), "GET key");
 /// ```
-fn parse_string_command(input: &str) -> Result<Box<dyn Command>> {
-  let inputs = input.split(' ').collect::<Vec<&str>>();
-  match inputs.get(0) {
-    Some(name) => {
-      let args = &inputs[1..];
-      match *name {
-        "GET" => Ok(Box::new(GetCommand::new(args)?)),
-        "SET" => Ok(Box::new(SetCommand::new(args)?)),
-        "REM" => Ok(Box::new(RemCommand::new(args)?)),
-        unknown => Err(format!("Command not found: {}", unknown).into()),
-      }
-    }
-    None => Err("Command not parsable: Input string not space-separated".into()),
+pub fn parse_command(input: &Data) -> Result<ParsedCommand> {
+  let inputs = parse_inputs(input)?;
+  if let Some(command) = parse_transaction_command(&inputs) {
+    return command.map(ParsedCommand::Transaction);
+  }
+  if let Some(command) = parse_engine_command(&inputs) {
+    return command.map(ParsedCommand::Engine);
+  }
+  parse_args_command(&inputs).map(ParsedCommand::Nest)
+}
+
+/// Split a [Data] into raw inputs: the command name followed by its arguments.
+///
+/// See [parse_command] for the accepted wire forms.
+///
+/// # Arguments
+/// * `input` - Input data to be split
+pub fn parse_inputs(input: &Data) -> Result<Vec<&[u8]>> {
+  match input {
+    Data::Array(array) => array
+      .iter()
+      .map(|data| match data {
+        Data::BulkString(input) => Ok(input.as_slice()),
+        _ => Err("Cannot parse command: array element is not a bulk string".into()),
+      })
+      .collect(),
+    Data::BulkString(input) => Ok(input.split(|byte| *byte == b' ').collect()),
+    _ => Err("Cannot parse command: data is not an array of bulk strings".into()),
+  }
+}
+
+/// Parse a list of raw arguments into a command.
+///
+/// The first argument is the command name and the following ones are the command arguments.
+///
+/// # Arguments
+/// * `inputs` - Command name followed by its arguments
+pub fn parse_args_command(inputs: &[&[u8]]) -> Result<Box<dyn Command>> {
+  match inputs.split_first() {
+    Some((name, args)) => match parse_string(name)?.to_uppercase().as_str() {
+      "GET" => Ok(Box::new(GetCommand::new(args)?)),
+      "SET" => Ok(Box::new(SetCommand::new(args)?)),
+      "SETNX" => Ok(Box::new(SetNxCommand::new(args)?)),
+      "GETSET" => Ok(Box::new(GetSetCommand::new(args)?)),
+      "GETDEL" => Ok(Box::new(GetDelCommand::new(args)?)),
+      "GETEX" => Ok(Box::new(GetExCommand::new(args)?)),
+      "APPEND" => Ok(Box::new(AppendCommand::new(args)?)),
+      "STRLEN" => Ok(Box::new(StrLenCommand::new(args)?)),
+      "GETRANGE" | "SUBSTR" => Ok(Box::new(GetRangeCommand::new(args)?)),
+      "SETRANGE" => Ok(Box::new(SetRangeCommand::new(args)?)),
+      "SETBIT" => Ok(Box::new(SetBitCommand::new(args)?)),
+      "GETBIT" => Ok(Box::new(GetBitCommand::new(args)?)),
+      "BITCOUNT" => Ok(Box::new(BitCountCommand::new(args)?)),
+      "BITOP" => Ok(Box::new(BitOpCommand::new(args)?)),
+      "BITPOS" => Ok(Box::new(BitPosCommand::new(args)?)),
+      "BITFIELD" => Ok(Box::new(BitFieldCommand::new(args)?)),
+      "REM" | "DEL" => Ok(Box::new(RemCommand::new(args)?)),
+      "MGET" => Ok(Box::new(MGetCommand::new(args)?)),
+      "MSET" => Ok(Box::new(MSetCommand::new(args, false)?)),
+      "MSETNX" => Ok(Box::new(MSetCommand::new(args, true)?)),
+      "EXISTS" => Ok(Box::new(ExistsCommand::new(args)?)),
+      "RENAME" => Ok(Box::new(RenameCommand::new(args, false)?)),
+      "RENAMENX" => Ok(Box::new(RenameCommand::new(args, true)?)),
+      "COPY" => Ok(Box::new(CopyCommand::new(args)?)),
+      "KEYS" => Ok(Box::new(KeysCommand::new(args)?)),
+      "SCAN" => Ok(Box::new(ScanCommand::new(args)?)),
+      "DBSIZE" => Ok(Box::new(DbSizeCommand::new(args)?)),
+      "TYPE" => Ok(Box::new(TypeCommand::new(args)?)),
+      "RANDOMKEY" => Ok(Box::new(RandomKeyCommand::new(args)?)),
+      "EXPIRE" => Ok(Box::new(ExpireCommand::new(args, Expiration::Seconds)?)),
+      "PEXPIRE" => Ok(Box::new(ExpireCommand::new(
+        args,
+        Expiration::Milliseconds,
+      )?)),
+      "EXPIREAT" => Ok(Box::new(ExpireCommand::new(args, Expiration::UnixSeconds)?)),
+      "PEXPIREAT" => Ok(Box::new(ExpireCommand::new(
+        args,
+        Expiration::UnixMilliseconds,
+      )?)),
+      "TTL" => Ok(Box::new(TtlCommand::new(args, TimeUnit::Seconds)?)),
+      "PTTL" => Ok(Box::new(TtlCommand::new(args, TimeUnit::Milliseconds)?)),
+      "PERSIST" => Ok(Box::new(PersistCommand::new(args)?)),
+      "INCR" => Ok(Box::new(IncrCommand::new(args, IncrOperation::Incr)?)),
+      "DECR" => Ok(Box::new(IncrCommand::new(args, IncrOperation::Decr)?)),
+      "INCRBY" => Ok(Box::new(IncrCommand::new(args, IncrOperation::IncrBy)?)),
+      "DECRBY" => Ok(Box::new(IncrCommand::new(args, IncrOperation::DecrBy)?)),
+      "INCRBYFLOAT" => Ok(Box::new(IncrByFloatCommand::new(args)?)),
+      "HSET" => Ok(Box::new(HSetCommand::new(args)?)),
+      "HGET" => Ok(Box::new(HGetCommand::new(args)?)),
+      "HMGET" => Ok(Box::new(HMGetCommand::new(args)?)),
+      "HDEL" => Ok(Box::new(HDelCommand::new(args)?)),
+      "HEXISTS" => Ok(Box::new(HExistsCommand::new(args)?)),
+      "HLEN" => Ok(Box::new(HLenCommand::new(args)?)),
+      "HKEYS" => Ok(Box::new(HGetAllCommand::new(args, HashPart::Fields)?)),
+      "HVALS" => Ok(Box::new(HGetAllCommand::new(args, HashPart::Values)?)),
+      "HGETALL" => Ok(Box::new(HGetAllCommand::new(args, HashPart::Entries)?)),
+      "HINCRBY" => Ok(Box::new(HIncrByCommand::new(args)?)),
+      "LPUSH" => Ok(Box::new(PushCommand::new(args, ListEnd::Left)?)),
+      "RPUSH" => Ok(Box::new(PushCommand::new(args, ListEnd::Right)?)),
+      "LPOP" => Ok(Box::new(PopCommand::new(args, ListEnd::Left)?)),
+      "RPOP" => Ok(Box::new(PopCommand::new(args, ListEnd::Right)?)),
+      "LRANGE" => Ok(Box::new(LRangeCommand::new(args)?)),
+      "LLEN" => Ok(Box::new(LLenCommand::new(args)?)),
+      "LINDEX" => Ok(Box::new(LIndexCommand::new(args)?)),
+      "LSET" => Ok(Box::new(LSetCommand::new(args)?)),
+      "LREM" => Ok(Box::new(LRemCommand::new(args)?)),
+      "LTRIM" => Ok(Box::new(LTrimCommand::new(args)?)),
+      "LINSERT" => Ok(Box::new(LInsertCommand::new(args)?)),
+      "LMOVE" => Ok(Box::new(LMoveCommand::new(args)?)),
+      "SADD" => Ok(Box::new(SAddCommand::new(args)?)),
+      "SREM" => Ok(Box::new(SRemCommand::new(args)?)),
+      "SISMEMBER" => Ok(Box::new(SIsMemberCommand::new(args)?)),
+      "SMISMEMBER" => Ok(Box::new(SMIsMemberCommand::new(args)?)),
+      "SMEMBERS" => Ok(Box::new(SMembersCommand::new(args)?)),
+      "SCARD" => Ok(Box::new(SCardCommand::new(args)?)),
+      "SPOP" => Ok(Box::new(SPopCommand::new(args)?)),
+      "SRANDMEMBER" => Ok(Box::new(SRandMemberCommand::new(args)?)),
+      "SINTER" => Ok(Box::new(SetOperationCommand::new(
+        args,
+        SetOperation::Inter,
+        false,
+      )?)),
+      "SUNION" => Ok(Box::new(SetOperationCommand::new(
+        args,
+        SetOperation::Union,
+        false,
+      )?)),
+      "SDIFF" => Ok(Box::new(SetOperationCommand::new(
+        args,
+        SetOperation::Diff,
+        false,
+      )?)),
+      "SINTERSTORE" => Ok(Box::new(SetOperationCommand::new(
+        args,
+        SetOperation::Inter,
+        true,
+      )?)),
+      "SUNIONSTORE" => Ok(Box::new(SetOperationCommand::new(
+        args,
+        SetOperation::Union,
+        true,
+      )?)),
+      "SDIFFSTORE" => Ok(Box::new(SetOperationCommand::new(
+        args,
+        SetOperation::Diff,
+        true,
+      )?)),
+      "ZADD" => Ok(Box::new(ZAddCommand::new(args)?)),
+      "ZSCORE" => Ok(Box::new(ZScoreCommand::new(args)?)),
+      "ZINCRBY" => Ok(Box::new(ZIncrByCommand::new(args)?)),
+      "ZRANK" => Ok(Box::new(ZRankCommand::new(args)?)),
+      "ZRANGE" => Ok(Box::new(ZRangeCommand::new(args)?)),
+      "ZREM" => Ok(Box::new(ZRemCommand::new(args)?)),
+      "ZCARD" => Ok(Box::new(ZCardCommand::new(args)?)),
+      "ZCOUNT" => Ok(Box::new(ZCountCommand::new(args)?)),
+      "ZPOPMIN" => Ok(Box::new(ZPopCommand::new(args, ScoreEnd::Min)?)),
+      "ZPOPMAX" => Ok(Box::new(ZPopCommand::new(args, ScoreEnd::Max)?)),
+      _ => Err(format!("Command not found: {}", String::from_utf8_lossy(name)).into()),
+    },
+    None => Err("Cannot parse command: empty command".into()),
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::core::commands::parse_command;
+  use crate::core::commands::{parse_command, EngineCommand, ParsedCommand, TransactionCommand};
   use sparrow_resp::Data;
 
+  fn array(inputs: &[&str]) -> Data {
+    Data::Array(
+      inputs
+        .iter()
+        .map(|input| Data::BulkString(input.as_bytes().to_vec()))
+        .collect(),
+    )
+  }
+
+  #[test]
+  fn test_parse_command_kind() {
+    assert!(matches!(
+      parse_command(&array(&["MULTI"])).unwrap(),
+      ParsedCommand::Transaction(TransactionCommand::Multi)
+    ));
+    assert!(matches!(
+      parse_command(&array(&["SAVE"])).unwrap(),
+      ParsedCommand::Engine(EngineCommand::Save)
+    ));
+    assert!(matches!(
+      parse_command(&array(&["GET", "key"])).unwrap(),
+      ParsedCommand::Nest(_)
+    ));
+  }
+
   #[test]
   fn test_parse_command_valid() {
-    let get_cmd = parse_command(&Data::BulkString("GET key".to_string())).unwrap();
+    let get_cmd = parse_command(&array(&["GET", "key"])).unwrap();
+    assert_eq!(format!("{}", get_cmd), "GET key");
+
+    let set_cmd = parse_command(&array(&["SET", "key", "some value"])).unwrap();
+    assert_eq!(format!("{}", set_cmd), "SET key some value");
+
+    let set_nx_cmd = parse_command(&array(&["SET", "key", "value", "nx", "px", "10"])).unwrap();
+    assert_eq!(format!("{}", set_nx_cmd), "SET key value NX PX 10");
+
+    let getex_cmd = parse_command(&array(&["GETEX", "key", "persist"])).unwrap();
+    assert_eq!(format!("{}", getex_cmd), "GETEX key PERSIST");
+
+    let substr_cmd = parse_command(&array(&["SUBSTR", "key", "0", "-1"])).unwrap();
+    assert_eq!(format!("{}", substr_cmd), "GETRANGE key 0 -1");
+
+    let setbit_cmd = parse_command(&array(&["SETBIT", "key", "7", "1"])).unwrap();
+    assert_eq!(format!("{}", setbit_cmd), "SETBIT key 7 1");
+
+    let bitop_cmd = parse_command(&array(&["bitop", "xor", "c", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", bitop_cmd), "BITOP XOR c a b");
+
+    let bitfield_cmd = parse_command(&array(&["BITFIELD", "key", "get", "i8", "#2"])).unwrap();
+    assert_eq!(format!("{}", bitfield_cmd), "BITFIELD key GET i8 16");
+
+    let rem_cmd = parse_command(&array(&["REM", "key"])).unwrap();
+    assert_eq!(format!("{}", rem_cmd), "REM key");
+
+    let del_cmd = parse_command(&array(&["DEL", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", del_cmd), "REM a b");
+
+    let mset_cmd = parse_command(&array(&["MSET", "a", "1", "b", "2"])).unwrap();
+    assert_eq!(format!("{}", mset_cmd), "MSET a 1 b 2");
+
+    let exists_cmd = parse_command(&array(&["exists", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", exists_cmd), "EXISTS a b");
+
+    let renamenx_cmd = parse_command(&array(&["renamenx", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", renamenx_cmd), "RENAMENX a b");
+
+    let scan_cmd = parse_command(&array(&["scan", "0", "type", "zset"])).unwrap();
+    assert_eq!(format!("{}", scan_cmd), "SCAN 0 TYPE zset");
+
+    let dbsize_cmd = parse_command(&array(&["DBSIZE"])).unwrap();
+    assert_eq!(format!("{}", dbsize_cmd), "DBSIZE");
+
+    let expire_cmd = parse_command(&array(&["EXPIRE", "key", "10"])).unwrap();
+    assert_eq!(format!("{}", expire_cmd), "EXPIRE key 10");
+
+    let pexpireat_cmd = parse_command(&array(&["PEXPIREAT", "key", "10"])).unwrap();
+    assert_eq!(format!("{}", pexpireat_cmd), "PEXPIREAT key 10");
+
+    let pttl_cmd = parse_command(&array(&["PTTL", "key"])).unwrap();
+    assert_eq!(format!("{}", pttl_cmd), "PTTL key");
+
+    let persist_cmd = parse_command(&array(&["PERSIST", "key"])).unwrap();
+    assert_eq!(format!("{}", persist_cmd), "PERSIST key");
+
+    let incrby_cmd = parse_command(&array(&["incrby", "key", "-3"])).unwrap();
+    assert_eq!(format!("{}", incrby_cmd), "INCRBY key -3");
+
+    let hset_cmd = parse_command(&array(&["HSET", "key", "field", "value"])).unwrap();
+    assert_eq!(format!("{}", hset_cmd), "HSET key field value");
+
+    let hmget_cmd = parse_command(&array(&["HMGET", "key", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", hmget_cmd), "HMGET key a b");
+
+    let hgetall_cmd = parse_command(&array(&["HGETALL", "key"])).unwrap();
+    assert_eq!(format!("{}", hgetall_cmd), "HGETALL key");
+
+    let hincrby_cmd = parse_command(&array(&["HINCRBY", "key", "field", "1"])).unwrap();
+    assert_eq!(format!("{}", hincrby_cmd), "HINCRBY key field 1");
+
+    let rpush_cmd = parse_command(&array(&["RPUSH", "key", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", rpush_cmd), "RPUSH key a b");
+
+    let lpop_cmd = parse_command(&array(&["LPOP", "key"])).unwrap();
+    assert_eq!(format!("{}", lpop_cmd), "LPOP key");
+
+    let lrange_cmd = parse_command(&array(&["LRANGE", "key", "0", "-1"])).unwrap();
+    assert_eq!(format!("{}", lrange_cmd), "LRANGE key 0 -1");
+
+    let linsert_cmd = parse_command(&array(&["LINSERT", "key", "after", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", linsert_cmd), "LINSERT key AFTER a b");
+
+    let lmove_cmd = parse_command(&array(&["LMOVE", "a", "b", "left", "right"])).unwrap();
+    assert_eq!(format!("{}", lmove_cmd), "LMOVE a b LEFT RIGHT");
+
+    let sadd_cmd = parse_command(&array(&["SADD", "key", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", sadd_cmd), "SADD key a b");
+
+    let sinter_cmd = parse_command(&array(&["sinter", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", sinter_cmd), "SINTER a b");
+
+    let sdiffstore_cmd = parse_command(&array(&["SDIFFSTORE", "c", "a", "b"])).unwrap();
+    assert_eq!(format!("{}", sdiffstore_cmd), "SDIFFSTORE c a b");
+
+    let zadd_cmd = parse_command(&array(&["ZADD", "key", "xx", "1", "a"])).unwrap();
+    assert_eq!(format!("{}", zadd_cmd), "ZADD key XX 1 a");
+
+    let zrange_cmd = parse_command(&array(&["ZRANGE", "key", "0", "-1", "withscores"])).unwrap();
+    assert_eq!(format!("{}", zrange_cmd), "ZRANGE key 0 -1 WITHSCORES");
+
+    let zpopmin_cmd = parse_command(&array(&["zpopmin", "key"])).unwrap();
+    assert_eq!(format!("{}", zpopmin_cmd), "ZPOPMIN key");
+  }
+
+  #[test]
+  fn test_parse_command_case_insensitive() {
+    let get_cmd = parse_command(&array(&["get", "key"])).unwrap();
     assert_eq!(format!("{}", get_cmd), "GET key");
 
-    let set_cmd = parse_command(&Data::BulkString("SET key value".to_string())).unwrap();
+    let set_cmd = parse_command(&array(&["sEt", "key", "value"])).unwrap();
     assert_eq!(format!("{}", set_cmd), "SET key value");
+  }
+
+  #[test]
+  fn test_parse_command_bulk_string_fallback() {
+    let get_cmd = parse_command(&Data::BulkString("GET key".into())).unwrap();
+    assert_eq!(format!("{}", get_cmd), "GET key");
 
-    let rem_cmd = parse_command(&Data::BulkString("REM key".to_string())).unwrap();
+    let set_cmd = parse_command(&Data::BulkString("set key value".into())).unwrap();
+    assert_eq!(format!("{}", set_cmd), "SET key value");
+
+    let rem_cmd = parse_command(&Data::BulkString("REM key".into())).unwrap();
     assert_eq!(format!("{}", rem_cmd), "REM key");
   }
 
+  #[test]
+  fn test_parse_command_binary_value() {
+    let set_cmd = parse_command(&Data::Array(vec![
+      Data::BulkString("SET".into()),
+      Data::BulkString("key".into()),
+      Data::BulkString(vec![0x00, 0xff, b' ', 0x89]),
+    ]))
+    .unwrap();
+    assert_eq!(format!("{}", set_cmd), "SET key \u{0}\u{FFFD} \u{FFFD}");
+  }
+
   #[test]
   #[should_panic(expected = "Command not found: TOTO")]
   fn test_parse_command_unknown() {
-    parse_command(&Data::BulkString("TOTO key".to_string())).unwrap();
+    parse_command(&array(&["TOTO", "key"])).unwrap();
   }
 
   #[test]
   #[should_panic(expected = "Command not found:")]
   fn test_parse_command_empty() {
-    parse_command(&Data::BulkString("".to_string())).unwrap();
+    parse_command(&Data::BulkString("".into())).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse command: empty command")]
+  fn test_parse_command_empty_array() {
+    parse_command(&Data::Array(vec![])).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse command: array element is not a bulk string")]
+  fn test_parse_command_array_not_bulk_string() {
+    parse_command(&Data::Array(vec![
+      Data::BulkString("GET".into()),
+      Data::Integer(1),
+    ]))
+    .unwrap();
   }
 
   #[test]
-  #[should_panic(expected = "Cannot parse command: data is not a bulk string")]
+  #[should_panic(expected = "Cannot parse command: data is not an array of bulk strings")]
   fn test_parse_command_null() {
     parse_command(&Data::Null).unwrap();
   }
@@ -0,0 +1,169 @@
+//! Engine COPY command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine COPY command.
+#[derive(Clone, Debug)]
+pub struct CopyCommand {
+  source: String,
+  destination: String,
+  replace: bool,
+}
+
+impl CopyCommand {
+  /// Return a new [CopyCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 or 3 arguments
+  ///   (source, destination, optional `REPLACE`).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::CopyCommand;
+  ///
+  /// let args = &[&b"source"[..], &b"destination"[..], &b"replace"[..]];
+  /// let cmd = CopyCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "COPY source destination REPLACE");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<CopyCommand> {
+    check_min_args_len("COPY", args, 2)?;
+    let mut replace = false;
+    for arg in &args[2..] {
+      match parse_string(arg)?.to_uppercase().as_str() {
+        "REPLACE" => replace = true,
+        arg => {
+          return Err(
+            format!(
+              "Cannot parse COPY command arguments: Syntax error near {}.",
+              arg
+            )
+            .into(),
+          )
+        }
+      }
+    }
+    Ok(CopyCommand {
+      source: parse_string(args.first().unwrap())?,
+      destination: parse_string(args.get(1).unwrap())?,
+      replace,
+    })
+  }
+}
+
+impl fmt::Display for CopyCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "COPY {} {}", self.source, self.destination)?;
+    if self.replace {
+      write!(f, " REPLACE")?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for CopyCommand {
+  /// Execute the `COPY source destination [REPLACE]` command on a given [Nest].
+  ///
+  /// The copy is a new [Egg] with the value and expiration date of the source.
+  /// Return `1` if the key was copied, `0` if the source does not exist or if the destination
+  /// already exists without `REPLACE`.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    if self.source == self.destination {
+      return Data::Error("Source and destination objects are the same".to_string());
+    }
+    if !self.replace && nest.get(&self.destination).is_some() {
+      return Data::Integer(0);
+    }
+    let copy = match nest.get(&self.source) {
+      Some(egg) => {
+        let mut copy = Egg::new(&self.destination, egg.value().clone());
+        copy.set_expires_at(egg.expires_at().cloned());
+        copy
+      }
+      None => return Data::Integer(0),
+    };
+    nest.set(copy);
+    Data::Integer(1)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(1) => Some(input.clone()),
+      _ => None,
+    }
+  }
+
+  fn pushed_keys(&self) -> Vec<&str> {
+    vec![&self.destination]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::copy_command::CopyCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut egg = Egg::new("source", &b"value"[..]);
+    egg.set_expires_at(Some(Utc::now() + Duration::seconds(60)));
+    let mut nest = Nest::new();
+    nest.set(egg);
+    nest.set(Egg::new("other", &b"other"[..]));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse COPY command arguments: Syntax error near OVERWRITE.")]
+  fn test_command_new_invalid_option() {
+    CopyCommand::new(&[b"source", b"destination", b"overwrite"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = CopyCommand::new(&[b"source", b"destination"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    let source = nest.get("source").unwrap().clone();
+    let copy = nest.get("destination").unwrap();
+    assert_eq!(copy.value(), source.value());
+    assert_eq!(copy.expires_at(), source.expires_at());
+
+    let command = CopyCommand::new(&[b"source", b"other"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert_eq!(
+      nest.get("other").unwrap().value().as_string(),
+      Some(&b"other"[..])
+    );
+
+    let command = CopyCommand::new(&[b"source", b"other", b"REPLACE"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(
+      nest.get("other").unwrap().value().as_string(),
+      Some(&b"value"[..])
+    );
+
+    let command = CopyCommand::new(&[b"missing", b"destination", b"REPLACE"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert!(nest.get("destination").is_some());
+  }
+
+  #[rstest]
+  fn test_command_execute_same_key(mut nest: Nest) {
+    let command = CopyCommand::new(&[b"source", b"source"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Source and destination objects are the same".to_string())
+    );
+  }
+}
@@ -0,0 +1,282 @@
+//! Engine SELECT, FLUSHDB, FLUSHALL, SWAPDB and MOVE commands.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Engine SELECT, FLUSHDB, FLUSHALL, SWAPDB and MOVE commands.
+///
+/// These commands manage the numbered [Nest]s used as databases rather than a single [Nest].
+/// Database indexes are checked against the number of databases when the command is executed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DatabaseCommand {
+  /// Select the database used by the following commands of the client.
+  Select(usize),
+  /// Remove every key of the selected database.
+  FlushDb,
+  /// Remove every key of every database.
+  FlushAll,
+  /// Swap the content of two databases.
+  SwapDb(usize, usize),
+  /// Move a key from the selected database to another one.
+  Move { key: String, db: usize },
+}
+
+impl DatabaseCommand {
+  /// Return a new `SELECT` [DatabaseCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (index).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::DatabaseCommand;
+  ///
+  /// let cmd = DatabaseCommand::new_select(&[b"2"]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SELECT 2");
+  /// ```
+  pub fn new_select(args: &[&[u8]]) -> Result<DatabaseCommand> {
+    check_args_len("SELECT", args, 1)?;
+    Ok(DatabaseCommand::Select(parse_db_index(args[0])?))
+  }
+
+  /// Return a new `SWAPDB` [DatabaseCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (index1, index2).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::DatabaseCommand;
+  ///
+  /// let cmd = DatabaseCommand::new_swapdb(&[b"0", b"1"]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SWAPDB 0 1");
+  /// ```
+  pub fn new_swapdb(args: &[&[u8]]) -> Result<DatabaseCommand> {
+    check_args_len("SWAPDB", args, 2)?;
+    Ok(DatabaseCommand::SwapDb(
+      parse_db_index(args[0])?,
+      parse_db_index(args[1])?,
+    ))
+  }
+
+  /// Return a new `MOVE` [DatabaseCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, db).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::DatabaseCommand;
+  ///
+  /// let cmd = DatabaseCommand::new_move(&[b"key", b"1"]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "MOVE key 1");
+  /// ```
+  pub fn new_move(args: &[&[u8]]) -> Result<DatabaseCommand> {
+    check_args_len("MOVE", args, 2)?;
+    Ok(DatabaseCommand::Move {
+      key: parse_string(args[0])?,
+      db: parse_db_index(args[1])?,
+    })
+  }
+
+  /// Execute the command on numbered [Nest]s.
+  ///
+  /// `SELECT` updates the selected database, the other commands return `OK`,
+  /// except `MOVE` which returns 1 if the key was moved, 0 if it does not exist
+  /// or already exists in the destination database.
+  /// The moved [Egg] keeps its creation and expiration dates.
+  ///
+  /// # Arguments
+  /// * `nests` - [Nest]s indexed by database
+  /// * `db` - Index of the database selected by the client
+  ///
+  /// [Egg]: crate::core::egg::Egg
+  pub fn execute(&self, nests: &mut [Nest], db: &mut usize) -> Data {
+    let out_of_range = |index: usize| index >= nests.len();
+    match self {
+      DatabaseCommand::Select(index) => {
+        if out_of_range(*index) {
+          return Data::Error(OUT_OF_RANGE_ERROR.to_string());
+        }
+        *db = *index;
+      }
+      DatabaseCommand::FlushDb => nests[*db] = Nest::new(),
+      DatabaseCommand::FlushAll => nests.iter_mut().for_each(|nest| *nest = Nest::new()),
+      DatabaseCommand::SwapDb(first, second) => {
+        if out_of_range(*first) || out_of_range(*second) {
+          return Data::Error(OUT_OF_RANGE_ERROR.to_string());
+        }
+        nests.swap(*first, *second);
+      }
+      DatabaseCommand::Move {
+        key,
+        db: destination,
+      } => {
+        if out_of_range(*destination) {
+          return Data::Error(OUT_OF_RANGE_ERROR.to_string());
+        }
+        if *destination == *db {
+          return Data::Error("Source and destination objects are the same".to_string());
+        }
+        if nests[*destination].get(key).is_some() {
+          return Data::Integer(0);
+        }
+        return match nests[*db].take(key) {
+          Some(egg) => {
+            nests[*destination].set(egg);
+            Data::Integer(1)
+          }
+          None => Data::Integer(0),
+        };
+      }
+    }
+    Data::SimpleString("OK".to_string())
+  }
+}
+
+/// Error returned when a database index is negative or above the number of databases.
+const OUT_OF_RANGE_ERROR: &str = "DB index is out of range";
+
+/// Parse a database index.
+fn parse_db_index(arg: &[u8]) -> Result<usize> {
+  usize::try_from(parse_integer(arg)?).map_err(|_| OUT_OF_RANGE_ERROR.into())
+}
+
+impl fmt::Display for DatabaseCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      DatabaseCommand::Select(db) => write!(f, "SELECT {}", db),
+      DatabaseCommand::FlushDb => write!(f, "FLUSHDB"),
+      DatabaseCommand::FlushAll => write!(f, "FLUSHALL"),
+      DatabaseCommand::SwapDb(first, second) => write!(f, "SWAPDB {} {}", first, second),
+      DatabaseCommand::Move { key, db } => write!(f, "MOVE {} {}", key, db),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::database_command::DatabaseCommand;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const OK: &str = "OK";
+
+  #[fixture]
+  fn nests() -> Vec<Nest> {
+    let mut nests = vec![Nest::new(), Nest::new(), Nest::new()];
+    nests[0].set(Egg::new("key", &b"first"[..]));
+    nests[0].set(Egg::new("other", &b"first"[..]));
+    nests[1].set(Egg::new("key", &b"second"[..]));
+    nests
+  }
+
+  #[test]
+  fn test_command_new() {
+    for (args, expected) in [
+      (
+        DatabaseCommand::new_select(&[b"-1"]),
+        "DB index is out of range",
+      ),
+      (
+        DatabaseCommand::new_swapdb(&[b"0", b"a"]),
+        "Value is not an integer or out of range",
+      ),
+      (
+        DatabaseCommand::new_move(&[b"key"]),
+        "Cannot parse MOVE command arguments: Wrong number of arguments. Expected 2, got 1.",
+      ),
+    ] {
+      assert_eq!(format!("{}", args.err().unwrap()), expected);
+    }
+  }
+
+  #[rstest]
+  fn test_command_execute_select(mut nests: Vec<Nest>) {
+    let mut db = 0;
+    let command = DatabaseCommand::new_select(&[b"2"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nests, &mut db),
+      Data::SimpleString(OK.to_string())
+    );
+    assert_eq!(db, 2);
+
+    let command = DatabaseCommand::new_select(&[b"3"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nests, &mut db),
+      Data::Error("DB index is out of range".to_string())
+    );
+    assert_eq!(db, 2);
+  }
+
+  #[rstest]
+  fn test_command_execute_flush(mut nests: Vec<Nest>) {
+    let mut db = 1;
+    DatabaseCommand::FlushDb.execute(&mut nests, &mut db);
+    assert_eq!(nests[0].len(), 2);
+    assert_eq!(nests[1].len(), 0);
+
+    DatabaseCommand::FlushAll.execute(&mut nests, &mut db);
+    assert!(nests.iter().all(|nest| nest.len() == 0));
+  }
+
+  #[rstest]
+  fn test_command_execute_swapdb(mut nests: Vec<Nest>) {
+    let mut db = 0;
+    let command = DatabaseCommand::new_swapdb(&[b"0", b"2"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nests, &mut db),
+      Data::SimpleString(OK.to_string())
+    );
+    assert_eq!(nests[0].len(), 0);
+    assert_eq!(nests[2].len(), 2);
+    assert_eq!(db, 0);
+
+    let command = DatabaseCommand::new_swapdb(&[b"0", b"3"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nests, &mut db),
+      Data::Error("DB index is out of range".to_string())
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_move(mut nests: Vec<Nest>) {
+    let mut db = 0;
+    let expires_at = Utc::now() + Duration::seconds(60);
+    nests[0].expire("other", Some(expires_at));
+
+    let command = DatabaseCommand::new_move(&[b"other", b"1"]).unwrap();
+    assert_eq!(command.execute(&mut nests, &mut db), Data::Integer(1));
+    assert!(nests[0].get("other").is_none());
+    assert_eq!(
+      nests[1].get("other").unwrap().expires_at(),
+      Some(&expires_at)
+    );
+
+    // Destination already has the key, or source does not
+    for key in [&b"key"[..], b"missing"] {
+      let command = DatabaseCommand::new_move(&[key, b"1"]).unwrap();
+      assert_eq!(command.execute(&mut nests, &mut db), Data::Integer(0));
+    }
+    assert_eq!(
+      nests[0].get("key").unwrap().value().as_string(),
+      Some(&b"first"[..])
+    );
+
+    let command = DatabaseCommand::new_move(&[b"key", b"0"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nests, &mut db),
+      Data::Error("Source and destination objects are the same".to_string())
+    );
+  }
+}
@@ -0,0 +1,79 @@
+//! Engine DBSIZE command.
+
+use crate::core::commands::args::check_args_len;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine DBSIZE command.
+#[derive(Clone, Debug)]
+pub struct DbSizeCommand;
+
+impl DbSizeCommand {
+  /// Return a new [DbSizeCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be no argument.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::DbSizeCommand;
+  ///
+  /// let cmd = DbSizeCommand::new(&[]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "DBSIZE");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<DbSizeCommand> {
+    check_args_len("DBSIZE", args, 0)?;
+    Ok(DbSizeCommand)
+  }
+}
+
+impl fmt::Display for DbSizeCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "DBSIZE")
+  }
+}
+
+impl Command for DbSizeCommand {
+  /// Execute the `DBSIZE` command on a given [Nest].
+  ///
+  /// Return the number of keys, including expired keys that have not been removed yet.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    Data::Integer(nest.len() as i64)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::dbsize_command::DbSizeCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse DBSIZE command arguments: Wrong number of arguments. Expected 0, got 1."
+  )]
+  fn test_command_new_1_args() {
+    DbSizeCommand::new(&[b"key"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = DbSizeCommand::new(&[]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    nest.set(Egg::new("a", &b"value"[..]));
+    nest.set(Egg::new("b", &b"value"[..]));
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+  }
+}
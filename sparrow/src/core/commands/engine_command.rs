@@ -0,0 +1,115 @@
+//! Commands executed by the engine itself rather than on a single [Nest].
+//!
+//! [Nest]: crate::core::nest::Nest
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::{BlockingCommand, DatabaseCommand, ListEnd};
+use crate::errors::Result;
+use std::fmt;
+
+/// Commands that need access to the engine state, e.g. persistence settings, blocked clients
+/// or the databases.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EngineCommand {
+  /// Write a snapshot of the [Nest] synchronously.
+  ///
+  /// [Nest]: crate::core::nest::Nest
+  Save,
+  /// Write a snapshot of the [Nest] in a background thread.
+  ///
+  /// [Nest]: crate::core::nest::Nest
+  BgSave,
+  /// Block the client until an element is available in a list.
+  Block(BlockingCommand),
+  /// Manage the databases or select the database of the client.
+  Database(DatabaseCommand),
+}
+
+/// Parse a list of raw arguments into an [EngineCommand].
+///
+/// Return [None] if the command name is not an engine command.
+///
+/// # Arguments
+/// * `inputs` - Command name followed by its arguments
+pub fn parse_engine_command(inputs: &[&[u8]]) -> Option<Result<EngineCommand>> {
+  let (name, args) = inputs.split_first()?;
+  let name = parse_string(name).ok()?.to_uppercase();
+  match name.as_str() {
+    "SAVE" => Some(check_args_len(&name, args, 0).map(|_| EngineCommand::Save)),
+    "BGSAVE" => Some(check_args_len(&name, args, 0).map(|_| EngineCommand::BgSave)),
+    "BLPOP" => Some(BlockingCommand::new_pop(args, ListEnd::Left).map(EngineCommand::Block)),
+    "BRPOP" => Some(BlockingCommand::new_pop(args, ListEnd::Right).map(EngineCommand::Block)),
+    "BLMOVE" => Some(BlockingCommand::new_move(args).map(EngineCommand::Block)),
+    "SELECT" => Some(DatabaseCommand::new_select(args).map(EngineCommand::Database)),
+    "FLUSHDB" => Some(
+      check_args_len(&name, args, 0).map(|_| EngineCommand::Database(DatabaseCommand::FlushDb)),
+    ),
+    "FLUSHALL" => Some(
+      check_args_len(&name, args, 0).map(|_| EngineCommand::Database(DatabaseCommand::FlushAll)),
+    ),
+    "SWAPDB" => Some(DatabaseCommand::new_swapdb(args).map(EngineCommand::Database)),
+    "MOVE" => Some(DatabaseCommand::new_move(args).map(EngineCommand::Database)),
+    _ => None,
+  }
+}
+
+impl fmt::Display for EngineCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      EngineCommand::Save => write!(f, "SAVE"),
+      EngineCommand::BgSave => write!(f, "BGSAVE"),
+      EngineCommand::Block(command) => write!(f, "{}", command),
+      EngineCommand::Database(command) => write!(f, "{}", command),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::engine_command::{parse_engine_command, EngineCommand};
+  use crate::core::commands::DatabaseCommand;
+
+  #[test]
+  fn test_parse_engine_command() {
+    assert_eq!(
+      parse_engine_command(&[b"save"]).unwrap().unwrap(),
+      EngineCommand::Save
+    );
+    assert_eq!(
+      parse_engine_command(&[b"BGSAVE"]).unwrap().unwrap(),
+      EngineCommand::BgSave
+    );
+    assert_eq!(
+      format!(
+        "{}",
+        parse_engine_command(&[b"brpop", b"key", b"1"])
+          .unwrap()
+          .unwrap()
+      ),
+      "BRPOP key 1"
+    );
+    assert_eq!(
+      parse_engine_command(&[b"flushall"]).unwrap().unwrap(),
+      EngineCommand::Database(DatabaseCommand::FlushAll)
+    );
+    assert_eq!(
+      format!(
+        "{}",
+        parse_engine_command(&[b"MOVE", b"key", b"2"])
+          .unwrap()
+          .unwrap()
+      ),
+      "MOVE key 2"
+    );
+    assert!(parse_engine_command(&[b"GET", b"key"]).is_none());
+    assert!(parse_engine_command(&[]).is_none());
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse SAVE command arguments: Wrong number of arguments. Expected 0, got 1."
+  )]
+  fn test_parse_engine_command_args() {
+    parse_engine_command(&[b"SAVE", b"key"]).unwrap().unwrap();
+  }
+}
@@ -0,0 +1,86 @@
+//! Engine EXISTS command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine EXISTS command.
+#[derive(Clone, Debug)]
+pub struct ExistsCommand {
+  keys: Vec<String>,
+}
+
+impl ExistsCommand {
+  /// Return a new [ExistsCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 1 argument (key...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ExistsCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"other"[..]];
+  /// let cmd = ExistsCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "EXISTS key other");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ExistsCommand> {
+    check_min_args_len("EXISTS", args, 1)?;
+    let keys = args
+      .iter()
+      .map(|key| parse_string(key))
+      .collect::<Result<_>>()?;
+    Ok(ExistsCommand { keys })
+  }
+}
+
+impl fmt::Display for ExistsCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "EXISTS {}", self.keys.join(" "))
+  }
+}
+
+impl Command for ExistsCommand {
+  /// Execute the `EXISTS key [key ...]` command on a given [Nest].
+  ///
+  /// Return the number of existing keys, a key given several times being counted several times.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let count = self
+      .keys
+      .iter()
+      .filter(|key| nest.get(key).is_some())
+      .count();
+    Data::Integer(count as i64)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::exists_command::ExistsCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut expired = Egg::new("expired", &b"value"[..]);
+    expired.set_expires_at(Some(Utc::now() - Duration::seconds(1)));
+    let mut nest = Nest::new();
+    nest.set(Egg::new("a", &b"1"[..]));
+    nest.set(expired);
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ExistsCommand::new(&[b"a", b"missing", b"expired", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+  }
+}
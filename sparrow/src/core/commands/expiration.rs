@@ -0,0 +1,148 @@
+//! Expiration options shared by engine commands.
+
+use chrono::prelude::{DateTime, TimeZone, Utc};
+use chrono::Duration;
+use std::fmt;
+
+/// Time unit used to express a time to live.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimeUnit {
+  Seconds,
+  Milliseconds,
+}
+
+/// Expiration of a key, either relative to the execution time or as a unix timestamp.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expiration {
+  /// Time to live in seconds.
+  Seconds(i64),
+  /// Time to live in milliseconds.
+  Milliseconds(i64),
+  /// Unix timestamp in seconds.
+  UnixSeconds(i64),
+  /// Unix timestamp in milliseconds.
+  UnixMilliseconds(i64),
+}
+
+impl Expiration {
+  /// Return the [Expiration] variant matching a command option: `EX`, `PX`, `EXAT` or `PXAT`.
+  ///
+  /// # Arguments
+  /// * `option` - Uppercase name of the option
+  pub fn from_option(option: &str) -> Option<fn(i64) -> Expiration> {
+    match option {
+      "EX" => Some(Expiration::Seconds),
+      "PX" => Some(Expiration::Milliseconds),
+      "EXAT" => Some(Expiration::UnixSeconds),
+      "PXAT" => Some(Expiration::UnixMilliseconds),
+      _ => None,
+    }
+  }
+
+  /// Return the expiration date computed from the given current date.
+  ///
+  /// Return [None] if the expiration date cannot be represented.
+  ///
+  /// # Arguments
+  /// * `now` - Current date used for relative expirations
+  pub fn deadline(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match *self {
+      Expiration::Seconds(seconds) => {
+        now.checked_add_signed(Duration::milliseconds(seconds.checked_mul(1000)?))
+      }
+      Expiration::Milliseconds(milliseconds) => {
+        now.checked_add_signed(Duration::milliseconds(milliseconds))
+      }
+      Expiration::UnixSeconds(seconds) => Utc
+        .timestamp_millis_opt(seconds.checked_mul(1000)?)
+        .single(),
+      Expiration::UnixMilliseconds(milliseconds) => Utc.timestamp_millis_opt(milliseconds).single(),
+    }
+  }
+}
+
+impl fmt::Display for Expiration {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Expiration::Seconds(seconds) => write!(f, "EX {}", seconds),
+      Expiration::Milliseconds(milliseconds) => write!(f, "PX {}", milliseconds),
+      Expiration::UnixSeconds(seconds) => write!(f, "EXAT {}", seconds),
+      Expiration::UnixMilliseconds(milliseconds) => write!(f, "PXAT {}", milliseconds),
+    }
+  }
+}
+
+/// Return the time to live of an expiration date in the given [TimeUnit].
+///
+/// Seconds are rounded to the closest value.
+///
+/// # Arguments
+/// * `expires_at` - Expiration date
+/// * `now` - Current date
+/// * `unit` - Unit of the returned time to live
+pub fn time_to_live(expires_at: &DateTime<Utc>, now: &DateTime<Utc>, unit: TimeUnit) -> i64 {
+  let milliseconds = expires_at
+    .signed_duration_since(*now)
+    .num_milliseconds()
+    .max(0);
+  match unit {
+    TimeUnit::Seconds => (milliseconds + 500) / 1000,
+    TimeUnit::Milliseconds => milliseconds,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::expiration::{time_to_live, Expiration, TimeUnit};
+  use chrono::prelude::{TimeZone, Utc};
+  use chrono::Duration;
+
+  #[test]
+  fn test_expiration_deadline() {
+    let now = Utc.timestamp_millis(1_000_000);
+    assert_eq!(
+      Expiration::Seconds(10).deadline(now),
+      Some(Utc.timestamp_millis(1_010_000))
+    );
+    assert_eq!(
+      Expiration::Milliseconds(10).deadline(now),
+      Some(Utc.timestamp_millis(1_000_010))
+    );
+    assert_eq!(
+      Expiration::UnixSeconds(10).deadline(now),
+      Some(Utc.timestamp_millis(10_000))
+    );
+    assert_eq!(
+      Expiration::UnixMilliseconds(10).deadline(now),
+      Some(Utc.timestamp_millis(10))
+    );
+  }
+
+  #[test]
+  fn test_expiration_from_option() {
+    assert_eq!(
+      Expiration::from_option("PXAT").map(|to_expiration| to_expiration(10)),
+      Some(Expiration::UnixMilliseconds(10))
+    );
+    assert!(Expiration::from_option("KEEPTTL").is_none());
+  }
+
+  #[test]
+  fn test_expiration_deadline_overflow() {
+    let now = Utc.timestamp_millis(1_000_000);
+    assert_eq!(Expiration::Seconds(i64::MAX).deadline(now), None);
+    assert_eq!(Expiration::UnixMilliseconds(i64::MAX).deadline(now), None);
+  }
+
+  #[test]
+  fn test_time_to_live() {
+    let now = Utc.timestamp_millis(1_000_000);
+    let expires_at = now + Duration::milliseconds(1_600);
+    assert_eq!(time_to_live(&expires_at, &now, TimeUnit::Seconds), 2);
+    assert_eq!(
+      time_to_live(&expires_at, &now, TimeUnit::Milliseconds),
+      1_600
+    );
+    assert_eq!(time_to_live(&now, &expires_at, TimeUnit::Milliseconds), 0);
+  }
+}
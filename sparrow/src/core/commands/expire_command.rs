@@ -0,0 +1,239 @@
+//! Engine EXPIRE, PEXPIRE, EXPIREAT and PEXPIREAT commands.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::command::command_data;
+use crate::core::commands::expiration::Expiration;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use chrono::Utc;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine EXPIRE, PEXPIRE, EXPIREAT and PEXPIREAT commands.
+#[derive(Clone, Debug)]
+pub struct ExpireCommand {
+  key: String,
+  expiration: Expiration,
+}
+
+impl ExpireCommand {
+  /// Return a new [ExpireCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, time).
+  /// * `to_expiration` - [Expiration] variant built from the time argument
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{Expiration, ExpireCommand};
+  ///
+  /// let args = &[&b"key"[..], &b"10"[..]];
+  /// let cmd = ExpireCommand::new(args, Expiration::Seconds).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "EXPIRE key 10");
+  /// ```
+  pub fn new(args: &[&[u8]], to_expiration: fn(i64) -> Expiration) -> Result<ExpireCommand> {
+    match args.len() {
+      2 => {
+        let key = parse_string(args.first().unwrap())?;
+        let expiration = to_expiration(parse_integer(args.get(1).unwrap())?);
+        Ok(ExpireCommand { key, expiration })
+      }
+      n => Err(
+        format!(
+          "Cannot parse {} command arguments: Wrong number of arguments. Expected 2, got {}.",
+          command_name(&to_expiration(0)),
+          n
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+/// Return the name of the command setting the given [Expiration].
+fn command_name(expiration: &Expiration) -> &'static str {
+  match expiration {
+    Expiration::Seconds(_) => "EXPIRE",
+    Expiration::Milliseconds(_) => "PEXPIRE",
+    Expiration::UnixSeconds(_) => "EXPIREAT",
+    Expiration::UnixMilliseconds(_) => "PEXPIREAT",
+  }
+}
+
+impl fmt::Display for ExpireCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let time = match self.expiration {
+      Expiration::Seconds(time)
+      | Expiration::Milliseconds(time)
+      | Expiration::UnixSeconds(time)
+      | Expiration::UnixMilliseconds(time) => time,
+    };
+    write!(
+      f,
+      "{} {} {}",
+      command_name(&self.expiration),
+      self.key,
+      time
+    )
+  }
+}
+
+impl Command for ExpireCommand {
+  /// Execute the `EXPIRE key seconds` command on a given [Nest].
+  ///
+  /// The [Egg] is removed right away if the expiration date is already passed.
+  ///
+  /// [Egg]: crate::core::egg::Egg
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let now = Utc::now();
+    match self.expiration.deadline(now) {
+      Some(expires_at) if expires_at <= now => match nest.get(&self.key) {
+        Some(_) => {
+          nest.rem(&self.key);
+          Data::Integer(1)
+        }
+        None => Data::Integer(0),
+      },
+      Some(expires_at) => Data::Integer(nest.expire(&self.key, Some(expires_at)) as i64),
+      None => Data::Error(format!(
+        "Invalid expire time in {} command",
+        command_name(&self.expiration)
+      )),
+    }
+  }
+
+  /// Relative expiration times are rewritten as `PEXPIREAT key timestamp` so that
+  /// replaying the command does not extend the time to live,
+  /// and as `REM key` if the [Egg] was removed right away.
+  ///
+  /// [Egg]: crate::core::egg::Egg
+  fn to_aof(&self, _input: &Data, output: &Data, nest: &Nest) -> Option<Data> {
+    if *output != Data::Integer(1) {
+      return None;
+    }
+    let key = self.key.clone().into_bytes();
+    match nest.peek(&self.key).and_then(|egg| egg.expires_at()) {
+      Some(expires_at) => Some(command_data(vec![
+        b"PEXPIREAT".to_vec(),
+        key,
+        expires_at.timestamp_millis().to_string().into_bytes(),
+      ])),
+      None => Some(command_data(vec![b"REM".to_vec(), key])),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::command::command_data;
+  use crate::core::commands::expiration::Expiration;
+  use crate::core::commands::expire_command::ExpireCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+  const TEST_VALUE: &str = "This is a test value!";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  fn test_command_new_2_args() {
+    let args = &[TEST_KEY.as_bytes(), b"10"];
+    let command = ExpireCommand::new(args, Expiration::Milliseconds).unwrap();
+    assert_eq!(command.key, TEST_KEY);
+    assert_eq!(command.expiration, Expiration::Milliseconds(10));
+    assert_eq!(format!("{}", command), "PEXPIRE My key 10");
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse EXPIREAT command arguments: Wrong number of arguments. Expected 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    let args = &[TEST_KEY.as_bytes()];
+    ExpireCommand::new(args, Expiration::UnixSeconds).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is not an integer or out of range")]
+  fn test_command_new_not_integer() {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes()];
+    ExpireCommand::new(args, Expiration::Seconds).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), b"10"];
+    let command = ExpireCommand::new(args, Expiration::Seconds).unwrap();
+
+    // Key does not exist
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    let expires_at = *nest.get(TEST_KEY).unwrap().expires_at().unwrap();
+    assert!(expires_at > Utc::now() + Duration::seconds(9));
+  }
+
+  #[rstest]
+  fn test_command_to_aof(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), b"10"];
+    let command = ExpireCommand::new(args, Expiration::Seconds).unwrap();
+    let input = Data::BulkString("EXPIRE My key 10".into());
+
+    assert_eq!(command.to_aof(&input, &Data::Integer(0), &nest), None);
+
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    let output = command.execute(&mut nest);
+    let expires_at = nest
+      .get(TEST_KEY)
+      .unwrap()
+      .expires_at()
+      .unwrap()
+      .timestamp_millis();
+    match command.to_aof(&input, &output, &nest) {
+      Some(Data::Array(array)) => {
+        assert_eq!(array[0], Data::BulkString("PEXPIREAT".into()));
+        assert_eq!(array[1], Data::BulkString(TEST_KEY.into()));
+        let timestamp = array[2].as_str().unwrap().parse::<i64>().unwrap();
+        assert_eq!(timestamp, expires_at);
+      }
+      data => panic!("Unexpected AOF data: {:?}", data),
+    }
+  }
+
+  #[rstest]
+  fn test_command_execute_past(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    let args = &[TEST_KEY.as_bytes(), b"-1"];
+    let command = ExpireCommand::new(args, Expiration::Seconds).unwrap();
+    let output = command.execute(&mut nest);
+    assert_eq!(output, Data::Integer(1));
+    assert!(nest.get(TEST_KEY).is_none());
+    assert_eq!(
+      command.to_aof(&Data::Null, &output, &nest),
+      Some(command_data(vec!["REM".into(), TEST_KEY.into()]))
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_invalid_time(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    let max = i64::MAX.to_string();
+    let args = &[TEST_KEY.as_bytes(), max.as_bytes()];
+    let command = ExpireCommand::new(args, Expiration::Seconds).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Invalid expire time in EXPIRE command".to_string())
+    );
+  }
+}
@@ -1,7 +1,9 @@
 //! Engine GET command.
 //!
+use crate::core::commands::args::parse_string;
 use crate::core::commands::Command;
 use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
 use crate::errors::Result;
 use sparrow_resp::Data;
 use std::fmt;
@@ -22,18 +24,16 @@ impl GetCommand {
   /// ```rust
   /// use crate::core::commands::GetCommand;
   ///
-  /// let args = &vec!["my key"];
+  /// let args = &[&b"my key"[..]];
   /// let cmd = GetCommand::new(args).unwrap();
   ///
   /// assert_eq!(format!("{}", cmd), "GET {my key}");
   /// ```
-  pub fn new(args: &[&str]) -> Result<GetCommand> {
+  pub fn new(args: &[&[u8]]) -> Result<GetCommand> {
     match args.len() {
       1 => {
-        let key = args.get(0).unwrap();
-        Ok(GetCommand {
-          key: key.to_string(),
-        })
+        let key = parse_string(args.first().unwrap())?;
+        Ok(GetCommand { key })
       }
       n => Err(
         format!(
@@ -55,10 +55,11 @@ impl fmt::Display for GetCommand {
 impl Command for GetCommand {
   /// Execute the `GET key` command on a given [Nest].
   fn execute(&self, nest: &mut Nest) -> Data {
-    nest
-      .get(&self.key)
-      .map(|egg| Data::BulkString(egg.value().clone()))
-      .unwrap_or(Data::Null)
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(value)) => Data::BulkString(value.clone()),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Null,
+    }
   }
 }
 
@@ -68,8 +69,10 @@ mod tests {
   use crate::core::commands::Command;
   use crate::core::egg::Egg;
   use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
   use rstest::*;
   use sparrow_resp::Data;
+  use std::collections::HashMap;
 
   const TEST_KEY: &str = "My key";
   const TEST_VALUE: &str = "This is a test value!";
@@ -81,7 +84,7 @@ mod tests {
 
   #[test]
   fn test_command_new_1_args() {
-    let args = &vec![TEST_KEY];
+    let args = &[TEST_KEY.as_bytes()];
     let command = GetCommand::new(args).unwrap();
     assert_eq!(command.key, TEST_KEY)
   }
@@ -91,7 +94,7 @@ mod tests {
     expected = "Cannot parse GET command arguments: Wrong number of arguments. Expected 1, got 0."
   )]
   fn test_command_new_0_args() {
-    let args = &vec![];
+    let args = &[];
     GetCommand::new(args).unwrap();
   }
 
@@ -100,20 +103,24 @@ mod tests {
     expected = "Cannot parse GET command arguments: Wrong number of arguments. Expected 1, got 2."
   )]
   fn test_command_new_2_args() {
-    let args = &vec![TEST_KEY, TEST_VALUE];
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes()];
     GetCommand::new(args).unwrap();
   }
 
   #[rstest]
   fn test_command_execute(mut nest: Nest) {
-    let args = &vec![TEST_KEY];
+    let args = &[TEST_KEY.as_bytes()];
     let command = Box::new(GetCommand::new(args).unwrap());
 
     let data = command.execute(&mut nest);
     assert_eq!(data, Data::Null);
 
-    nest.set(Egg::new(TEST_KEY, TEST_VALUE));
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    let data = command.execute(&mut nest);
+    assert_eq!(data, Data::BulkString(TEST_VALUE.into()));
+
+    nest.set(Egg::new(TEST_KEY, HashMap::new()));
     let data = command.execute(&mut nest);
-    assert_eq!(data, Data::BulkString(TEST_VALUE.to_string()));
+    assert_eq!(data, Data::Error(WRONG_TYPE_ERROR.to_string()));
   }
 }
@@ -0,0 +1,91 @@
+//! Engine GETBIT command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::bitmap::{get_bit, parse_bit_offset};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine GETBIT command.
+#[derive(Clone, Debug)]
+pub struct GetBitCommand {
+  key: String,
+  offset: usize,
+}
+
+impl GetBitCommand {
+  /// Return a new [GetBitCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, offset).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::GetBitCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"7"[..]];
+  /// let cmd = GetBitCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "GETBIT key 7");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<GetBitCommand> {
+    check_args_len("GETBIT", args, 2)?;
+    Ok(GetBitCommand {
+      key: parse_string(args.first().unwrap())?,
+      offset: parse_bit_offset(args.get(1).unwrap())?,
+    })
+  }
+}
+
+impl fmt::Display for GetBitCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "GETBIT {} {}", self.key, self.offset)
+  }
+}
+
+impl Command for GetBitCommand {
+  /// Execute the `GETBIT key offset` command on a given [Nest].
+  ///
+  /// Return the bit at the offset, `0` past the end of the value or if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(bytes)) => Data::Integer(get_bit(bytes, self.offset) as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::getbit_command::GetBitCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &[0b0100_0000][..]));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = GetBitCommand::new(&[TEST_KEY.as_bytes(), b"1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    let command = GetBitCommand::new(&[TEST_KEY.as_bytes(), b"2"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = GetBitCommand::new(&[TEST_KEY.as_bytes(), b"100"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = GetBitCommand::new(&[b"missing", b"1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+  }
+}
@@ -0,0 +1,101 @@
+//! Engine GETDEL command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine GETDEL command.
+#[derive(Clone, Debug)]
+pub struct GetDelCommand {
+  key: String,
+}
+
+impl GetDelCommand {
+  /// Return a new [GetDelCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::GetDelCommand;
+  ///
+  /// let cmd = GetDelCommand::new(&[&b"key"[..]]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "GETDEL key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<GetDelCommand> {
+    check_args_len("GETDEL", args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(GetDelCommand { key })
+  }
+}
+
+impl fmt::Display for GetDelCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "GETDEL {}", self.key)
+  }
+}
+
+impl Command for GetDelCommand {
+  /// Execute the `GETDEL key` command on a given [Nest].
+  ///
+  /// Return the value of the removed key, or [Data::Null] if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let value = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(value)) => value.clone(),
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::Null,
+    };
+    nest.rem(&self.key);
+    Data::BulkString(value)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::BulkString(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::getdel_command::GetDelCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    nest.set(Egg::new("set", HashSet::new()));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = GetDelCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("value".into()));
+    assert!(nest.get(TEST_KEY).is_none());
+    assert_eq!(command.execute(&mut nest), Data::Null);
+
+    let command = GetDelCommand::new(&[b"set"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+    assert!(nest.get("set").is_some());
+  }
+}
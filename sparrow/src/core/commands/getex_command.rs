@@ -0,0 +1,203 @@
+//! Engine GETEX command.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::command::command_data;
+use crate::core::commands::{Command, Expiration};
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use chrono::Utc;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine GETEX command.
+#[derive(Clone, Debug)]
+pub struct GetExCommand {
+  key: String,
+  expiration: Option<Expiration>,
+  /// Whether the expiration is removed.
+  persist: bool,
+}
+
+impl GetExCommand {
+  /// Return a new [GetExCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key), optionally followed by
+  ///   `EX seconds`, `PX milliseconds`, `EXAT timestamp`, `PXAT timestamp` or `PERSIST`.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::GetExCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"px"[..], &b"100"[..]];
+  /// let cmd = GetExCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "GETEX key PX 100");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<GetExCommand> {
+    let mut command = GetExCommand {
+      key: String::new(),
+      expiration: None,
+      persist: false,
+    };
+    let option = args.get(1).map(|option| parse_string(option)).transpose()?;
+    match (args, option.map(|option| option.to_uppercase())) {
+      ([key], None) => command.key = parse_string(key)?,
+      ([key, _], Some(option)) if option == "PERSIST" => {
+        command.key = parse_string(key)?;
+        command.persist = true;
+      }
+      ([key, _, time], Some(option)) if Expiration::from_option(&option).is_some() => {
+        let time = parse_integer(time)?;
+        if time <= 0 {
+          return Err("Invalid expire time in GETEX command".into());
+        }
+        command.key = parse_string(key)?;
+        command.expiration = Expiration::from_option(&option).map(|to_expiration| to_expiration(time));
+      }
+      (_, Some(option)) => {
+        return Err(
+          format!(
+            "Cannot parse GETEX command arguments: Syntax error near {}.",
+            option
+          )
+          .into(),
+        )
+      }
+      (_, None) => {
+        return Err(
+          "Cannot parse GETEX command arguments: Wrong number of arguments. Expected at least 1, got 0."
+            .into(),
+        )
+      }
+    }
+    Ok(command)
+  }
+}
+
+impl fmt::Display for GetExCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "GETEX {}", self.key)?;
+    if let Some(expiration) = self.expiration {
+      write!(f, " {}", expiration)?;
+    }
+    if self.persist {
+      write!(f, " PERSIST")?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for GetExCommand {
+  /// Execute the `GETEX key [EX seconds|PX milliseconds|EXAT timestamp|PXAT timestamp|PERSIST]`
+  /// command on a given [Nest].
+  ///
+  /// Return the value of the key, or [Data::Null] if the key does not exist,
+  /// and update its expiration according to the option.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let value = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(value)) => value.clone(),
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::Null,
+    };
+    if let Some(expiration) = self.expiration {
+      match expiration.deadline(Utc::now()) {
+        Some(expires_at) => nest.expire(&self.key, Some(expires_at)),
+        None => return Data::Error("Invalid expire time in GETEX command".to_string()),
+      };
+    }
+    if self.persist {
+      nest.expire(&self.key, None);
+    }
+    Data::BulkString(value)
+  }
+
+  /// Rewrite the command as `PEXPIREAT key timestamp` or `PERSIST key`,
+  /// so that replaying it does not extend the time to live.
+  fn to_aof(&self, _input: &Data, output: &Data, nest: &Nest) -> Option<Data> {
+    if !matches!(output, Data::BulkString(_)) {
+      return None;
+    }
+    let key = self.key.clone().into_bytes();
+    match self.expiration {
+      Some(_) => {
+        let expires_at = nest.peek(&self.key)?.expires_at()?;
+        Some(command_data(vec![
+          b"PEXPIREAT".to_vec(),
+          key,
+          expires_at.timestamp_millis().to_string().into_bytes(),
+        ]))
+      }
+      None if self.persist => Some(command_data(vec![b"PERSIST".to_vec(), key])),
+      None => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::command::command_data;
+  use crate::core::commands::getex_command::GetExCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse GETEX command arguments: Syntax error near EX.")]
+  fn test_command_new_missing_time() {
+    GetExCommand::new(&[TEST_KEY.as_bytes(), b"ex"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid expire time in GETEX command")]
+  fn test_command_new_negative_time() {
+    GetExCommand::new(&[TEST_KEY.as_bytes(), b"EX", b"0"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = GetExCommand::new(&[TEST_KEY.as_bytes(), b"EX", b"10"]).unwrap();
+    let output = command.execute(&mut nest);
+    assert_eq!(output, Data::BulkString("value".into()));
+    let expires_at = *nest.get(TEST_KEY).unwrap().expires_at().unwrap();
+    assert!(expires_at > Utc::now() + Duration::seconds(9));
+    // The logged expiration is the applied one
+    assert_eq!(
+      command.to_aof(&Data::Null, &output, &nest),
+      Some(command_data(vec![
+        "PEXPIREAT".into(),
+        TEST_KEY.into(),
+        expires_at.timestamp_millis().to_string().into(),
+      ]))
+    );
+
+    let command = GetExCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("value".into()));
+    assert!(nest.get(TEST_KEY).unwrap().expires_at().is_some());
+    assert_eq!(command.to_aof(&Data::Null, &output, &nest), None);
+
+    let command = GetExCommand::new(&[TEST_KEY.as_bytes(), b"persist"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("value".into()));
+    assert!(nest.get(TEST_KEY).unwrap().expires_at().is_none());
+    assert_eq!(
+      command.to_aof(&Data::Null, &output, &nest),
+      Some(command_data(vec!["PERSIST".into(), TEST_KEY.into()]))
+    );
+
+    let command = GetExCommand::new(&[b"missing", b"PERSIST"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+  }
+}
@@ -0,0 +1,107 @@
+//! Engine GETRANGE command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::list::resolve_range;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine GETRANGE command, also known as SUBSTR.
+#[derive(Clone, Debug)]
+pub struct GetRangeCommand {
+  key: String,
+  start: i64,
+  end: i64,
+}
+
+impl GetRangeCommand {
+  /// Return a new [GetRangeCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, start, end).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::GetRangeCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"0"[..], &b"-1"[..]];
+  /// let cmd = GetRangeCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "GETRANGE key 0 -1");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<GetRangeCommand> {
+    check_args_len("GETRANGE", args, 3)?;
+    Ok(GetRangeCommand {
+      key: parse_string(args.first().unwrap())?,
+      start: parse_integer(args.get(1).unwrap())?,
+      end: parse_integer(args.get(2).unwrap())?,
+    })
+  }
+}
+
+impl fmt::Display for GetRangeCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "GETRANGE {} {} {}", self.key, self.start, self.end)
+  }
+}
+
+impl Command for GetRangeCommand {
+  /// Execute the `GETRANGE key start end` command on a given [Nest].
+  ///
+  /// Return the bytes of the value between the `start` and `end` offsets, both included.
+  /// Negative offsets count from the end of the value and out of range offsets are clamped.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let value = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(value)) => value,
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::BulkString(Vec::new()),
+    };
+    match resolve_range(self.start, self.end, value.len()) {
+      Some((start, end)) => Data::BulkString(value[start..=end].to_vec()),
+      None => Data::BulkString(Vec::new()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::getrange_command::GetRangeCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &b"This is a string"[..]));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let ranges: &[(&[u8], &[u8], &str)] = &[
+      (b"0", b"3", "This"),
+      (b"-3", b"-1", "ing"),
+      (b"0", b"-1", "This is a string"),
+      (b"10", b"100", "string"),
+      (b"5", b"3", ""),
+      (b"-100", b"-17", ""),
+    ];
+    for (start, end, expected) in ranges {
+      let command = GetRangeCommand::new(&[TEST_KEY.as_bytes(), start, end]).unwrap();
+      assert_eq!(
+        command.execute(&mut nest),
+        Data::BulkString(expected.as_bytes().to_vec())
+      );
+    }
+    let command = GetRangeCommand::new(&[b"missing", b"0", b"-1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString(Vec::new()));
+  }
+}
@@ -0,0 +1,110 @@
+//! Engine GETSET command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine GETSET command.
+#[derive(Clone, Debug)]
+pub struct GetSetCommand {
+  key: String,
+  value: Vec<u8>,
+}
+
+impl GetSetCommand {
+  /// Return a new [GetSetCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, value).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::GetSetCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"value"[..]];
+  /// let cmd = GetSetCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "GETSET key value");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<GetSetCommand> {
+    check_args_len("GETSET", args, 2)?;
+    Ok(GetSetCommand {
+      key: parse_string(args.first().unwrap())?,
+      value: args.get(1).unwrap().to_vec(),
+    })
+  }
+}
+
+impl fmt::Display for GetSetCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "GETSET {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.value)
+    )
+  }
+}
+
+impl Command for GetSetCommand {
+  /// Execute the `GETSET key value` command on a given [Nest].
+  ///
+  /// Like `SET`, the previous expiration is discarded. Return the previous value,
+  /// or [Data::Null] if the key did not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let previous = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(value)) => Data::BulkString(value.clone()),
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Null,
+    };
+    nest.set(Egg::new(&self.key, self.value.as_slice()));
+    previous
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Error(_) => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::getset_command::GetSetCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new("set", HashSet::new()));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = GetSetCommand::new(&[TEST_KEY.as_bytes(), b"first"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+    let command = GetSetCommand::new(&[TEST_KEY.as_bytes(), b"second"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("first".into()));
+
+    let command = GetSetCommand::new(&[b"set", b"value"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
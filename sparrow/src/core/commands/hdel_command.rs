@@ -0,0 +1,137 @@
+//! Engine HDEL command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine HDEL command.
+#[derive(Clone, Debug)]
+pub struct HDelCommand {
+  key: String,
+  fields: Vec<Vec<u8>>,
+}
+
+impl HDelCommand {
+  /// Return a new [HDelCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, field...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::HDelCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = HDelCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HDEL key a b");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<HDelCommand> {
+    check_min_args_len("HDEL", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let fields = args[1..].iter().map(|field| field.to_vec()).collect();
+    Ok(HDelCommand { key, fields })
+  }
+}
+
+impl fmt::Display for HDelCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "HDEL {}", self.key)?;
+    for field in &self.fields {
+      write!(f, " {}", String::from_utf8_lossy(field))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for HDelCommand {
+  /// Execute the `HDEL key field [field ...]` command on a given [Nest].
+  ///
+  /// Return the number of removed fields. The key is removed along with its last field.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let hash = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_hash_mut() {
+        Some(hash) => hash,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::Integer(0),
+    };
+    let removed = self
+      .fields
+      .iter()
+      .filter(|field| hash.remove(*field).is_some())
+      .count();
+    if hash.is_empty() {
+      nest.rem(&self.key);
+    }
+    Data::Integer(removed as i64)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hdel_command::HDelCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashMap;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut hash = HashMap::new();
+    hash.insert(b"a".to_vec(), b"1".to_vec());
+    hash.insert(b"b".to_vec(), b"2".to_vec());
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, hash));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HDEL command arguments: Wrong number of arguments. Expected at least 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    HDelCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = HDelCommand::new(&[TEST_KEY.as_bytes(), b"a", b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    // Removing the last field removes the key
+    let command = HDelCommand::new(&[TEST_KEY.as_bytes(), b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert!(nest.get(TEST_KEY).is_none());
+  }
+
+  #[test]
+  fn test_command_to_aof() {
+    let command = HDelCommand::new(&[TEST_KEY.as_bytes(), b"a"]).unwrap();
+    let input = Data::BulkString("HDEL key a".into());
+    assert_eq!(
+      command.to_aof(&input, &Data::Integer(0), &Nest::new()),
+      None
+    );
+    assert_eq!(
+      command.to_aof(&input, &Data::Integer(1), &Nest::new()),
+      Some(input)
+    );
+  }
+}
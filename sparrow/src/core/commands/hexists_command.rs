@@ -0,0 +1,105 @@
+//! Engine HEXISTS command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine HEXISTS command.
+#[derive(Clone, Debug)]
+pub struct HExistsCommand {
+  key: String,
+  field: Vec<u8>,
+}
+
+impl HExistsCommand {
+  /// Return a new [HExistsCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, field).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::HExistsCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"field"[..]];
+  /// let cmd = HExistsCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HEXISTS key field");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<HExistsCommand> {
+    check_args_len("HEXISTS", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let field = args.get(1).unwrap().to_vec();
+    Ok(HExistsCommand { key, field })
+  }
+}
+
+impl fmt::Display for HExistsCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "HEXISTS {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.field)
+    )
+  }
+}
+
+impl Command for HExistsCommand {
+  /// Execute the `HEXISTS key field` command on a given [Nest].
+  ///
+  /// Return `1` if the field exists, `0` otherwise.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Hash(hash)) => Data::Integer(hash.contains_key(&self.field) as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hexists_command::HExistsCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashMap;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut hash = HashMap::new();
+    hash.insert(b"field".to_vec(), b"value".to_vec());
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, hash));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HEXISTS command arguments: Wrong number of arguments. Expected 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    HExistsCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = HExistsCommand::new(&[TEST_KEY.as_bytes(), b"field"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+
+    let command = HExistsCommand::new(&[TEST_KEY.as_bytes(), b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    let command = HExistsCommand::new(&[b"missing", b"field"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+  }
+}
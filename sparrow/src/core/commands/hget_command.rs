@@ -0,0 +1,117 @@
+//! Engine HGET command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine HGET command.
+#[derive(Clone, Debug)]
+pub struct HGetCommand {
+  key: String,
+  field: Vec<u8>,
+}
+
+impl HGetCommand {
+  /// Return a new [HGetCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, field).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::HGetCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"field"[..]];
+  /// let cmd = HGetCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HGET key field");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<HGetCommand> {
+    check_args_len("HGET", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let field = args.get(1).unwrap().to_vec();
+    Ok(HGetCommand { key, field })
+  }
+}
+
+impl fmt::Display for HGetCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "HGET {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.field)
+    )
+  }
+}
+
+impl Command for HGetCommand {
+  /// Execute the `HGET key field` command on a given [Nest].
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Hash(hash)) => hash
+        .get(&self.field)
+        .map(|value| Data::BulkString(value.clone()))
+        .unwrap_or(Data::Null),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Null,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hget_command::HGetCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashMap;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut hash = HashMap::new();
+    hash.insert(b"field".to_vec(), b"value".to_vec());
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, hash));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HGET command arguments: Wrong number of arguments. Expected 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    HGetCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = HGetCommand::new(&[TEST_KEY.as_bytes(), b"field"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("value".into()));
+
+    let command = HGetCommand::new(&[TEST_KEY.as_bytes(), b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+
+    let command = HGetCommand::new(&[b"missing", b"field"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+  }
+
+  #[rstest]
+  fn test_command_execute_wrong_type(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    let command = HGetCommand::new(&[TEST_KEY.as_bytes(), b"field"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
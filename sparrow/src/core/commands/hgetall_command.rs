@@ -0,0 +1,146 @@
+//! Engine HGETALL, HKEYS and HVALS commands.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Part of the hash entries returned by a [HGetAllCommand].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HashPart {
+  /// Fields only (`HKEYS`).
+  Fields,
+  /// Values only (`HVALS`).
+  Values,
+  /// Fields followed by their value (`HGETALL`).
+  Entries,
+}
+
+/// Engine HGETALL, HKEYS and HVALS commands.
+#[derive(Clone, Debug)]
+pub struct HGetAllCommand {
+  key: String,
+  part: HashPart,
+}
+
+impl HGetAllCommand {
+  /// Return a new [HGetAllCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  /// * `part` - [HashPart] of the entries to return
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{HashPart, HGetAllCommand};
+  ///
+  /// let args = &[&b"key"[..]];
+  /// let cmd = HGetAllCommand::new(args, HashPart::Fields).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HKEYS key");
+  /// ```
+  pub fn new(args: &[&[u8]], part: HashPart) -> Result<HGetAllCommand> {
+    check_args_len(command_name(part), args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(HGetAllCommand { key, part })
+  }
+}
+
+/// Return the name of the command returning the given [HashPart].
+fn command_name(part: HashPart) -> &'static str {
+  match part {
+    HashPart::Fields => "HKEYS",
+    HashPart::Values => "HVALS",
+    HashPart::Entries => "HGETALL",
+  }
+}
+
+impl fmt::Display for HGetAllCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} {}", command_name(self.part), self.key)
+  }
+}
+
+impl Command for HGetAllCommand {
+  /// Execute the `HGETALL key` command on a given [Nest].
+  ///
+  /// Return an empty array if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let hash = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Hash(hash)) => hash,
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::Array(vec![]),
+    };
+    let mut array = Vec::with_capacity(hash.len() * 2);
+    for (field, value) in hash {
+      if self.part != HashPart::Values {
+        array.push(Data::BulkString(field.clone()));
+      }
+      if self.part != HashPart::Fields {
+        array.push(Data::BulkString(value.clone()));
+      }
+    }
+    Data::Array(array)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hgetall_command::{HGetAllCommand, HashPart};
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashMap;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut hash = HashMap::new();
+    hash.insert(b"field".to_vec(), b"value".to_vec());
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, hash));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HVALS command arguments: Wrong number of arguments. Expected 1, got 0."
+  )]
+  fn test_command_new_0_args() {
+    HGetAllCommand::new(&[], HashPart::Values).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes()];
+    let command = HGetAllCommand::new(args, HashPart::Entries).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![
+        Data::BulkString("field".into()),
+        Data::BulkString("value".into())
+      ])
+    );
+
+    let command = HGetAllCommand::new(args, HashPart::Fields).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![Data::BulkString("field".into())])
+    );
+
+    let command = HGetAllCommand::new(args, HashPart::Values).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![Data::BulkString("value".into())])
+    );
+
+    let command = HGetAllCommand::new(&[b"missing"], HashPart::Entries).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Array(vec![]));
+  }
+}
@@ -0,0 +1,143 @@
+//! Engine HINCRBY command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Engine HINCRBY command.
+#[derive(Clone, Debug)]
+pub struct HIncrByCommand {
+  key: String,
+  field: Vec<u8>,
+  increment: i64,
+}
+
+impl HIncrByCommand {
+  /// Return a new [HIncrByCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, field, increment).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::HIncrByCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"field"[..], &b"-2"[..]];
+  /// let cmd = HIncrByCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HINCRBY key field -2");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<HIncrByCommand> {
+    check_args_len("HINCRBY", args, 3)?;
+    let key = parse_string(args.first().unwrap())?;
+    let field = args.get(1).unwrap().to_vec();
+    let increment = parse_integer(args.get(2).unwrap())?;
+    Ok(HIncrByCommand {
+      key,
+      field,
+      increment,
+    })
+  }
+}
+
+impl fmt::Display for HIncrByCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "HINCRBY {} {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.field),
+      self.increment
+    )
+  }
+}
+
+impl Command for HIncrByCommand {
+  /// Execute the `HINCRBY key field increment` command on a given [Nest].
+  ///
+  /// Missing fields are set to `0` before being incremented. Return the new value.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::Hash(HashMap::new()));
+    let hash = match egg.value_mut().as_hash_mut() {
+      Some(hash) => hash,
+      None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+    };
+    let current = match hash.get(&self.field) {
+      Some(value) => match parse_integer(value) {
+        Ok(current) => current,
+        Err(_) => return Data::Error("Hash value is not an integer".to_string()),
+      },
+      None => 0,
+    };
+    match current.checked_add(self.increment) {
+      Some(value) => {
+        hash.insert(self.field.clone(), value.to_string().into_bytes());
+        Data::Integer(value)
+      }
+      None => Data::Error("Increment or decrement would overflow".to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, _output: &Data, _nest: &Nest) -> Option<Data> {
+    Some(input.clone())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hincrby_command::HIncrByCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashMap;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut hash = HashMap::new();
+    hash.insert(b"text".to_vec(), b"value".to_vec());
+    hash.insert(b"max".to_vec(), i64::MAX.to_string().into_bytes());
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, hash));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is not an integer or out of range")]
+  fn test_command_new_not_integer() {
+    HIncrByCommand::new(&[TEST_KEY.as_bytes(), b"field", b"one"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = HIncrByCommand::new(&[TEST_KEY.as_bytes(), b"counter", b"5"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(5));
+    assert_eq!(command.execute(&mut nest), Data::Integer(10));
+
+    let command = HIncrByCommand::new(&[b"other", b"counter", b"-1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(-1));
+  }
+
+  #[rstest]
+  fn test_command_execute_invalid(mut nest: Nest) {
+    let command = HIncrByCommand::new(&[TEST_KEY.as_bytes(), b"text", b"1"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Hash value is not an integer".to_string())
+    );
+
+    let command = HIncrByCommand::new(&[TEST_KEY.as_bytes(), b"max", b"1"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Increment or decrement would overflow".to_string())
+    );
+  }
+}
@@ -0,0 +1,91 @@
+//! Engine HLEN command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine HLEN command.
+#[derive(Clone, Debug)]
+pub struct HLenCommand {
+  key: String,
+}
+
+impl HLenCommand {
+  /// Return a new [HLenCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::HLenCommand;
+  ///
+  /// let args = &[&b"key"[..]];
+  /// let cmd = HLenCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HLEN key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<HLenCommand> {
+    check_args_len("HLEN", args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(HLenCommand { key })
+  }
+}
+
+impl fmt::Display for HLenCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "HLEN {}", self.key)
+  }
+}
+
+impl Command for HLenCommand {
+  /// Execute the `HLEN key` command on a given [Nest].
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Hash(hash)) => Data::Integer(hash.len() as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hlen_command::HLenCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashMap;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = HLenCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    let mut hash = HashMap::new();
+    hash.insert(b"a".to_vec(), b"1".to_vec());
+    hash.insert(b"b".to_vec(), b"2".to_vec());
+    nest.set(Egg::new(TEST_KEY, hash));
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
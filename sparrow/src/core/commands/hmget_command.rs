@@ -0,0 +1,121 @@
+//! Engine HMGET command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine HMGET command.
+#[derive(Clone, Debug)]
+pub struct HMGetCommand {
+  key: String,
+  fields: Vec<Vec<u8>>,
+}
+
+impl HMGetCommand {
+  /// Return a new [HMGetCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, field...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::HMGetCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = HMGetCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HMGET key a b");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<HMGetCommand> {
+    check_min_args_len("HMGET", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let fields = args[1..].iter().map(|field| field.to_vec()).collect();
+    Ok(HMGetCommand { key, fields })
+  }
+}
+
+impl fmt::Display for HMGetCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "HMGET {}", self.key)?;
+    for field in &self.fields {
+      write!(f, " {}", String::from_utf8_lossy(field))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for HMGetCommand {
+  /// Execute the `HMGET key field [field ...]` command on a given [Nest].
+  ///
+  /// Return the values in the fields order, [Data::Null] for missing fields.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let hash = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Hash(hash)) => Some(hash),
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => None,
+    };
+    Data::Array(
+      self
+        .fields
+        .iter()
+        .map(|field| {
+          hash
+            .and_then(|hash| hash.get(field))
+            .map(|value| Data::BulkString(value.clone()))
+            .unwrap_or(Data::Null)
+        })
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hmget_command::HMGetCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashMap;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut hash = HashMap::new();
+    hash.insert(b"a".to_vec(), b"1".to_vec());
+    hash.insert(b"b".to_vec(), b"2".to_vec());
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, hash));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HMGET command arguments: Wrong number of arguments. Expected at least 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    HMGetCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = HMGetCommand::new(&[TEST_KEY.as_bytes(), b"b", b"missing", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![
+        Data::BulkString("2".into()),
+        Data::Null,
+        Data::BulkString("1".into()),
+      ])
+    );
+
+    let command = HMGetCommand::new(&[b"missing", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Array(vec![Data::Null]));
+  }
+}
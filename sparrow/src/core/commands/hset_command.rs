@@ -0,0 +1,159 @@
+//! Engine HSET command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Engine HSET command.
+#[derive(Clone, Debug)]
+pub struct HSetCommand {
+  key: String,
+  entries: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl HSetCommand {
+  /// Return a new [HSetCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be a key followed by field value pairs.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::HSetCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"field"[..], &b"value"[..]];
+  /// let cmd = HSetCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "HSET key field value");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<HSetCommand> {
+    check_min_args_len("HSET", args, 3)?;
+    if args.len().is_multiple_of(2) {
+      return Err(
+        "Cannot parse HSET command arguments: Wrong number of arguments. Expected field value pairs."
+          .into(),
+      );
+    }
+    let key = parse_string(args.first().unwrap())?;
+    let entries = args[1..]
+      .chunks(2)
+      .map(|pair| (pair[0].to_vec(), pair[1].to_vec()))
+      .collect();
+    Ok(HSetCommand { key, entries })
+  }
+}
+
+impl fmt::Display for HSetCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "HSET {}", self.key)?;
+    for (field, value) in &self.entries {
+      write!(
+        f,
+        " {} {}",
+        String::from_utf8_lossy(field),
+        String::from_utf8_lossy(value)
+      )?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for HSetCommand {
+  /// Execute the `HSET key field value [field value ...]` command on a given [Nest].
+  ///
+  /// Return the number of fields that were added.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::Hash(HashMap::new()));
+    match egg.value_mut().as_hash_mut() {
+      Some(hash) => {
+        let mut added = 0;
+        for (field, value) in &self.entries {
+          if hash.insert(field.clone(), value.clone()).is_none() {
+            added += 1;
+          }
+        }
+        Data::Integer(added)
+      }
+      None => Data::Error(WRONG_TYPE_ERROR.to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, _output: &Data, _nest: &Nest) -> Option<Data> {
+    Some(input.clone())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::hset_command::HSetCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  fn test_command_new() {
+    let command = HSetCommand::new(&[TEST_KEY.as_bytes(), b"a", b"1", b"b", b"2"]).unwrap();
+    assert_eq!(command.key, TEST_KEY);
+    assert_eq!(
+      command.entries,
+      vec![
+        (b"a".to_vec(), b"1".to_vec()),
+        (b"b".to_vec(), b"2".to_vec())
+      ]
+    );
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HSET command arguments: Wrong number of arguments. Expected at least 3, got 2."
+  )]
+  fn test_command_new_2_args() {
+    HSetCommand::new(&[TEST_KEY.as_bytes(), b"a"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse HSET command arguments: Wrong number of arguments. Expected field value pairs."
+  )]
+  fn test_command_new_odd_pairs() {
+    HSetCommand::new(&[TEST_KEY.as_bytes(), b"a", b"1", b"b"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = HSetCommand::new(&[TEST_KEY.as_bytes(), b"a", b"1", b"b", b"2"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+
+    let command = HSetCommand::new(&[TEST_KEY.as_bytes(), b"a", b"3", b"c", b"4"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+
+    let hash = nest.get(TEST_KEY).unwrap().value().as_hash().unwrap();
+    assert_eq!(hash.len(), 3);
+    assert_eq!(hash.get(&b"a"[..]), Some(&b"3".to_vec()));
+  }
+
+  #[rstest]
+  fn test_command_execute_wrong_type(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    let command = HSetCommand::new(&[TEST_KEY.as_bytes(), b"a", b"1"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -0,0 +1,220 @@
+//! Engine INCR, DECR, INCRBY and DECRBY commands.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Operation performed by an [IncrCommand].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IncrOperation {
+  /// Increment by one.
+  Incr,
+  /// Decrement by one.
+  Decr,
+  /// Increment by a given amount.
+  IncrBy,
+  /// Decrement by a given amount.
+  DecrBy,
+}
+
+/// Engine INCR, DECR, INCRBY and DECRBY commands.
+#[derive(Clone, Debug)]
+pub struct IncrCommand {
+  key: String,
+  operation: IncrOperation,
+  amount: i64,
+}
+
+impl IncrCommand {
+  /// Return a new [IncrCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key) for `INCR` and `DECR`,
+  ///   2 arguments (key, amount) for `INCRBY` and `DECRBY`.
+  /// * `operation` - [IncrOperation] performed by the command
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{IncrCommand, IncrOperation};
+  ///
+  /// let args = &[&b"key"[..], &b"5"[..]];
+  /// let cmd = IncrCommand::new(args, IncrOperation::DecrBy).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "DECRBY key 5");
+  /// ```
+  pub fn new(args: &[&[u8]], operation: IncrOperation) -> Result<IncrCommand> {
+    let name = command_name(operation);
+    let amount = match operation {
+      IncrOperation::Incr | IncrOperation::Decr => {
+        check_args_len(name, args, 1)?;
+        1
+      }
+      IncrOperation::IncrBy | IncrOperation::DecrBy => {
+        check_args_len(name, args, 2)?;
+        parse_integer(args.get(1).unwrap())?
+      }
+    };
+    Ok(IncrCommand {
+      key: parse_string(args.first().unwrap())?,
+      operation,
+      amount,
+    })
+  }
+}
+
+/// Return the name of the command performing the given [IncrOperation].
+fn command_name(operation: IncrOperation) -> &'static str {
+  match operation {
+    IncrOperation::Incr => "INCR",
+    IncrOperation::Decr => "DECR",
+    IncrOperation::IncrBy => "INCRBY",
+    IncrOperation::DecrBy => "DECRBY",
+  }
+}
+
+impl fmt::Display for IncrCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} {}", command_name(self.operation), self.key)?;
+    if let IncrOperation::IncrBy | IncrOperation::DecrBy = self.operation {
+      write!(f, " {}", self.amount)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for IncrCommand {
+  /// Execute the `INCR key`, `DECR key`, `INCRBY key increment` or `DECRBY key decrement`
+  /// command on a given [Nest].
+  ///
+  /// Missing keys are set to `0` before being incremented, existing keys keep their time to live.
+  /// Return the new value.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let increment = match self.operation {
+      IncrOperation::Incr | IncrOperation::IncrBy => Some(self.amount),
+      IncrOperation::Decr | IncrOperation::DecrBy => self.amount.checked_neg(),
+    };
+    let bytes = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_string_mut() {
+        Some(bytes) => Some(bytes),
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => None,
+    };
+    let current = match bytes.as_deref() {
+      Some(bytes) => match parse_integer(bytes) {
+        Ok(current) => current,
+        Err(error) => return Data::Error(error.to_string()),
+      },
+      None => 0,
+    };
+    let value = match increment.and_then(|increment| current.checked_add(increment)) {
+      Some(value) => value,
+      None => return Data::Error("Increment or decrement would overflow".to_string()),
+    };
+    match bytes {
+      Some(bytes) => *bytes = value.to_string().into_bytes(),
+      None => nest.set(Egg::new(&self.key, value.to_string().into_bytes())),
+    }
+    Data::Integer(value)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::incr_command::{IncrCommand, IncrOperation};
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &b"10"[..]));
+    nest.set(Egg::new("text", &b"value"[..]));
+    nest.set(Egg::new("min", i64::MIN.to_string().into_bytes()));
+    nest.set(Egg::new("set", HashSet::new()));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse INCR command arguments: Wrong number of arguments. Expected 1, got 2."
+  )]
+  fn test_command_new_args() {
+    IncrCommand::new(&[TEST_KEY.as_bytes(), b"1"], IncrOperation::Incr).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = IncrCommand::new(&[TEST_KEY.as_bytes()], IncrOperation::Incr).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(11));
+    let command = IncrCommand::new(&[TEST_KEY.as_bytes(), b"20"], IncrOperation::DecrBy).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(-9));
+    assert_eq!(
+      nest.get(TEST_KEY).unwrap().value().as_string(),
+      Some(&b"-9"[..])
+    );
+
+    let command = IncrCommand::new(&[b"missing"], IncrOperation::Decr).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(-1));
+  }
+
+  #[rstest]
+  fn test_command_execute_keep_ttl(mut nest: Nest) {
+    let expires_at = Utc::now() + Duration::seconds(10);
+    nest.expire(TEST_KEY, Some(expires_at));
+    let command = IncrCommand::new(&[TEST_KEY.as_bytes(), b"5"], IncrOperation::IncrBy).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(15));
+    assert_eq!(nest.get(TEST_KEY).unwrap().expires_at(), Some(&expires_at));
+  }
+
+  #[rstest]
+  fn test_command_execute_invalid(mut nest: Nest) {
+    let command = IncrCommand::new(&[b"text"], IncrOperation::Incr).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Value is not an integer or out of range".to_string())
+    );
+
+    let command = IncrCommand::new(&[b"min"], IncrOperation::Decr).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Increment or decrement would overflow".to_string())
+    );
+    let min = i64::MIN.to_string();
+    let command = IncrCommand::new(
+      &[TEST_KEY.as_bytes(), min.as_bytes()],
+      IncrOperation::DecrBy,
+    )
+    .unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Increment or decrement would overflow".to_string())
+    );
+
+    let command = IncrCommand::new(&[b"set"], IncrOperation::Incr).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
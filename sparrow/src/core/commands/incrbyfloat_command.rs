@@ -0,0 +1,137 @@
+//! Engine INCRBYFLOAT command.
+
+use crate::core::commands::args::{check_args_len, parse_float, parse_string};
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine INCRBYFLOAT command.
+#[derive(Clone, Debug)]
+pub struct IncrByFloatCommand {
+  key: String,
+  increment: f64,
+}
+
+impl IncrByFloatCommand {
+  /// Return a new [IncrByFloatCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, increment).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::IncrByFloatCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"0.5"[..]];
+  /// let cmd = IncrByFloatCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "INCRBYFLOAT key 0.5");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<IncrByFloatCommand> {
+    check_args_len("INCRBYFLOAT", args, 2)?;
+    Ok(IncrByFloatCommand {
+      key: parse_string(args.first().unwrap())?,
+      increment: parse_float(args.get(1).unwrap())?,
+    })
+  }
+}
+
+impl fmt::Display for IncrByFloatCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "INCRBYFLOAT {} {}", self.key, self.increment)
+  }
+}
+
+impl Command for IncrByFloatCommand {
+  /// Execute the `INCRBYFLOAT key increment` command on a given [Nest].
+  ///
+  /// Missing keys are set to `0` before being incremented, existing keys keep their time to live.
+  /// Return the new value as a bulk string.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let bytes = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_string_mut() {
+        Some(bytes) => Some(bytes),
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => None,
+    };
+    let current = match bytes.as_deref() {
+      Some(bytes) => match parse_float(bytes) {
+        Ok(current) => current,
+        Err(error) => return Data::Error(error.to_string()),
+      },
+      None => 0.0,
+    };
+    let value = current + self.increment;
+    if !value.is_finite() {
+      return Data::Error("Increment would produce NaN or Infinity".to_string());
+    }
+    let value = value.to_string().into_bytes();
+    match bytes {
+      Some(bytes) => *bytes = value.clone(),
+      None => nest.set(Egg::new(&self.key, value.clone())),
+    }
+    Data::BulkString(value)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::BulkString(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::incrbyfloat_command::IncrByFloatCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &b"10.5"[..]));
+    nest.set(Egg::new("text", &b"value"[..]));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is not a valid float")]
+  fn test_command_new_not_float() {
+    IncrByFloatCommand::new(&[TEST_KEY.as_bytes(), b"one"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = IncrByFloatCommand::new(&[TEST_KEY.as_bytes(), b"0.1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("10.6".into()));
+    let command = IncrByFloatCommand::new(&[TEST_KEY.as_bytes(), b"-5.6"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("5".into()));
+    let command = IncrByFloatCommand::new(&[b"missing", b"3e2"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("300".into()));
+  }
+
+  #[rstest]
+  fn test_command_execute_invalid(mut nest: Nest) {
+    let command = IncrByFloatCommand::new(&[b"text", b"1"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Value is not a valid float".to_string())
+    );
+    let command = IncrByFloatCommand::new(&[TEST_KEY.as_bytes(), b"inf"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Increment would produce NaN or Infinity".to_string())
+    );
+  }
+}
@@ -0,0 +1,100 @@
+//! Engine KEYS command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::pattern::glob_match;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine KEYS command.
+#[derive(Clone, Debug)]
+pub struct KeysCommand {
+  pattern: String,
+}
+
+impl KeysCommand {
+  /// Return a new [KeysCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (pattern).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::KeysCommand;
+  ///
+  /// let args = &[&b"user:*"[..]];
+  /// let cmd = KeysCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "KEYS user:*");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<KeysCommand> {
+    check_args_len("KEYS", args, 1)?;
+    Ok(KeysCommand {
+      pattern: parse_string(args.first().unwrap())?,
+    })
+  }
+}
+
+impl fmt::Display for KeysCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "KEYS {}", self.pattern)
+  }
+}
+
+impl Command for KeysCommand {
+  /// Execute the `KEYS pattern` command on a given [Nest].
+  ///
+  /// Return every key matching the glob-style pattern, in no particular order.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let keys = nest
+      .keys()
+      .filter(|key| glob_match(self.pattern.as_bytes(), key.as_bytes()))
+      .map(|key| Data::BulkString(key.as_bytes().to_vec()))
+      .collect();
+    Data::Array(keys)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::keys_command::KeysCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    for key in ["user:1", "user:2", "session:1"] {
+      nest.set(Egg::new(key, &b"value"[..]));
+    }
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = KeysCommand::new(&[b"user:*"]).unwrap();
+    let mut keys = match command.execute(&mut nest) {
+      Data::Array(keys) => keys,
+      output => panic!("Unexpected output {:?}", output),
+    };
+    keys.sort_by_key(|key| format!("{:?}", key));
+    assert_eq!(
+      keys,
+      vec![
+        Data::BulkString(b"user:1".to_vec()),
+        Data::BulkString(b"user:2".to_vec())
+      ]
+    );
+
+    let command = KeysCommand::new(&[b"*:[^1]"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![Data::BulkString(b"user:2".to_vec())])
+    );
+  }
+}
@@ -0,0 +1,95 @@
+//! Engine LINDEX command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::list::resolve_index;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine LINDEX command.
+#[derive(Clone, Debug)]
+pub struct LIndexCommand {
+  key: String,
+  index: i64,
+}
+
+impl LIndexCommand {
+  /// Return a new [LIndexCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, index).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LIndexCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"-1"[..]];
+  /// let cmd = LIndexCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LINDEX key -1");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LIndexCommand> {
+    check_args_len("LINDEX", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let index = parse_integer(args.get(1).unwrap())?;
+    Ok(LIndexCommand { key, index })
+  }
+}
+
+impl fmt::Display for LIndexCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "LINDEX {} {}", self.key, self.index)
+  }
+}
+
+impl Command for LIndexCommand {
+  /// Execute the `LINDEX key index` command on a given [Nest].
+  ///
+  /// Return [Data::Null] if the index is out of range.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::List(list)) => resolve_index(self.index, list.len())
+        .map(|index| Data::BulkString(list[index].clone()))
+        .unwrap_or(Data::Null),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Null,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::lindex_command::LIndexCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    for (index, expected) in [
+      ("0", Data::BulkString("a".into())),
+      ("-1", Data::BulkString("b".into())),
+      ("2", Data::Null),
+      ("-3", Data::Null),
+    ] {
+      let command = LIndexCommand::new(&[TEST_KEY.as_bytes(), index.as_bytes()]).unwrap();
+      assert_eq!(command.execute(&mut nest), expected);
+    }
+  }
+}
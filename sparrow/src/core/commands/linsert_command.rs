@@ -0,0 +1,163 @@
+//! Engine LINSERT command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Position of an inserted element relative to the pivot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum InsertPosition {
+  Before,
+  After,
+}
+
+/// Engine LINSERT command.
+#[derive(Clone, Debug)]
+pub struct LInsertCommand {
+  key: String,
+  position: InsertPosition,
+  pivot: Vec<u8>,
+  element: Vec<u8>,
+}
+
+impl LInsertCommand {
+  /// Return a new [LInsertCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 4 arguments
+  ///   (key, `BEFORE` or `AFTER`, pivot, element).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LInsertCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"before"[..], &b"b"[..], &b"a"[..]];
+  /// let cmd = LInsertCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LINSERT key BEFORE b a");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LInsertCommand> {
+    check_args_len("LINSERT", args, 4)?;
+    let key = parse_string(args.first().unwrap())?;
+    let position = match parse_string(args.get(1).unwrap())?.to_uppercase().as_str() {
+      "BEFORE" => InsertPosition::Before,
+      "AFTER" => InsertPosition::After,
+      option => {
+        return Err(
+          format!(
+            "Cannot parse LINSERT command arguments: Syntax error near {}.",
+            option
+          )
+          .into(),
+        )
+      }
+    };
+    Ok(LInsertCommand {
+      key,
+      position,
+      pivot: args.get(2).unwrap().to_vec(),
+      element: args.get(3).unwrap().to_vec(),
+    })
+  }
+}
+
+impl fmt::Display for LInsertCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let position = match self.position {
+      InsertPosition::Before => "BEFORE",
+      InsertPosition::After => "AFTER",
+    };
+    write!(
+      f,
+      "LINSERT {} {} {} {}",
+      self.key,
+      position,
+      String::from_utf8_lossy(&self.pivot),
+      String::from_utf8_lossy(&self.element)
+    )
+  }
+}
+
+impl Command for LInsertCommand {
+  /// Execute the `LINSERT key BEFORE|AFTER pivot element` command on a given [Nest].
+  ///
+  /// Return the length of the list, `-1` if the pivot was not found and `0` if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let list = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_list_mut() {
+        Some(list) => list,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::Integer(0),
+    };
+    match list.iter().position(|element| *element == self.pivot) {
+      Some(index) => {
+        let index = match self.position {
+          InsertPosition::Before => index,
+          InsertPosition::After => index + 1,
+        };
+        list.insert(index, self.element.clone());
+        Data::Integer(list.len() as i64)
+      }
+      None => Data::Integer(-1),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(length) if *length > 0 => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::linsert_command::LInsertCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"c".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse LINSERT command arguments: Syntax error near MIDDLE.")]
+  fn test_command_new_invalid_position() {
+    LInsertCommand::new(&[TEST_KEY.as_bytes(), b"middle", b"a", b"b"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = LInsertCommand::new(&[TEST_KEY.as_bytes(), b"AFTER", b"a", b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(3));
+    let command = LInsertCommand::new(&[TEST_KEY.as_bytes(), b"BEFORE", b"a", b"0"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(4));
+
+    let list = nest.get(TEST_KEY).unwrap().value().as_list().unwrap();
+    assert_eq!(
+      list,
+      &vec![b"0".to_vec(), b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]
+    );
+
+    let command = LInsertCommand::new(&[TEST_KEY.as_bytes(), b"AFTER", b"z", b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(-1));
+
+    let command = LInsertCommand::new(&[b"missing", b"AFTER", b"a", b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+  }
+}
@@ -0,0 +1,143 @@
+//! Utilities shared by list commands.
+
+use crate::core::commands::args::parse_string;
+use crate::errors::Result;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// End of a list where elements are pushed or popped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ListEnd {
+  /// Head of the list.
+  Left,
+  /// Tail of the list.
+  Right,
+}
+
+impl ListEnd {
+  /// Parse a `LEFT` or `RIGHT` command argument into a [ListEnd].
+  ///
+  /// # Arguments
+  /// * `name` - Name of the command, used in the error message
+  /// * `arg` - Raw argument bytes
+  pub fn parse(name: &str, arg: &[u8]) -> Result<ListEnd> {
+    match parse_string(arg)?.to_uppercase().as_str() {
+      "LEFT" => Ok(ListEnd::Left),
+      "RIGHT" => Ok(ListEnd::Right),
+      arg => Err(
+        format!(
+          "Cannot parse {} command arguments: Syntax error near {}.",
+          name, arg
+        )
+        .into(),
+      ),
+    }
+  }
+
+  /// Push an element at this end of a list.
+  pub fn push(self, list: &mut VecDeque<Vec<u8>>, element: Vec<u8>) {
+    match self {
+      ListEnd::Left => list.push_front(element),
+      ListEnd::Right => list.push_back(element),
+    }
+  }
+
+  /// Pop an element from this end of a list.
+  pub fn pop(self, list: &mut VecDeque<Vec<u8>>) -> Option<Vec<u8>> {
+    match self {
+      ListEnd::Left => list.pop_front(),
+      ListEnd::Right => list.pop_back(),
+    }
+  }
+}
+
+impl fmt::Display for ListEnd {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ListEnd::Left => write!(f, "LEFT"),
+      ListEnd::Right => write!(f, "RIGHT"),
+    }
+  }
+}
+
+/// Resolve an index into a position in a list.
+///
+/// Negative indices count from the tail: `-1` is the last element.
+/// Return [None] if the index is out of range.
+///
+/// # Arguments
+/// * `index` - Index to resolve
+/// * `len` - Length of the list
+pub fn resolve_index(index: i64, len: usize) -> Option<usize> {
+  let len = len as i64;
+  let index = if index < 0 { index + len } else { index };
+  (0..len).contains(&index).then_some(index as usize)
+}
+
+/// Resolve an inclusive range of indices into positions in a list.
+///
+/// Negative indices count from the tail. Out of range indices are clamped to the list bounds.
+/// Return [None] if the range is empty.
+///
+/// # Arguments
+/// * `start` - Index of the first element of the range
+/// * `stop` - Index of the last element of the range
+/// * `len` - Length of the list
+pub fn resolve_range(start: i64, stop: i64, len: usize) -> Option<(usize, usize)> {
+  let len = len as i64;
+  let start = if start < 0 {
+    (start + len).max(0)
+  } else {
+    start
+  };
+  let stop = if stop < 0 {
+    stop + len
+  } else {
+    stop.min(len - 1)
+  };
+  (start <= stop && start < len).then_some((start as usize, stop as usize))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::list::{resolve_index, resolve_range, ListEnd};
+  use std::collections::VecDeque;
+
+  #[test]
+  fn test_list_end() {
+    let mut list = VecDeque::new();
+    ListEnd::Left.push(&mut list, b"a".to_vec());
+    ListEnd::Left.push(&mut list, b"b".to_vec());
+    ListEnd::Right.push(&mut list, b"c".to_vec());
+    assert_eq!(list, vec![b"b".to_vec(), b"a".to_vec(), b"c".to_vec()]);
+
+    assert_eq!(ListEnd::Right.pop(&mut list), Some(b"c".to_vec()));
+    assert_eq!(ListEnd::parse("LMOVE", b"left").unwrap(), ListEnd::Left);
+    assert_eq!(format!("{}", ListEnd::Right), "RIGHT");
+    assert!(ListEnd::parse("LMOVE", b"middle").is_err());
+    assert_eq!(ListEnd::Left.pop(&mut list), Some(b"b".to_vec()));
+  }
+
+  #[test]
+  fn test_resolve_index() {
+    assert_eq!(resolve_index(0, 3), Some(0));
+    assert_eq!(resolve_index(2, 3), Some(2));
+    assert_eq!(resolve_index(3, 3), None);
+    assert_eq!(resolve_index(-1, 3), Some(2));
+    assert_eq!(resolve_index(-3, 3), Some(0));
+    assert_eq!(resolve_index(-4, 3), None);
+    assert_eq!(resolve_index(0, 0), None);
+  }
+
+  #[test]
+  fn test_resolve_range() {
+    assert_eq!(resolve_range(0, -1, 3), Some((0, 2)));
+    assert_eq!(resolve_range(-100, 100, 3), Some((0, 2)));
+    assert_eq!(resolve_range(1, 1, 3), Some((1, 1)));
+    assert_eq!(resolve_range(-2, -1, 3), Some((1, 2)));
+    assert_eq!(resolve_range(2, 1, 3), None);
+    assert_eq!(resolve_range(3, 5, 3), None);
+    assert_eq!(resolve_range(0, -4, 3), None);
+    assert_eq!(resolve_range(0, -1, 0), None);
+  }
+}
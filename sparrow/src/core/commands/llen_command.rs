@@ -0,0 +1,89 @@
+//! Engine LLEN command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine LLEN command.
+#[derive(Clone, Debug)]
+pub struct LLenCommand {
+  key: String,
+}
+
+impl LLenCommand {
+  /// Return a new [LLenCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LLenCommand;
+  ///
+  /// let args = &[&b"key"[..]];
+  /// let cmd = LLenCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LLEN key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LLenCommand> {
+    check_args_len("LLEN", args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(LLenCommand { key })
+  }
+}
+
+impl fmt::Display for LLenCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "LLEN {}", self.key)
+  }
+}
+
+impl Command for LLenCommand {
+  /// Execute the `LLEN key` command on a given [Nest].
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::List(list)) => Data::Integer(list.len() as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::llen_command::LLenCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = LLenCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into();
+    nest.set(Egg::new(TEST_KEY, list));
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -0,0 +1,214 @@
+//! Engine LMOVE command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::list::ListEnd;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Engine LMOVE command.
+#[derive(Clone, Debug)]
+pub struct LMoveCommand {
+  source: String,
+  destination: String,
+  from: ListEnd,
+  to: ListEnd,
+}
+
+impl LMoveCommand {
+  /// Return a new [LMoveCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 4 arguments
+  ///   (source, destination, `LEFT` or `RIGHT`, `LEFT` or `RIGHT`).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LMoveCommand;
+  ///
+  /// let args = &[&b"source"[..], &b"destination"[..], &b"right"[..], &b"left"[..]];
+  /// let cmd = LMoveCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LMOVE source destination RIGHT LEFT");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LMoveCommand> {
+    check_args_len("LMOVE", args, 4)?;
+    Ok(LMoveCommand {
+      source: parse_string(args.first().unwrap())?,
+      destination: parse_string(args.get(1).unwrap())?,
+      from: ListEnd::parse("LMOVE", args.get(2).unwrap())?,
+      to: ListEnd::parse("LMOVE", args.get(3).unwrap())?,
+    })
+  }
+}
+
+impl fmt::Display for LMoveCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "LMOVE {} {} {} {}",
+      self.source, self.destination, self.from, self.to
+    )
+  }
+}
+
+impl Command for LMoveCommand {
+  /// Execute the `LMOVE source destination LEFT|RIGHT LEFT|RIGHT` command on a given [Nest].
+  ///
+  /// See [move_element].
+  fn execute(&self, nest: &mut Nest) -> Data {
+    move_element(nest, &self.source, &self.destination, self.from, self.to)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::BulkString(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+
+  fn pushed_keys(&self) -> Vec<&str> {
+    vec![&self.destination]
+  }
+}
+
+/// Pop an element from a list and push it to another list.
+///
+/// Return the moved element, or [Data::Null] if the source list does not exist.
+/// Nothing is moved if one of the keys holds a value that is not a list.
+///
+/// # Arguments
+/// * `nest` - [Nest] containing the lists
+/// * `source` - Key of the list to pop the element from
+/// * `destination` - Key of the list to push the element to
+/// * `from` - [ListEnd] where the element is popped
+/// * `to` - [ListEnd] where the element is pushed
+pub fn move_element(
+  nest: &mut Nest,
+  source: &str,
+  destination: &str,
+  from: ListEnd,
+  to: ListEnd,
+) -> Data {
+  if let Some(egg) = nest.get(destination) {
+    if egg.value().as_list().is_none() {
+      return Data::Error(WRONG_TYPE_ERROR.to_string());
+    }
+  }
+  let list = match nest.get_mut(source) {
+    Some(egg) => match egg.value_mut().as_list_mut() {
+      Some(list) => list,
+      None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+    },
+    None => return Data::Null,
+  };
+  let element = match from.pop(list) {
+    Some(element) => element,
+    None => return Data::Null,
+  };
+  if list.is_empty() {
+    nest.rem(source);
+  }
+  let egg = nest.get_or_insert_with(destination, || Value::List(VecDeque::new()));
+  if let Some(list) = egg.value_mut().as_list_mut() {
+    to.push(list, element.clone());
+  }
+  Data::BulkString(element)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::lmove_command::LMoveCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const SOURCE_KEY: &str = "source";
+  const DESTINATION_KEY: &str = "destination";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(SOURCE_KEY, list));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse LMOVE command arguments: Syntax error near UP.")]
+  fn test_command_new_invalid_end() {
+    LMoveCommand::new(&[b"source", b"destination", b"up", b"left"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let args = &[
+      SOURCE_KEY.as_bytes(),
+      DESTINATION_KEY.as_bytes(),
+      b"RIGHT",
+      b"LEFT",
+    ];
+    let command = LMoveCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("b".into()));
+    assert_eq!(command.execute(&mut nest), Data::BulkString("a".into()));
+    assert_eq!(command.execute(&mut nest), Data::Null);
+
+    assert!(nest.get(SOURCE_KEY).is_none());
+    let list = nest
+      .get(DESTINATION_KEY)
+      .unwrap()
+      .value()
+      .as_list()
+      .unwrap();
+    assert_eq!(list, &vec![b"a".to_vec(), b"b".to_vec()]);
+  }
+
+  #[rstest]
+  fn test_command_execute_rotate(mut nest: Nest) {
+    let args = &[
+      SOURCE_KEY.as_bytes(),
+      SOURCE_KEY.as_bytes(),
+      b"LEFT",
+      b"RIGHT",
+    ];
+    let command = LMoveCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("a".into()));
+
+    let list = nest.get(SOURCE_KEY).unwrap().value().as_list().unwrap();
+    assert_eq!(list, &vec![b"b".to_vec(), b"a".to_vec()]);
+  }
+
+  #[rstest]
+  fn test_command_execute_wrong_type(mut nest: Nest) {
+    nest.set(Egg::new(DESTINATION_KEY, &b"value"[..]));
+    let args = &[
+      SOURCE_KEY.as_bytes(),
+      DESTINATION_KEY.as_bytes(),
+      b"LEFT",
+      b"LEFT",
+    ];
+    let command = LMoveCommand::new(args).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+    assert_eq!(
+      nest
+        .get(SOURCE_KEY)
+        .unwrap()
+        .value()
+        .as_list()
+        .unwrap()
+        .len(),
+      2
+    );
+  }
+}
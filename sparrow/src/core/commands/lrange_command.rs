@@ -0,0 +1,124 @@
+//! Engine LRANGE command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::list::resolve_range;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine LRANGE command.
+#[derive(Clone, Debug)]
+pub struct LRangeCommand {
+  key: String,
+  start: i64,
+  stop: i64,
+}
+
+impl LRangeCommand {
+  /// Return a new [LRangeCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, start, stop).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LRangeCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"0"[..], &b"-1"[..]];
+  /// let cmd = LRangeCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LRANGE key 0 -1");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LRangeCommand> {
+    check_args_len("LRANGE", args, 3)?;
+    let key = parse_string(args.first().unwrap())?;
+    let start = parse_integer(args.get(1).unwrap())?;
+    let stop = parse_integer(args.get(2).unwrap())?;
+    Ok(LRangeCommand { key, start, stop })
+  }
+}
+
+impl fmt::Display for LRangeCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "LRANGE {} {} {}", self.key, self.start, self.stop)
+  }
+}
+
+impl Command for LRangeCommand {
+  /// Execute the `LRANGE key start stop` command on a given [Nest].
+  ///
+  /// Both `start` and `stop` are inclusive and can be negative to count from the tail.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let list = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::List(list)) => list,
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::Array(vec![]),
+    };
+    match resolve_range(self.start, self.stop, list.len()) {
+      Some((start, stop)) => Data::Array(
+        list
+          .range(start..=stop)
+          .map(|element| Data::BulkString(element.clone()))
+          .collect(),
+      ),
+      None => Data::Array(vec![]),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::lrange_command::LRangeCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  fn lrange(nest: &mut Nest, key: &str, start: &str, stop: &str) -> Data {
+    LRangeCommand::new(&[key.as_bytes(), start.as_bytes(), stop.as_bytes()])
+      .unwrap()
+      .execute(nest)
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is not an integer or out of range")]
+  fn test_command_new_not_integer() {
+    LRangeCommand::new(&[TEST_KEY.as_bytes(), b"0", b"last"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    assert_eq!(
+      lrange(&mut nest, TEST_KEY, "0", "-1"),
+      Data::Array(vec![
+        Data::BulkString("a".into()),
+        Data::BulkString("b".into()),
+        Data::BulkString("c".into()),
+      ])
+    );
+    assert_eq!(
+      lrange(&mut nest, TEST_KEY, "-2", "100"),
+      Data::Array(vec![
+        Data::BulkString("b".into()),
+        Data::BulkString("c".into()),
+      ])
+    );
+    assert_eq!(lrange(&mut nest, TEST_KEY, "2", "1"), Data::Array(vec![]));
+    assert_eq!(lrange(&mut nest, "missing", "0", "-1"), Data::Array(vec![]));
+  }
+}
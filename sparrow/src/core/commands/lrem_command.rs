@@ -0,0 +1,176 @@
+//! Engine LREM command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine LREM command.
+#[derive(Clone, Debug)]
+pub struct LRemCommand {
+  key: String,
+  count: i64,
+  element: Vec<u8>,
+}
+
+impl LRemCommand {
+  /// Return a new [LRemCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, count, element).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LRemCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"-2"[..], &b"a"[..]];
+  /// let cmd = LRemCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LREM key -2 a");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LRemCommand> {
+    check_args_len("LREM", args, 3)?;
+    let key = parse_string(args.first().unwrap())?;
+    let count = parse_integer(args.get(1).unwrap())?;
+    let element = args.get(2).unwrap().to_vec();
+    Ok(LRemCommand {
+      key,
+      count,
+      element,
+    })
+  }
+}
+
+impl fmt::Display for LRemCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "LREM {} {} {}",
+      self.key,
+      self.count,
+      String::from_utf8_lossy(&self.element)
+    )
+  }
+}
+
+impl Command for LRemCommand {
+  /// Execute the `LREM key count element` command on a given [Nest].
+  ///
+  /// Remove the first `count` occurrences of the element from the head if `count` is positive,
+  /// from the tail if it is negative, and every occurrence if it is `0`.
+  /// Return the number of removed elements.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let list = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_list_mut() {
+        Some(list) => list,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::Integer(0),
+    };
+    let limit = match self.count {
+      0 => usize::MAX,
+      count => count.unsigned_abs() as usize,
+    };
+    let positions: Box<dyn Iterator<Item = usize>> = if self.count < 0 {
+      Box::new((0..list.len()).rev())
+    } else {
+      Box::new(0..list.len())
+    };
+    let mut removed = vec![false; list.len()];
+    let mut count = 0;
+    for position in positions {
+      if count == limit {
+        break;
+      }
+      if list[position] == self.element {
+        removed[position] = true;
+        count += 1;
+      }
+    }
+    let mut removed = removed.into_iter();
+    list.retain(|_| !removed.next().unwrap_or(false));
+    if list.is_empty() {
+      nest.rem(&self.key);
+    }
+    Data::Integer(count as i64)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::lrem_command::LRemCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = ["a", "b", "a", "c", "a"]
+      .iter()
+      .map(|element| element.as_bytes().to_vec())
+      .collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  fn elements(nest: &mut Nest) -> Vec<String> {
+    nest
+      .get(TEST_KEY)
+      .map(|egg| {
+        egg
+          .value()
+          .as_list()
+          .unwrap()
+          .iter()
+          .map(|element| String::from_utf8(element.clone()).unwrap())
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  #[rstest]
+  fn test_command_execute_from_head(mut nest: Nest) {
+    let command = LRemCommand::new(&[TEST_KEY.as_bytes(), b"2", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    assert_eq!(elements(&mut nest), vec!["b", "c", "a"]);
+  }
+
+  #[rstest]
+  fn test_command_execute_from_tail(mut nest: Nest) {
+    let command = LRemCommand::new(&[TEST_KEY.as_bytes(), b"-2", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    assert_eq!(elements(&mut nest), vec!["a", "b", "c"]);
+  }
+
+  #[rstest]
+  fn test_command_execute_all(mut nest: Nest) {
+    let command = LRemCommand::new(&[TEST_KEY.as_bytes(), b"0", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(3));
+    assert_eq!(elements(&mut nest), vec!["b", "c"]);
+
+    let command = LRemCommand::new(&[TEST_KEY.as_bytes(), b"0", b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    for element in ["b", "c"] {
+      let command = LRemCommand::new(&[TEST_KEY.as_bytes(), b"0", element.as_bytes()]).unwrap();
+      command.execute(&mut nest);
+    }
+    assert!(nest.get(TEST_KEY).is_none());
+  }
+}
@@ -0,0 +1,126 @@
+//! Engine LSET command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::list::resolve_index;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine LSET command.
+#[derive(Clone, Debug)]
+pub struct LSetCommand {
+  key: String,
+  index: i64,
+  element: Vec<u8>,
+}
+
+impl LSetCommand {
+  /// Return a new [LSetCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, index, element).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LSetCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"0"[..], &b"a"[..]];
+  /// let cmd = LSetCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LSET key 0 a");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LSetCommand> {
+    check_args_len("LSET", args, 3)?;
+    let key = parse_string(args.first().unwrap())?;
+    let index = parse_integer(args.get(1).unwrap())?;
+    let element = args.get(2).unwrap().to_vec();
+    Ok(LSetCommand {
+      key,
+      index,
+      element,
+    })
+  }
+}
+
+impl fmt::Display for LSetCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "LSET {} {} {}",
+      self.key,
+      self.index,
+      String::from_utf8_lossy(&self.element)
+    )
+  }
+}
+
+impl Command for LSetCommand {
+  /// Execute the `LSET key index element` command on a given [Nest].
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let list = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_list_mut() {
+        Some(list) => list,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::Error("No such key".to_string()),
+    };
+    match resolve_index(self.index, list.len()) {
+      Some(index) => {
+        list[index] = self.element.clone();
+        Data::SimpleString("OK".to_string())
+      }
+      None => Data::Error("Index out of range".to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, _output: &Data, _nest: &Nest) -> Option<Data> {
+    Some(input.clone())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::lset_command::LSetCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = LSetCommand::new(&[TEST_KEY.as_bytes(), b"-1", b"c"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::SimpleString("OK".to_string())
+    );
+    let list = nest.get(TEST_KEY).unwrap().value().as_list().unwrap();
+    assert_eq!(list, &vec![b"a".to_vec(), b"c".to_vec()]);
+
+    let command = LSetCommand::new(&[TEST_KEY.as_bytes(), b"2", b"c"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Index out of range".to_string())
+    );
+
+    let command = LSetCommand::new(&[b"missing", b"0", b"c"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("No such key".to_string())
+    );
+  }
+}
@@ -0,0 +1,119 @@
+//! Engine LTRIM command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::list::resolve_range;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine LTRIM command.
+#[derive(Clone, Debug)]
+pub struct LTrimCommand {
+  key: String,
+  start: i64,
+  stop: i64,
+}
+
+impl LTrimCommand {
+  /// Return a new [LTrimCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, start, stop).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::LTrimCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"0"[..], &b"99"[..]];
+  /// let cmd = LTrimCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LTRIM key 0 99");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<LTrimCommand> {
+    check_args_len("LTRIM", args, 3)?;
+    let key = parse_string(args.first().unwrap())?;
+    let start = parse_integer(args.get(1).unwrap())?;
+    let stop = parse_integer(args.get(2).unwrap())?;
+    Ok(LTrimCommand { key, start, stop })
+  }
+}
+
+impl fmt::Display for LTrimCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "LTRIM {} {} {}", self.key, self.start, self.stop)
+  }
+}
+
+impl Command for LTrimCommand {
+  /// Execute the `LTRIM key start stop` command on a given [Nest].
+  ///
+  /// Only the elements in the inclusive range are kept. The key is removed if the range is empty.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let list = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_list_mut() {
+        Some(list) => list,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::SimpleString("OK".to_string()),
+    };
+    match resolve_range(self.start, self.stop, list.len()) {
+      Some((start, stop)) => {
+        list.truncate(stop + 1);
+        list.drain(..start);
+      }
+      None => {
+        nest.rem(&self.key);
+      }
+    }
+    Data::SimpleString("OK".to_string())
+  }
+
+  fn to_aof(&self, input: &Data, _output: &Data, _nest: &Nest) -> Option<Data> {
+    Some(input.clone())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::ltrim_command::LTrimCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = LTrimCommand::new(&[TEST_KEY.as_bytes(), b"1", b"-1"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::SimpleString("OK".to_string())
+    );
+    let list = nest.get(TEST_KEY).unwrap().value().as_list().unwrap();
+    assert_eq!(list, &vec![b"b".to_vec(), b"c".to_vec()]);
+  }
+
+  #[rstest]
+  fn test_command_execute_empty_range(mut nest: Nest) {
+    let command = LTrimCommand::new(&[TEST_KEY.as_bytes(), b"5", b"10"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::SimpleString("OK".to_string())
+    );
+    assert!(nest.get(TEST_KEY).is_none());
+  }
+}
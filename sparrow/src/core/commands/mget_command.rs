@@ -0,0 +1,99 @@
+//! Engine MGET command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::Value;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine MGET command.
+#[derive(Clone, Debug)]
+pub struct MGetCommand {
+  keys: Vec<String>,
+}
+
+impl MGetCommand {
+  /// Return a new [MGetCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 1 argument (key...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::MGetCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"other"[..]];
+  /// let cmd = MGetCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "MGET key other");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<MGetCommand> {
+    check_min_args_len("MGET", args, 1)?;
+    let keys = args
+      .iter()
+      .map(|key| parse_string(key))
+      .collect::<Result<_>>()?;
+    Ok(MGetCommand { keys })
+  }
+}
+
+impl fmt::Display for MGetCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "MGET {}", self.keys.join(" "))
+  }
+}
+
+impl Command for MGetCommand {
+  /// Execute the `MGET key [key ...]` command on a given [Nest].
+  ///
+  /// Return the values of the keys in order, [Data::Null] for missing keys
+  /// and for keys holding a value that is not a string.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    Data::Array(
+      self
+        .keys
+        .iter()
+        .map(|key| match nest.get(key).map(|egg| egg.value()) {
+          Some(Value::String(value)) => Data::BulkString(value.clone()),
+          _ => Data::Null,
+        })
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::mget_command::MGetCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new("a", &b"1"[..]));
+    nest.set(Egg::new("b", &b"2"[..]));
+    nest.set(Egg::new("set", HashSet::new()));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = MGetCommand::new(&[b"a", b"missing", b"set", b"b"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![
+        Data::BulkString("1".into()),
+        Data::Null,
+        Data::Null,
+        Data::BulkString("2".into()),
+      ])
+    );
+  }
+}
@@ -1,12 +1,159 @@
 //! Engine commands.
 //!
 //! This module is used to define commands that will be executed by Sparrow Engine.
+mod append_command;
+mod args;
+mod bitcount_command;
+mod bitfield_command;
+mod bitmap;
+mod bitop_command;
+mod bitpos_command;
+mod blocking_command;
 mod command;
+mod copy_command;
+mod database_command;
+mod dbsize_command;
+mod engine_command;
+mod exists_command;
+mod expiration;
+mod expire_command;
 mod get_command;
+mod getbit_command;
+mod getdel_command;
+mod getex_command;
+mod getrange_command;
+mod getset_command;
+mod hdel_command;
+mod hexists_command;
+mod hget_command;
+mod hgetall_command;
+mod hincrby_command;
+mod hlen_command;
+mod hmget_command;
+mod hset_command;
+mod incr_command;
+mod incrbyfloat_command;
+mod keys_command;
+mod lindex_command;
+mod linsert_command;
+mod list;
+mod llen_command;
+mod lmove_command;
+mod lrange_command;
+mod lrem_command;
+mod lset_command;
+mod ltrim_command;
+mod mget_command;
+mod mset_command;
+mod pattern;
+mod persist_command;
+mod pop_command;
+mod push_command;
+mod randomkey_command;
 mod rem_command;
+mod rename_command;
+mod sadd_command;
+mod scan_command;
+mod scard_command;
+mod set;
 mod set_command;
+mod set_operation_command;
+mod setbit_command;
+mod setnx_command;
+mod setrange_command;
+mod sismember_command;
+mod smembers_command;
+mod smismember_command;
+mod sorted_set;
+mod spop_command;
+mod srandmember_command;
+mod srem_command;
+mod strlen_command;
+mod transaction_command;
+mod ttl_command;
+mod type_command;
+mod zadd_command;
+mod zcard_command;
+mod zcount_command;
+mod zincrby_command;
+mod zpop_command;
+mod zrange_command;
+mod zrank_command;
+mod zrem_command;
+mod zscore_command;
 
-pub use command::{parse_command, Command};
+pub use append_command::AppendCommand;
+pub use bitcount_command::BitCountCommand;
+pub use bitfield_command::BitFieldCommand;
+pub use bitop_command::BitOpCommand;
+pub use bitpos_command::BitPosCommand;
+pub use blocking_command::BlockingCommand;
+pub use command::{command_data, parse_command, Command, ParsedCommand};
+pub use copy_command::CopyCommand;
+pub use database_command::DatabaseCommand;
+pub use dbsize_command::DbSizeCommand;
+pub use engine_command::EngineCommand;
+pub use exists_command::ExistsCommand;
+pub use expiration::{Expiration, TimeUnit};
+pub use expire_command::ExpireCommand;
 pub use get_command::GetCommand;
+pub use getbit_command::GetBitCommand;
+pub use getdel_command::GetDelCommand;
+pub use getex_command::GetExCommand;
+pub use getrange_command::GetRangeCommand;
+pub use getset_command::GetSetCommand;
+pub use hdel_command::HDelCommand;
+pub use hexists_command::HExistsCommand;
+pub use hget_command::HGetCommand;
+pub use hgetall_command::{HGetAllCommand, HashPart};
+pub use hincrby_command::HIncrByCommand;
+pub use hlen_command::HLenCommand;
+pub use hmget_command::HMGetCommand;
+pub use hset_command::HSetCommand;
+pub use incr_command::{IncrCommand, IncrOperation};
+pub use incrbyfloat_command::IncrByFloatCommand;
+pub use keys_command::KeysCommand;
+pub use lindex_command::LIndexCommand;
+pub use linsert_command::LInsertCommand;
+pub use list::ListEnd;
+pub use llen_command::LLenCommand;
+pub use lmove_command::LMoveCommand;
+pub use lrange_command::LRangeCommand;
+pub use lrem_command::LRemCommand;
+pub use lset_command::LSetCommand;
+pub use ltrim_command::LTrimCommand;
+pub use mget_command::MGetCommand;
+pub use mset_command::MSetCommand;
+pub use persist_command::PersistCommand;
+pub use pop_command::PopCommand;
+pub use push_command::PushCommand;
+pub use randomkey_command::RandomKeyCommand;
 pub use rem_command::RemCommand;
+pub use rename_command::RenameCommand;
+pub use sadd_command::SAddCommand;
+pub use scan_command::ScanCommand;
+pub use scard_command::SCardCommand;
 pub use set_command::SetCommand;
+pub use set_operation_command::{SetOperation, SetOperationCommand};
+pub use setbit_command::SetBitCommand;
+pub use setnx_command::SetNxCommand;
+pub use setrange_command::SetRangeCommand;
+pub use sismember_command::SIsMemberCommand;
+pub use smembers_command::SMembersCommand;
+pub use smismember_command::SMIsMemberCommand;
+pub use spop_command::SPopCommand;
+pub use srandmember_command::SRandMemberCommand;
+pub use srem_command::SRemCommand;
+pub use strlen_command::StrLenCommand;
+pub use transaction_command::TransactionCommand;
+pub use ttl_command::TtlCommand;
+pub use type_command::TypeCommand;
+pub use zadd_command::ZAddCommand;
+pub use zcard_command::ZCardCommand;
+pub use zcount_command::ZCountCommand;
+pub use zincrby_command::ZIncrByCommand;
+pub use zpop_command::{ScoreEnd, ZPopCommand};
+pub use zrange_command::ZRangeCommand;
+pub use zrank_command::ZRankCommand;
+pub use zrem_command::ZRemCommand;
+pub use zscore_command::ZScoreCommand;
@@ -0,0 +1,144 @@
+//! Engine MSET and MSETNX commands.
+
+use crate::core::commands::args::parse_string;
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine MSET and MSETNX commands.
+#[derive(Clone, Debug)]
+pub struct MSetCommand {
+  entries: Vec<(String, Vec<u8>)>,
+  /// Whether no key is set when at least one of them already exists (`MSETNX`).
+  nx: bool,
+}
+
+impl MSetCommand {
+  /// Return a new [MSetCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be key value pairs.
+  /// * `nx` - Whether keys are only set if none of them exists (`MSETNX`)
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::MSetCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"value"[..], &b"other"[..], &b"value"[..]];
+  /// let cmd = MSetCommand::new(args, true).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "MSETNX key value other value");
+  /// ```
+  pub fn new(args: &[&[u8]], nx: bool) -> Result<MSetCommand> {
+    if args.is_empty() || !args.len().is_multiple_of(2) {
+      return Err(
+        format!(
+          "Cannot parse {} command arguments: Wrong number of arguments. Expected key value pairs.",
+          command_name(nx)
+        )
+        .into(),
+      );
+    }
+    let entries = args
+      .chunks(2)
+      .map(|pair| Ok((parse_string(pair[0])?, pair[1].to_vec())))
+      .collect::<Result<_>>()?;
+    Ok(MSetCommand { entries, nx })
+  }
+}
+
+/// Return the name of the command, `MSETNX` if keys are only set when none of them exists.
+fn command_name(nx: bool) -> &'static str {
+  match nx {
+    true => "MSETNX",
+    false => "MSET",
+  }
+}
+
+impl fmt::Display for MSetCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", command_name(self.nx))?;
+    for (key, value) in &self.entries {
+      write!(f, " {} {}", key, String::from_utf8_lossy(value))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for MSetCommand {
+  /// Execute the `MSET key value [key value ...]` or `MSETNX key value [key value ...]` command
+  /// on a given [Nest].
+  ///
+  /// Like `SET`, previous values and expirations are replaced. `MSET` returns `OK`, while `MSETNX`
+  /// returns `1` if the keys were set and `0` if none was set because one of them already exists.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    if self.nx && self.entries.iter().any(|(key, _)| nest.get(key).is_some()) {
+      return Data::Integer(0);
+    }
+    for (key, value) in &self.entries {
+      nest.set(Egg::new(key, value.as_slice()));
+    }
+    match self.nx {
+      true => Data::Integer(1),
+      false => Data::SimpleString("OK".to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::mset_command::MSetCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new("a", &b"1"[..]));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse MSET command arguments: Wrong number of arguments. Expected key value pairs."
+  )]
+  fn test_command_new_odd_args() {
+    MSetCommand::new(&[b"a", b"1", b"b"], false).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = MSetCommand::new(&[b"a", b"2", b"b", b"3"], false).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::SimpleString("OK".to_string())
+    );
+    assert_eq!(nest.get("a").unwrap().value().as_string(), Some(&b"2"[..]));
+    assert_eq!(nest.get("b").unwrap().value().as_string(), Some(&b"3"[..]));
+  }
+
+  #[rstest]
+  fn test_command_execute_nx(mut nest: Nest) {
+    let command = MSetCommand::new(&[b"b", b"2", b"a", b"2"], true).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert!(nest.get("b").is_none());
+    assert_eq!(command.to_aof(&Data::Null, &Data::Integer(0), &nest), None);
+
+    let command = MSetCommand::new(&[b"b", b"2", b"c", b"3"], true).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(nest.get("c").unwrap().value().as_string(), Some(&b"3"[..]));
+  }
+}
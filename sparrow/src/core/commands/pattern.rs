@@ -0,0 +1,127 @@
+//! Glob-style pattern matching shared by keyspace commands.
+//!
+//! Supported tokens are `*` (any sequence of bytes), `?` (any byte), `[abc]`, `[^abc]` and `[a-z]`
+//! (byte classes), and `\x` (the literal byte `x`).
+
+/// Return whether a string matches a glob-style pattern.
+///
+/// # Arguments
+/// * `pattern` - Pattern, e.g. `user:*` or `h?llo`
+/// * `string` - String to match against the pattern
+pub fn glob_match(pattern: &[u8], string: &[u8]) -> bool {
+  let (mut pattern_index, mut string_index) = (0, 0);
+  // Positions right after the last `*` and of the byte it currently extends to
+  let mut backtrack = None;
+  while string_index < string.len() {
+    match pattern.get(pattern_index) {
+      Some(b'*') => {
+        pattern_index += 1;
+        backtrack = Some((pattern_index, string_index));
+        continue;
+      }
+      Some(_) => {
+        if let Some(len) = match_token(&pattern[pattern_index..], string[string_index]) {
+          pattern_index += len;
+          string_index += 1;
+          continue;
+        }
+      }
+      None => {}
+    }
+    // Let the last `*` match one more byte
+    match backtrack {
+      Some((star_index, star_string_index)) => {
+        pattern_index = star_index;
+        string_index = star_string_index + 1;
+        backtrack = Some((star_index, string_index));
+      }
+      None => return false,
+    }
+  }
+  pattern[pattern_index..].iter().all(|&token| token == b'*')
+}
+
+/// Match a byte against the token at the start of a pattern.
+///
+/// Return the length of the token if it matches, [None] otherwise.
+fn match_token(pattern: &[u8], byte: u8) -> Option<usize> {
+  match pattern {
+    [b'?', ..] => Some(1),
+    [b'\\', escaped, ..] => (*escaped == byte).then_some(2),
+    [b'[', class @ ..] => match_class(class, byte).map(|len| len + 1),
+    [token, ..] => (*token == byte).then_some(1),
+    [] => None,
+  }
+}
+
+/// Match a byte against a byte class, following its opening `[`.
+///
+/// Return the length of the class including its closing `]` if it matches, [None] otherwise.
+/// An unterminated class extends to the end of the pattern.
+fn match_class(class: &[u8], byte: u8) -> Option<usize> {
+  let (negated, mut index) = match class.first() {
+    Some(b'^') => (true, 1),
+    _ => (false, 0),
+  };
+  let mut matched = false;
+  loop {
+    match &class[index..] {
+      [] => break,
+      [b']', ..] => {
+        index += 1;
+        break;
+      }
+      [b'\\', escaped, ..] => {
+        matched |= *escaped == byte;
+        index += 2;
+      }
+      [start, b'-', end, ..] if *end != b']' => {
+        matched |= (*start.min(end)..=*start.max(end)).contains(&byte);
+        index += 3;
+      }
+      [token, ..] => {
+        matched |= *token == byte;
+        index += 1;
+      }
+    }
+  }
+  (matched != negated).then_some(index)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::pattern::glob_match;
+
+  #[test]
+  fn test_glob_match() {
+    for (pattern, string, expected) in [
+      (&b"*"[..], &b""[..], true),
+      (b"*", b"anything", true),
+      (b"h?llo", b"hello", true),
+      (b"h?llo", b"hllo", false),
+      (b"h*llo", b"heeeello", true),
+      (b"h*llo", b"hello world", false),
+      (b"user:*:name", b"user:42:name", true),
+      (b"user:*:name", b"user:42:age", false),
+      (b"h[ae]llo", b"hallo", true),
+      (b"h[ae]llo", b"hillo", false),
+      (b"h[^e]llo", b"hallo", true),
+      (b"h[^e]llo", b"hello", false),
+      (b"h[a-c]llo", b"hbllo", true),
+      (b"h[c-a]llo", b"hbllo", true),
+      (b"h[a-c]llo", b"hdllo", false),
+      (b"h\\*llo", b"h*llo", true),
+      (b"h\\*llo", b"hello", false),
+      (b"*a*b", b"xaxxab", true),
+      (b"a", b"ab", false),
+    ] {
+      assert_eq!(
+        glob_match(pattern, string),
+        expected,
+        "{} against {}",
+        String::from_utf8_lossy(pattern),
+        String::from_utf8_lossy(string)
+      );
+    }
+  }
+}
@@ -0,0 +1,123 @@
+//! Engine PERSIST command.
+
+use crate::core::commands::args::parse_string;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine PERSIST command.
+#[derive(Clone, Debug)]
+pub struct PersistCommand {
+  key: String,
+}
+
+impl PersistCommand {
+  /// Return a new [PersistCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::PersistCommand;
+  ///
+  /// let args = &[&b"key"[..]];
+  /// let cmd = PersistCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "PERSIST key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<PersistCommand> {
+    match args.len() {
+      1 => {
+        let key = parse_string(args.first().unwrap())?;
+        Ok(PersistCommand { key })
+      }
+      n => Err(
+        format!(
+          "Cannot parse PERSIST command arguments: Wrong number of arguments. Expected 1, got {}.",
+          n
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+impl fmt::Display for PersistCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "PERSIST {}", self.key)
+  }
+}
+
+impl Command for PersistCommand {
+  /// Execute the `PERSIST key` command on a given [Nest].
+  ///
+  /// Return `1` if the expiration date has been removed, `0` otherwise.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.expires_at().is_some()) {
+      Some(true) => Data::Integer(nest.expire(&self.key, None) as i64),
+      _ => Data::Integer(0),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(1) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::persist_command::PersistCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+  const TEST_VALUE: &str = "This is a test value!";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  fn test_command_new_1_args() {
+    let args = &[TEST_KEY.as_bytes()];
+    let command = PersistCommand::new(args).unwrap();
+    assert_eq!(command.key, TEST_KEY)
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse PERSIST command arguments: Wrong number of arguments. Expected 1, got 0."
+  )]
+  fn test_command_new_0_args() {
+    let args = &[];
+    PersistCommand::new(args).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes()];
+    let command = PersistCommand::new(args).unwrap();
+
+    // Key does not exist
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    // Key has no expiration
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    nest.expire(TEST_KEY, Some(Utc::now() + Duration::seconds(10)));
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(nest.get(TEST_KEY).unwrap().expires_at(), None);
+  }
+}
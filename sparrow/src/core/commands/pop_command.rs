@@ -0,0 +1,191 @@
+//! Engine LPOP and RPOP commands.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::list::ListEnd;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine LPOP and RPOP commands.
+#[derive(Clone, Debug)]
+pub struct PopCommand {
+  key: String,
+  count: Option<usize>,
+  end: ListEnd,
+}
+
+impl PopCommand {
+  /// Return a new [PopCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 or 2 arguments (key, count).
+  /// * `end` - [ListEnd] where elements are popped
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{ListEnd, PopCommand};
+  ///
+  /// let args = &[&b"key"[..], &b"2"[..]];
+  /// let cmd = PopCommand::new(args, ListEnd::Right).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "RPOP key 2");
+  /// ```
+  pub fn new(args: &[&[u8]], end: ListEnd) -> Result<PopCommand> {
+    match args {
+      [key] => Ok(PopCommand {
+        key: parse_string(key)?,
+        count: None,
+        end,
+      }),
+      [key, count] => {
+        let count = parse_integer(count)?;
+        if count < 0 {
+          return Err("Value is out of range, must be positive".into());
+        }
+        Ok(PopCommand {
+          key: parse_string(key)?,
+          count: Some(count as usize),
+          end,
+        })
+      }
+      _ => Err(
+        format!(
+          "Cannot parse {} command arguments: Wrong number of arguments. Expected 1 or 2, got {}.",
+          command_name(end),
+          args.len()
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+/// Return the name of the command popping elements at the given [ListEnd].
+fn command_name(end: ListEnd) -> &'static str {
+  match end {
+    ListEnd::Left => "LPOP",
+    ListEnd::Right => "RPOP",
+  }
+}
+
+impl fmt::Display for PopCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} {}", command_name(self.end), self.key)?;
+    if let Some(count) = self.count {
+      write!(f, " {}", count)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for PopCommand {
+  /// Execute the `LPOP key [count]` command on a given [Nest].
+  ///
+  /// Without count, return the popped element or [Data::Null].
+  /// With a count, return an array of popped elements or [Data::NullArray].
+  /// The key is removed along with its last element.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let list = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_list_mut() {
+        Some(list) => list,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None if self.count.is_some() => return Data::NullArray,
+      None => return Data::Null,
+    };
+    let output = match self.count {
+      Some(count) => Data::Array(
+        (0..count)
+          .map_while(|_| self.end.pop(list))
+          .map(Data::BulkString)
+          .collect(),
+      ),
+      None => self
+        .end
+        .pop(list)
+        .map(Data::BulkString)
+        .unwrap_or(Data::Null),
+    };
+    if list.is_empty() {
+      nest.rem(&self.key);
+    }
+    output
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Null | Data::NullArray => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::list::ListEnd;
+  use crate::core::commands::pop_command::PopCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()].into();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, list));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is out of range, must be positive")]
+  fn test_command_new_negative_count() {
+    PopCommand::new(&[TEST_KEY.as_bytes(), b"-1"], ListEnd::Left).unwrap();
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse LPOP command arguments: Wrong number of arguments. Expected 1 or 2, got 0."
+  )]
+  fn test_command_new_0_args() {
+    PopCommand::new(&[], ListEnd::Left).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = PopCommand::new(&[TEST_KEY.as_bytes()], ListEnd::Left).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("a".into()));
+
+    let command = PopCommand::new(&[TEST_KEY.as_bytes()], ListEnd::Right).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("c".into()));
+    assert_eq!(command.execute(&mut nest), Data::BulkString("b".into()));
+
+    // The key is removed along with its last element
+    assert!(nest.get(TEST_KEY).is_none());
+    assert_eq!(command.execute(&mut nest), Data::Null);
+  }
+
+  #[rstest]
+  fn test_command_execute_count(mut nest: Nest) {
+    let command = PopCommand::new(&[TEST_KEY.as_bytes(), b"2"], ListEnd::Right).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![
+        Data::BulkString("c".into()),
+        Data::BulkString("b".into())
+      ])
+    );
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![Data::BulkString("a".into())])
+    );
+    assert_eq!(command.execute(&mut nest), Data::NullArray);
+  }
+}
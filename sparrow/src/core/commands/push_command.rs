@@ -0,0 +1,138 @@
+//! Engine LPUSH and RPUSH commands.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::list::ListEnd;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Engine LPUSH and RPUSH commands.
+#[derive(Clone, Debug)]
+pub struct PushCommand {
+  key: String,
+  elements: Vec<Vec<u8>>,
+  end: ListEnd,
+}
+
+impl PushCommand {
+  /// Return a new [PushCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, element...).
+  /// * `end` - [ListEnd] where elements are pushed
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{ListEnd, PushCommand};
+  ///
+  /// let args = &[&b"key"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = PushCommand::new(args, ListEnd::Left).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "LPUSH key a b");
+  /// ```
+  pub fn new(args: &[&[u8]], end: ListEnd) -> Result<PushCommand> {
+    check_min_args_len(command_name(end), args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let elements = args[1..].iter().map(|element| element.to_vec()).collect();
+    Ok(PushCommand { key, elements, end })
+  }
+}
+
+/// Return the name of the command pushing elements at the given [ListEnd].
+fn command_name(end: ListEnd) -> &'static str {
+  match end {
+    ListEnd::Left => "LPUSH",
+    ListEnd::Right => "RPUSH",
+  }
+}
+
+impl fmt::Display for PushCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} {}", command_name(self.end), self.key)?;
+    for element in &self.elements {
+      write!(f, " {}", String::from_utf8_lossy(element))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for PushCommand {
+  /// Execute the `LPUSH key element [element ...]` command on a given [Nest].
+  ///
+  /// Elements are pushed one after the other. Return the length of the list.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::List(VecDeque::new()));
+    match egg.value_mut().as_list_mut() {
+      Some(list) => {
+        for element in &self.elements {
+          self.end.push(list, element.clone());
+        }
+        Data::Integer(list.len() as i64)
+      }
+      None => Data::Error(WRONG_TYPE_ERROR.to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, _output: &Data, _nest: &Nest) -> Option<Data> {
+    Some(input.clone())
+  }
+
+  fn pushed_keys(&self) -> Vec<&str> {
+    vec![&self.key]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::list::ListEnd;
+  use crate::core::commands::push_command::PushCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse RPUSH command arguments: Wrong number of arguments. Expected at least 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    PushCommand::new(&[TEST_KEY.as_bytes()], ListEnd::Right).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), b"a", b"b"];
+    let command = PushCommand::new(args, ListEnd::Left).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+
+    let args = &[TEST_KEY.as_bytes(), b"c"];
+    let command = PushCommand::new(args, ListEnd::Right).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(3));
+
+    let list = nest.get(TEST_KEY).unwrap().value().as_list().unwrap();
+    assert_eq!(list, &vec![b"b".to_vec(), b"a".to_vec(), b"c".to_vec()]);
+  }
+
+  #[rstest]
+  fn test_command_execute_wrong_type(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    let command = PushCommand::new(&[TEST_KEY.as_bytes(), b"a"], ListEnd::Left).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -0,0 +1,80 @@
+//! Engine RANDOMKEY command.
+
+use crate::core::commands::args::check_args_len;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine RANDOMKEY command.
+#[derive(Clone, Debug)]
+pub struct RandomKeyCommand;
+
+impl RandomKeyCommand {
+  /// Return a new [RandomKeyCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be no argument.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::RandomKeyCommand;
+  ///
+  /// let cmd = RandomKeyCommand::new(&[]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "RANDOMKEY");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<RandomKeyCommand> {
+    check_args_len("RANDOMKEY", args, 0)?;
+    Ok(RandomKeyCommand)
+  }
+}
+
+impl fmt::Display for RandomKeyCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "RANDOMKEY")
+  }
+}
+
+impl Command for RandomKeyCommand {
+  /// Execute the `RANDOMKEY` command on a given [Nest].
+  ///
+  /// Return a random key, or [Data::Null] if the [Nest] is empty.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.random_key() {
+      Some(key) => Data::BulkString(key.into_bytes()),
+      None => Data::Null,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::randomkey_command::RandomKeyCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = RandomKeyCommand::new(&[]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+
+    nest.set(Egg::new("a", &b"value"[..]));
+    nest.set(Egg::new("b", &b"value"[..]));
+    for _ in 0..10 {
+      match command.execute(&mut nest) {
+        Data::BulkString(key) => assert!(key == b"a" || key == b"b"),
+        output => panic!("Unexpected output {:?}", output),
+      }
+    }
+  }
+}
@@ -1,60 +1,61 @@
+use crate::core::commands::args::{check_min_args_len, parse_string};
 use crate::core::commands::Command;
 use crate::core::nest::Nest;
 use crate::errors::Result;
 use sparrow_resp::Data;
 use std::fmt;
 
-/// Engine REM command.
+/// Engine REM command, also known as DEL.
 #[derive(Clone, Debug)]
 pub struct RemCommand {
-  key: String,
+  keys: Vec<String>,
 }
 
 impl RemCommand {
   /// Return a new [RemCommand].
   ///
   /// # Arguments
-  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  /// * `args` - Arguments of this command. There should be at least 1 argument (key...).
   ///
   /// # Examples
   /// ```rust
   /// use crate::core::commands::RemCommand;
   ///
-  /// let args = &vec!["key"];
+  /// let args = &[&b"key"[..], &b"other"[..]];
   /// let cmd = RemCommand::new(args).unwrap();
   ///
-  /// assert_eq!(format!("{}", cmd), "REM key");
+  /// assert_eq!(format!("{}", cmd), "REM key other");
   /// ```
-  pub fn new(args: &[&str]) -> Result<RemCommand> {
-    match args.len() {
-      1 => {
-        let key = args.get(0).unwrap();
-        Ok(RemCommand {
-          key: key.to_string(),
-        })
-      }
-      n => Err(
-        format!(
-          "Cannot parse REM command arguments: Wrong number of arguments. Expected 1, got {}.",
-          n
-        )
-        .into(),
-      ),
-    }
+  pub fn new(args: &[&[u8]]) -> Result<RemCommand> {
+    check_min_args_len("REM", args, 1)?;
+    let keys = args
+      .iter()
+      .map(|key| parse_string(key))
+      .collect::<Result<_>>()?;
+    Ok(RemCommand { keys })
   }
 }
 
 impl fmt::Display for RemCommand {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "REM {}", self.key)
+    write!(f, "REM {}", self.keys.join(" "))
   }
 }
 
 impl Command for RemCommand {
-  /// Execute the `POP key` command on a given [Nest].
+  /// Execute the `REM key [key ...]` command on a given [Nest].
+  ///
+  /// Return the number of keys that were removed.
   fn execute(&self, nest: &mut Nest) -> Data {
-    nest.rem(&self.key);
-    Data::SimpleString("OK".to_string())
+    let removed = self.keys.iter().filter(|key| nest.rem(key)).count();
+    Data::Integer(removed as i64)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
   }
 }
 
@@ -77,42 +78,70 @@ mod tests {
 
   #[test]
   fn test_command_new_1_args() {
-    let args = &vec![TEST_KEY];
+    let args = &[TEST_KEY.as_bytes()];
     let command = RemCommand::new(args).unwrap();
-    assert_eq!(command.key, TEST_KEY)
+    assert_eq!(command.keys, vec![TEST_KEY])
   }
 
   #[test]
   #[should_panic(
-    expected = "Cannot parse REM command arguments: Wrong number of arguments. Expected 1, got 0."
+    expected = "Cannot parse REM command arguments: Wrong number of arguments. Expected at least 1, got 0."
   )]
   fn test_command_new_0_args() {
-    let args = &vec![];
+    let args = &[];
     RemCommand::new(args).unwrap();
   }
 
   #[test]
-  #[should_panic(
-    expected = "Cannot parse REM command arguments: Wrong number of arguments. Expected 1, got 2."
-  )]
   fn test_command_new_2_args() {
-    let args = &vec![TEST_KEY, TEST_VALUE];
-    RemCommand::new(args).unwrap();
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes()];
+    let command = RemCommand::new(args).unwrap();
+    assert_eq!(command.keys, vec![TEST_KEY, TEST_VALUE])
   }
 
   #[rstest]
   fn test_command_execute(mut nest: Nest) {
-    let args = &vec![TEST_KEY];
+    let args = &[TEST_KEY.as_bytes()];
     let command = Box::new(RemCommand::new(args).unwrap());
 
     let data = command.execute(&mut nest);
-    assert_eq!(data, Data::SimpleString("OK".to_string()));
+    assert_eq!(data, Data::Integer(0));
 
-    nest.set(Egg::new(TEST_KEY, TEST_VALUE));
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
     let data = command.execute(&mut nest);
-    assert_eq!(data, Data::SimpleString("OK".to_string()));
+    assert_eq!(data, Data::Integer(1));
 
     let egg = nest.get(TEST_KEY);
     assert!(egg.is_none());
   }
+
+  #[rstest]
+  fn test_command_execute_many_keys(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    nest.set(Egg::new("other", TEST_VALUE.as_bytes()));
+    let args = &[
+      TEST_KEY.as_bytes(),
+      b"missing",
+      b"other",
+      TEST_KEY.as_bytes(),
+    ];
+    let command = RemCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    assert_eq!(nest.len(), 0);
+  }
+
+  #[test]
+  fn test_command_to_aof() {
+    let args = &[TEST_KEY.as_bytes()];
+    let command = RemCommand::new(args).unwrap();
+    let input = Data::BulkString("REM My key".into());
+    assert_eq!(
+      command.to_aof(&input, &Data::Integer(0), &Nest::new()),
+      None
+    );
+    assert_eq!(
+      command.to_aof(&input, &Data::Integer(1), &Nest::new()),
+      Some(input)
+    );
+  }
 }
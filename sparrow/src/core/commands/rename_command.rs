@@ -0,0 +1,172 @@
+//! Engine RENAME and RENAMENX commands.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine RENAME and RENAMENX commands.
+#[derive(Clone, Debug)]
+pub struct RenameCommand {
+  source: String,
+  destination: String,
+  /// Whether the key is only renamed if the destination does not exist (`RENAMENX`).
+  nx: bool,
+}
+
+impl RenameCommand {
+  /// Return a new [RenameCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (source, destination).
+  /// * `nx` - Whether the key is only renamed if the destination does not exist (`RENAMENX`)
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::RenameCommand;
+  ///
+  /// let args = &[&b"source"[..], &b"destination"[..]];
+  /// let cmd = RenameCommand::new(args, false).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "RENAME source destination");
+  /// ```
+  pub fn new(args: &[&[u8]], nx: bool) -> Result<RenameCommand> {
+    check_args_len(command_name(nx), args, 2)?;
+    Ok(RenameCommand {
+      source: parse_string(args.first().unwrap())?,
+      destination: parse_string(args.get(1).unwrap())?,
+      nx,
+    })
+  }
+}
+
+/// Return the name of the command, `RENAMENX` if the destination must not exist.
+fn command_name(nx: bool) -> &'static str {
+  match nx {
+    true => "RENAMENX",
+    false => "RENAME",
+  }
+}
+
+impl fmt::Display for RenameCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} {} {}",
+      command_name(self.nx),
+      self.source,
+      self.destination
+    )
+  }
+}
+
+impl Command for RenameCommand {
+  /// Execute the `RENAME source destination` or `RENAMENX source destination` command
+  /// on a given [Nest].
+  ///
+  /// The [Egg] keeps its value, creation date and expiration date under its new key,
+  /// and replaces the destination key. `RENAME` returns `OK`, while `RENAMENX` returns `1`
+  /// if the key was renamed and `0` if the destination already exists.
+  /// An error is returned if the source key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    if nest.get(&self.source).is_none() {
+      return Data::Error("No such key".to_string());
+    }
+    if self.nx && nest.get(&self.destination).is_some() {
+      return Data::Integer(0);
+    }
+    if self.source != self.destination {
+      if let Some(egg) = nest.take(&self.source) {
+        nest.set(egg.with_key(&self.destination));
+      }
+    }
+    match self.nx {
+      true => Data::Integer(1),
+      false => Data::SimpleString("OK".to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
+  }
+
+  fn pushed_keys(&self) -> Vec<&str> {
+    vec![&self.destination]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::rename_command::RenameCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut egg = Egg::new("source", &b"value"[..]);
+    egg.set_expires_at(Some(Utc::now() + Duration::seconds(60)));
+    let mut nest = Nest::new();
+    nest.set(egg);
+    nest.set(Egg::new("other", &b"other"[..]));
+    nest
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse RENAMENX command arguments: Wrong number of arguments. Expected 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    RenameCommand::new(&[b"source"], true).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let source = nest.get("source").unwrap().clone();
+    let command = RenameCommand::new(&[b"source", b"other"], false).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::SimpleString("OK".to_string())
+    );
+    assert!(nest.get("source").is_none());
+    let egg = nest.get("other").unwrap();
+    assert_eq!(egg.value(), source.value());
+    assert_eq!(egg.created_at(), source.created_at());
+    assert_eq!(egg.expires_at(), source.expires_at());
+
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("No such key".to_string())
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_nx(mut nest: Nest) {
+    let command = RenameCommand::new(&[b"source", b"other"], true).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert!(nest.get("source").is_some());
+
+    let command = RenameCommand::new(&[b"source", b"destination"], true).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert!(nest.get("source").is_none());
+    assert!(nest.get("destination").is_some());
+  }
+
+  #[rstest]
+  fn test_command_execute_same_key(mut nest: Nest) {
+    let command = RenameCommand::new(&[b"source", b"source"], false).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::SimpleString("OK".to_string())
+    );
+    assert!(nest.get("source").is_some());
+  }
+}
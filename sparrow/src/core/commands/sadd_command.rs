@@ -0,0 +1,124 @@
+//! Engine SADD command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Engine SADD command.
+#[derive(Clone, Debug)]
+pub struct SAddCommand {
+  key: String,
+  members: Vec<Vec<u8>>,
+}
+
+impl SAddCommand {
+  /// Return a new [SAddCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, member...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SAddCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = SAddCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SADD key a b");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SAddCommand> {
+    check_min_args_len("SADD", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let members = args[1..].iter().map(|member| member.to_vec()).collect();
+    Ok(SAddCommand { key, members })
+  }
+}
+
+impl fmt::Display for SAddCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SADD {}", self.key)?;
+    for member in &self.members {
+      write!(f, " {}", String::from_utf8_lossy(member))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for SAddCommand {
+  /// Execute the `SADD key member [member ...]` command on a given [Nest].
+  ///
+  /// Return the number of members that were added, not counting members already in the set.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::Set(HashSet::new()));
+    match egg.value_mut().as_set_mut() {
+      Some(set) => {
+        let added = self
+          .members
+          .iter()
+          .filter(|member| set.insert(member.to_vec()))
+          .count();
+        Data::Integer(added as i64)
+      }
+      None => Data::Error(WRONG_TYPE_ERROR.to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::sadd_command::SAddCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse SADD command arguments: Wrong number of arguments. Expected at least 2, got 1."
+  )]
+  fn test_command_new_1_args() {
+    SAddCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SAddCommand::new(&[TEST_KEY.as_bytes(), b"a", b"b", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    let command = SAddCommand::new(&[TEST_KEY.as_bytes(), b"b", b"c"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+
+    let set = nest.get(TEST_KEY).unwrap().value().as_set().unwrap();
+    assert_eq!(set.len(), 3);
+  }
+
+  #[rstest]
+  fn test_command_execute_wrong_type(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    let command = SAddCommand::new(&[TEST_KEY.as_bytes(), b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -0,0 +1,203 @@
+//! Engine SCAN command.
+
+use crate::core::commands::args::{check_min_args_len, parse_integer, parse_string};
+use crate::core::commands::pattern::glob_match;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Number of keys visited by a [ScanCommand] without a `COUNT` option.
+const DEFAULT_COUNT: usize = 10;
+
+/// Engine SCAN command.
+#[derive(Clone, Debug)]
+pub struct ScanCommand {
+  cursor: u64,
+  pattern: Option<String>,
+  count: Option<usize>,
+  type_name: Option<String>,
+}
+
+impl ScanCommand {
+  /// Return a new [ScanCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 1 argument
+  ///   (cursor, optional `MATCH` pattern, optional `COUNT` count, optional `TYPE` type).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ScanCommand;
+  ///
+  /// let args = &[&b"0"[..], &b"match"[..], &b"user:*"[..], &b"count"[..], &b"100"[..]];
+  /// let cmd = ScanCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SCAN 0 MATCH user:* COUNT 100");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ScanCommand> {
+    check_min_args_len("SCAN", args, 1)?;
+    let cursor = parse_string(args.first().unwrap())?
+      .parse()
+      .map_err(|_| "Invalid cursor")?;
+    let mut command = ScanCommand {
+      cursor,
+      pattern: None,
+      count: None,
+      type_name: None,
+    };
+    for option in args[1..].chunks(2) {
+      let name = parse_string(option[0])?.to_uppercase();
+      match (name.as_str(), option.get(1)) {
+        ("MATCH", Some(pattern)) => command.pattern = Some(parse_string(pattern)?),
+        ("COUNT", Some(count)) if parse_integer(count)? >= 1 => {
+          command.count = Some(parse_integer(count)? as usize)
+        }
+        ("TYPE", Some(type_name)) => {
+          command.type_name = Some(parse_string(type_name)?.to_lowercase())
+        }
+        _ => {
+          return Err(
+            format!(
+              "Cannot parse SCAN command arguments: Syntax error near {}.",
+              name
+            )
+            .into(),
+          )
+        }
+      }
+    }
+    Ok(command)
+  }
+}
+
+impl fmt::Display for ScanCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SCAN {}", self.cursor)?;
+    if let Some(pattern) = &self.pattern {
+      write!(f, " MATCH {}", pattern)?;
+    }
+    if let Some(count) = self.count {
+      write!(f, " COUNT {}", count)?;
+    }
+    if let Some(type_name) = &self.type_name {
+      write!(f, " TYPE {}", type_name)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for ScanCommand {
+  /// Execute the `SCAN cursor [MATCH pattern] [COUNT count] [TYPE type]` command on a given [Nest].
+  ///
+  /// Return the cursor of the next call, `0` once the scan is complete, along with the keys
+  /// visited by this call. See [Nest::scan].
+  /// `MATCH` and `TYPE` filter the visited keys, so that fewer keys than `COUNT` may be returned.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let (cursor, eggs) = nest.scan(self.cursor, self.count.unwrap_or(DEFAULT_COUNT));
+    let keys = eggs
+      .into_iter()
+      .filter(|egg| match &self.pattern {
+        Some(pattern) => glob_match(pattern.as_bytes(), egg.key().as_bytes()),
+        None => true,
+      })
+      .filter(|egg| match &self.type_name {
+        Some(type_name) => egg.value().type_name() == type_name,
+        None => true,
+      })
+      .map(|egg| Data::BulkString(egg.key().as_bytes().to_vec()))
+      .collect();
+    Data::Array(vec![
+      Data::BulkString(cursor.to_string().into_bytes()),
+      Data::Array(keys),
+    ])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::scan_command::ScanCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    for index in 0..20 {
+      nest.set(Egg::new(&format!("string:{}", index), &b"value"[..]));
+    }
+    for index in 0..20 {
+      let set: HashSet<Vec<u8>> = vec![b"member".to_vec()].into_iter().collect();
+      nest.set(Egg::new(&format!("set:{}", index), set));
+    }
+    nest
+  }
+
+  /// Scan a whole [Nest] and return the sorted keys.
+  fn scan(nest: &mut Nest, options: &[&[u8]]) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut cursor = b"0".to_vec();
+    loop {
+      let mut args = vec![&cursor[..]];
+      args.extend_from_slice(options);
+      let command = ScanCommand::new(&args).unwrap();
+      match command.execute(nest) {
+        Data::Array(mut output) => match (output.remove(0), output.remove(0)) {
+          (Data::BulkString(next), Data::Array(batch)) => {
+            keys.extend(batch.into_iter().map(|key| match key {
+              Data::BulkString(key) => String::from_utf8(key).unwrap(),
+              key => panic!("Unexpected key {:?}", key),
+            }));
+            cursor = next;
+          }
+          output => panic!("Unexpected output {:?}", output),
+        },
+        output => panic!("Unexpected output {:?}", output),
+      }
+      if cursor == b"0" {
+        break;
+      }
+    }
+    keys.sort();
+    keys
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid cursor")]
+  fn test_command_new_invalid_cursor() {
+    ScanCommand::new(&[b"-1"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse SCAN command arguments: Syntax error near COUNT.")]
+  fn test_command_new_zero_count() {
+    ScanCommand::new(&[b"0", b"COUNT", b"0"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse SCAN command arguments: Syntax error near MATCH.")]
+  fn test_command_new_missing_pattern() {
+    ScanCommand::new(&[b"0", b"MATCH"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    assert_eq!(scan(&mut nest, &[]).len(), 40);
+    assert_eq!(scan(&mut nest, &[b"COUNT", b"3"]).len(), 40);
+
+    let keys = scan(&mut nest, &[b"MATCH", b"string:1?"]);
+    assert_eq!(keys.len(), 10);
+    assert!(keys.iter().all(|key| key.starts_with("string:1")));
+
+    let keys = scan(&mut nest, &[b"TYPE", b"SET", b"COUNT", b"7"]);
+    assert_eq!(keys.len(), 20);
+    assert!(keys.iter().all(|key| key.starts_with("set:")));
+
+    assert!(scan(&mut nest, &[b"MATCH", b"set:*", b"TYPE", b"string"]).is_empty());
+  }
+}
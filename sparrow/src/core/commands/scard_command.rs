@@ -0,0 +1,91 @@
+//! Engine SCARD command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SCARD command.
+#[derive(Clone, Debug)]
+pub struct SCardCommand {
+  key: String,
+}
+
+impl SCardCommand {
+  /// Return a new [SCardCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SCardCommand;
+  ///
+  /// let cmd = SCardCommand::new(&[&b"key"[..]]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SCARD key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SCardCommand> {
+    check_args_len("SCARD", args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(SCardCommand { key })
+  }
+}
+
+impl fmt::Display for SCardCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SCARD {}", self.key)
+  }
+}
+
+impl Command for SCardCommand {
+  /// Execute the `SCARD key` command on a given [Nest].
+  ///
+  /// Return the number of members of the set, `0` if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Set(set)) => Data::Integer(set.len() as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::scard_command::SCardCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into_iter().collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, set));
+    nest.set(Egg::new("string", &b"value"[..]));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SCardCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    let command = SCardCommand::new(&[b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = SCardCommand::new(&[b"string"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -0,0 +1,63 @@
+//! Utilities shared by set commands.
+
+use std::collections::HashSet;
+
+/// Pick distinct random members of a set.
+///
+/// Every member is returned, in a random order, if the count exceeds the set size.
+///
+/// # Arguments
+/// * `set` - Set to pick members from
+/// * `count` - Number of members to pick
+pub fn random_members(set: &HashSet<Vec<u8>>, count: usize) -> Vec<Vec<u8>> {
+  let mut members: Vec<&Vec<u8>> = set.iter().collect();
+  let count = count.min(members.len());
+  // Partial Fisher-Yates shuffle: only the first `count` positions are drawn
+  for index in 0..count {
+    let other = fastrand::usize(index..members.len());
+    members.swap(index, other);
+  }
+  members.into_iter().take(count).cloned().collect()
+}
+
+/// Pick random members of a set, possibly returning the same member several times.
+///
+/// # Arguments
+/// * `set` - Non-empty set to pick members from
+/// * `count` - Number of members to pick
+pub fn random_members_with_repetitions(set: &HashSet<Vec<u8>>, count: usize) -> Vec<Vec<u8>> {
+  let members: Vec<&Vec<u8>> = set.iter().collect();
+  (0..count)
+    .map(|_| members[fastrand::usize(..members.len())].clone())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::set::{random_members, random_members_with_repetitions};
+  use std::collections::HashSet;
+
+  #[test]
+  fn test_random_members() {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]
+      .into_iter()
+      .collect();
+
+    let members = random_members(&set, 2);
+    assert_eq!(members.len(), 2);
+    assert_ne!(members[0], members[1]);
+    assert!(members.iter().all(|member| set.contains(member)));
+
+    let members: HashSet<Vec<u8>> = random_members(&set, 5).into_iter().collect();
+    assert_eq!(members, set);
+  }
+
+  #[test]
+  fn test_random_members_with_repetitions() {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec()].into_iter().collect();
+    assert_eq!(
+      random_members_with_repetitions(&set, 3),
+      vec![b"a".to_vec(), b"a".to_vec(), b"a".to_vec()]
+    );
+  }
+}
@@ -1,74 +1,235 @@
-use crate::core::commands::Command;
+//! Engine SET command.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::command::command_data;
+use crate::core::commands::{Command, Expiration};
 use crate::core::egg::Egg;
 use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
 use crate::errors::Result;
+use chrono::Utc;
 use sparrow_resp::Data;
 use std::fmt;
 
-/// Engine INSERT command.
+/// Condition for a [SetCommand] to set the value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SetCondition {
+  /// Only set the key if it does not exist.
+  Nx,
+  /// Only set the key if it already exists.
+  Xx,
+}
+
+/// Engine SET command.
 #[derive(Clone, Debug)]
 pub struct SetCommand {
   key: String,
-  value: String,
+  value: Vec<u8>,
+  expiration: Option<Expiration>,
+  condition: Option<SetCondition>,
+  /// Whether the previous value is returned.
+  get: bool,
+  /// Whether the previous expiration is kept.
+  keep_ttl: bool,
 }
 
 impl SetCommand {
   /// Return a new [SetCommand].
   ///
   /// # Arguments
-  /// * `args` - Arguments of this command. There should be 2 argument (key, value).
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, value),
+  ///   followed by options: `NX` or `XX`, `GET`, and `EX seconds`, `PX milliseconds`,
+  ///   `EXAT timestamp`, `PXAT timestamp` or `KEEPTTL`.
   ///
   /// # Examples
   /// ```rust
   /// use crate::core::commands::SetCommand;
   ///
-  /// let args = &vec!["my key", "some value"];
+  /// let args = &[&b"my key"[..], &b"some value"[..], &b"EX"[..], &b"10"[..]];
   /// let cmd = SetCommand::new(args).unwrap();
   ///
-  /// assert_eq!(format!("{}", cmd), "SET {my key} {some value}");
+  /// assert_eq!(format!("{}", cmd), "SET my key some value EX 10");
   /// ```
-  pub fn new(args: &[&str]) -> Result<SetCommand> {
-    match args.len() {
-      2 => {
-        let key = args.get(0).unwrap();
-        let value = args.get(1).unwrap();
-        Ok(SetCommand {
-          key: key.to_string(),
-          value: value.to_string(),
-        })
-      }
-      n => Err(
+  pub fn new(args: &[&[u8]]) -> Result<SetCommand> {
+    if args.len() < 2 {
+      return Err(
         format!(
-          "Cannot parse SET command arguments: Wrong number of arguments. Expected 2, got {}.",
-          n
+          "Cannot parse SET command arguments: Wrong number of arguments. Expected at least 2, got {}.",
+          args.len()
         )
         .into(),
-      ),
+      );
+    }
+
+    let mut command = SetCommand {
+      key: parse_string(args.first().unwrap())?,
+      value: args.get(1).unwrap().to_vec(),
+      expiration: None,
+      condition: None,
+      get: false,
+      keep_ttl: false,
+    };
+    let mut options = args[2..].iter();
+    while let Some(option) = options.next() {
+      let option = parse_string(option)?.to_uppercase();
+      let has_expiration = command.expiration.is_some() || command.keep_ttl;
+      let to_expiration = match option.as_str() {
+        "NX" if command.condition.is_none() => {
+          command.condition = Some(SetCondition::Nx);
+          continue;
+        }
+        "XX" if command.condition.is_none() => {
+          command.condition = Some(SetCondition::Xx);
+          continue;
+        }
+        "GET" if !command.get => {
+          command.get = true;
+          continue;
+        }
+        "KEEPTTL" if !has_expiration => {
+          command.keep_ttl = true;
+          continue;
+        }
+        option => match Expiration::from_option(option) {
+          Some(to_expiration) if !has_expiration => to_expiration,
+          _ => {
+            return Err(
+              format!(
+                "Cannot parse SET command arguments: Syntax error near {}.",
+                option
+              )
+              .into(),
+            )
+          }
+        },
+      };
+      let time = options
+        .next()
+        .ok_or("Cannot parse SET command arguments: Syntax error.")?;
+      let time = parse_integer(time)?;
+      if time <= 0 {
+        return Err("Invalid expire time in SET command".into());
+      }
+      command.expiration = Some(to_expiration(time));
+    }
+    Ok(command)
+  }
+
+  /// Return whether the command has set the value, given its output.
+  fn is_written(&self, output: &Data) -> bool {
+    match output {
+      Data::Error(_) => false,
+      Data::SimpleString(_) => true,
+      // Without GET, a null reply means that the condition was not met
+      Data::Null if !self.get => false,
+      Data::Null => self.condition != Some(SetCondition::Xx),
+      _ => self.condition != Some(SetCondition::Nx),
     }
   }
 }
 
 impl fmt::Display for SetCommand {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    write!(f, "SET {} {}", self.key, self.value)
+    write!(
+      f,
+      "SET {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.value)
+    )?;
+    match self.condition {
+      Some(SetCondition::Nx) => write!(f, " NX")?,
+      Some(SetCondition::Xx) => write!(f, " XX")?,
+      None => {}
+    }
+    if self.get {
+      write!(f, " GET")?;
+    }
+    if let Some(expiration) = self.expiration {
+      write!(f, " {}", expiration)?;
+    }
+    if self.keep_ttl {
+      write!(f, " KEEPTTL")?;
+    }
+    Ok(())
   }
 }
 
 impl Command for SetCommand {
-  /// Execute the `INSERT key value` command on a given [Nest].
+  /// Execute the `SET key value [NX|XX] [GET] [EX seconds|PX milliseconds|EXAT timestamp|PXAT timestamp|KEEPTTL]`
+  /// command on a given [Nest].
+  ///
+  /// Return `OK`, or [Data::Null] if the value was not set because of `NX` or `XX`.
+  /// With `GET`, return the previous value or [Data::Null] instead, whether the value was set or not.
   fn execute(&self, nest: &mut Nest) -> Data {
-    nest.set(Egg::new(&self.key, &self.value));
-    Data::SimpleString("OK".to_string())
+    let current = nest.get(&self.key);
+    let exists = current.is_some();
+    let expires_at = current.and_then(|egg| egg.expires_at().cloned());
+    let previous = match current.map(|egg| egg.value()) {
+      Some(Value::String(value)) if self.get => Data::BulkString(value.clone()),
+      Some(_) if self.get => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      _ => Data::Null,
+    };
+    let output = match self.get {
+      true => previous,
+      false => Data::SimpleString("OK".to_string()),
+    };
+    match self.condition {
+      Some(SetCondition::Nx) if exists => return if self.get { output } else { Data::Null },
+      Some(SetCondition::Xx) if !exists => return Data::Null,
+      _ => {}
+    }
+
+    let mut egg = Egg::new(&self.key, self.value.as_slice());
+    if self.keep_ttl {
+      egg.set_expires_at(expires_at);
+    }
+    if let Some(expiration) = self.expiration {
+      match expiration.deadline(Utc::now()) {
+        Some(expires_at) => egg.set_expires_at(Some(expires_at)),
+        None => return Data::Error("Invalid expire time in SET command".to_string()),
+      }
+    }
+    nest.set(egg);
+    output
+  }
+
+  /// Conditional commands are rewritten as plain `SET` commands, and expiration times
+  /// as `PXAT timestamp` so that replaying the command does not extend the time to live.
+  fn to_aof(&self, input: &Data, output: &Data, nest: &Nest) -> Option<Data> {
+    if !self.is_written(output) {
+      return None;
+    }
+    if self.expiration.is_none() && self.condition.is_none() && !self.get {
+      return Some(input.clone());
+    }
+    let mut inputs = vec![
+      b"SET".to_vec(),
+      self.key.clone().into_bytes(),
+      self.value.clone(),
+    ];
+    if self.expiration.is_some() {
+      let expires_at = nest.peek(&self.key)?.expires_at()?;
+      inputs.push(b"PXAT".to_vec());
+      inputs.push(expires_at.timestamp_millis().to_string().into_bytes());
+    }
+    if self.keep_ttl {
+      inputs.push(b"KEEPTTL".to_vec());
+    }
+    Some(command_data(inputs))
   }
 }
 
 #[cfg(test)]
 mod tests {
   use crate::core::commands::set_command::SetCommand;
-  use crate::core::commands::Command;
+  use crate::core::commands::{Command, Expiration};
+  use crate::core::egg::Egg;
   use crate::core::nest::Nest;
+  use crate::core::value::{Value, WRONG_TYPE_ERROR};
+  use chrono::{Duration, Utc};
   use rstest::*;
   use sparrow_resp::Data;
+  use std::collections::HashSet;
 
   const TEST_KEY: &str = "My key";
   const TEST_VALUE: &str = "This is a test value!";
@@ -80,33 +241,84 @@ mod tests {
 
   #[test]
   fn test_command_new_2_args() {
-    let args = &vec![TEST_KEY, TEST_VALUE];
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes()];
     let command = SetCommand::new(args).unwrap();
     assert_eq!(command.key, TEST_KEY);
-    assert_eq!(command.value, TEST_VALUE);
+    assert_eq!(command.value, TEST_VALUE.as_bytes());
   }
 
   #[test]
   #[should_panic(
-    expected = "Cannot parse SET command arguments: Wrong number of arguments. Expected 2, got 0."
+    expected = "Cannot parse SET command arguments: Wrong number of arguments. Expected at least 2, got 0."
   )]
   fn test_command_new_0_args() {
-    let args = &vec![];
+    let args = &[];
+    SetCommand::new(args).unwrap();
+  }
+
+  #[test]
+  fn test_command_new_expiration() {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"ex", b"10"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.expiration, Some(Expiration::Seconds(10)));
+
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"PX", b"10"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.expiration, Some(Expiration::Milliseconds(10)));
+
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"EXAT", b"10"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.expiration, Some(Expiration::UnixSeconds(10)));
+
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"PXAT", b"10"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.expiration, Some(Expiration::UnixMilliseconds(10)));
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse SET command arguments: Syntax error near PX.")]
+  fn test_command_new_expiration_twice() {
+    let args = &[
+      TEST_KEY.as_bytes(),
+      TEST_VALUE.as_bytes(),
+      b"EX",
+      b"10",
+      b"PX",
+      b"10",
+    ];
+    SetCommand::new(args).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse SET command arguments: Syntax error.")]
+  fn test_command_new_expiration_missing_time() {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"EX"];
+    SetCommand::new(args).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Invalid expire time in SET command")]
+  fn test_command_new_expiration_negative() {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"EX", b"-1"];
     SetCommand::new(args).unwrap();
   }
 
   #[test]
   #[should_panic(
-    expected = "Cannot parse SET command arguments: Wrong number of arguments. Expected 2, got 3."
+    expected = "Cannot parse SET command arguments: Syntax error near THIS IS A TEST VALUE!."
   )]
   fn test_command_new_3_args() {
-    let args = &vec![TEST_KEY, TEST_VALUE, TEST_VALUE];
+    let args = &[
+      TEST_KEY.as_bytes(),
+      TEST_VALUE.as_bytes(),
+      TEST_VALUE.as_bytes(),
+    ];
     SetCommand::new(args).unwrap();
   }
 
   #[rstest]
   fn test_command_execute(mut nest: Nest) {
-    let args = &vec![TEST_KEY, TEST_VALUE];
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes()];
     let set_command = Box::new(SetCommand::new(args).unwrap());
 
     let data = set_command.execute(&mut nest);
@@ -114,6 +326,155 @@ mod tests {
 
     let egg = nest.get(TEST_KEY).unwrap();
     assert_eq!(egg.key(), TEST_KEY);
-    assert_eq!(egg.value(), TEST_VALUE);
+    assert_eq!(egg.value(), &Value::String(TEST_VALUE.into()));
+    assert_eq!(egg.expires_at(), None);
+  }
+
+  #[rstest]
+  fn test_command_execute_expiration(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"EX", b"10"];
+    let set_command = Box::new(SetCommand::new(args).unwrap());
+
+    let data = set_command.execute(&mut nest);
+    assert_eq!(data, Data::SimpleString("OK".to_string()));
+
+    let expires_at = *nest.get(TEST_KEY).unwrap().expires_at().unwrap();
+    assert!(expires_at > Utc::now() + Duration::seconds(9));
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse SET command arguments: Syntax error near EX.")]
+  fn test_command_new_keep_ttl_and_expiration() {
+    let args = &[
+      TEST_KEY.as_bytes(),
+      TEST_VALUE.as_bytes(),
+      b"KEEPTTL",
+      b"EX",
+      b"10",
+    ];
+    SetCommand::new(args).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse SET command arguments: Syntax error near XX.")]
+  fn test_command_new_nx_and_xx() {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"NX", b"xx"];
+    SetCommand::new(args).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute_conditions(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), b"first", b"XX"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+    assert!(nest.get(TEST_KEY).is_none());
+
+    let args = &[TEST_KEY.as_bytes(), b"first", b"NX"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::SimpleString("OK".to_string())
+    );
+    let args = &[TEST_KEY.as_bytes(), b"second", b"nx"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+
+    let args = &[TEST_KEY.as_bytes(), b"second", b"XX", b"GET"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("first".into()));
+    assert_eq!(
+      nest.get(TEST_KEY).unwrap().value(),
+      &Value::String("second".into())
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_get(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"GET"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::BulkString(TEST_VALUE.into())
+    );
+
+    nest.set(Egg::new(TEST_KEY, HashSet::new()));
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_keep_ttl(mut nest: Nest) {
+    let expires_at = Utc::now() + Duration::seconds(10);
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    nest.expire(TEST_KEY, Some(expires_at));
+
+    let args = &[TEST_KEY.as_bytes(), b"other", b"KEEPTTL"];
+    let command = SetCommand::new(args).unwrap();
+    command.execute(&mut nest);
+    assert_eq!(nest.get(TEST_KEY).unwrap().expires_at(), Some(&expires_at));
+
+    let args = &[TEST_KEY.as_bytes(), b"other"];
+    let command = SetCommand::new(args).unwrap();
+    command.execute(&mut nest);
+    assert_eq!(nest.get(TEST_KEY).unwrap().expires_at(), None);
+  }
+
+  #[rstest]
+  fn test_command_to_aof(mut nest: Nest) {
+    let output = Data::SimpleString("OK".to_string());
+
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes()];
+    let command = SetCommand::new(args).unwrap();
+    let input = Data::BulkString("SET key value".into());
+    assert_eq!(command.to_aof(&input, &output, &nest), Some(input));
+
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"PXAT", b"10"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(command.to_aof(&Data::Null, &output, &nest), None);
+    command.execute(&mut nest);
+    assert_eq!(
+      command.to_aof(&Data::Null, &output, &nest),
+      Some(Data::Array(vec![
+        Data::BulkString("SET".into()),
+        Data::BulkString(TEST_KEY.into()),
+        Data::BulkString(TEST_VALUE.into()),
+        Data::BulkString("PXAT".into()),
+        Data::BulkString("10".into()),
+      ]))
+    );
+
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"NX", b"GET"];
+    let command = SetCommand::new(args).unwrap();
+    assert_eq!(
+      command.to_aof(&Data::Null, &Data::Null, &nest),
+      Some(Data::Array(vec![
+        Data::BulkString("SET".into()),
+        Data::BulkString(TEST_KEY.into()),
+        Data::BulkString(TEST_VALUE.into()),
+      ]))
+    );
+    assert_eq!(
+      command.to_aof(&Data::Null, &Data::BulkString("previous".into()), &nest),
+      None
+    );
+
+    // The logged expiration is the applied one
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes(), b"EX", b"10"];
+    let command = SetCommand::new(args).unwrap();
+    command.execute(&mut nest);
+    let expires_at = *nest.get(TEST_KEY).unwrap().expires_at().unwrap();
+    assert_eq!(
+      command.to_aof(&Data::Null, &output, &nest),
+      Some(Data::Array(vec![
+        Data::BulkString("SET".into()),
+        Data::BulkString(TEST_KEY.into()),
+        Data::BulkString(TEST_VALUE.into()),
+        Data::BulkString("PXAT".into()),
+        Data::BulkString(expires_at.timestamp_millis().to_string().into()),
+      ]))
+    );
   }
 }
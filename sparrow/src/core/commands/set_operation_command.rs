@@ -0,0 +1,240 @@
+//! Engine SINTER, SUNION and SDIFF commands, along with their STORE variants.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Operation combining the sets of a [SetOperationCommand].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SetOperation {
+  /// Members of every set.
+  Inter,
+  /// Members of any set.
+  Union,
+  /// Members of the first set that are not in the other sets.
+  Diff,
+}
+
+/// Engine SINTER, SUNION, SDIFF, SINTERSTORE, SUNIONSTORE and SDIFFSTORE commands.
+#[derive(Clone, Debug)]
+pub struct SetOperationCommand {
+  destination: Option<String>,
+  keys: Vec<String>,
+  operation: SetOperation,
+}
+
+impl SetOperationCommand {
+  /// Return a new [SetOperationCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 1 argument (key...),
+  ///   or at least 2 arguments (destination, key...) for STORE variants.
+  /// * `operation` - [SetOperation] combining the sets
+  /// * `store` - Whether the result is stored at the destination key rather than returned
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{SetOperation, SetOperationCommand};
+  ///
+  /// let args = &[&b"destination"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = SetOperationCommand::new(args, SetOperation::Union, true).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SUNIONSTORE destination a b");
+  /// ```
+  pub fn new(args: &[&[u8]], operation: SetOperation, store: bool) -> Result<SetOperationCommand> {
+    let name = command_name(operation, store);
+    check_min_args_len(&name, args, 1 + store as usize)?;
+    let mut keys = args
+      .iter()
+      .map(|key| parse_string(key))
+      .collect::<Result<Vec<_>>>()?;
+    let destination = store.then(|| keys.remove(0));
+    Ok(SetOperationCommand {
+      destination,
+      keys,
+      operation,
+    })
+  }
+
+  /// Combine the sets of the command keys, missing keys being empty sets.
+  fn combine(&self, nest: &mut Nest) -> std::result::Result<HashSet<Vec<u8>>, Data> {
+    let mut result: Option<HashSet<Vec<u8>>> = None;
+    for key in &self.keys {
+      let set = match nest.get(key).map(|egg| egg.value()) {
+        Some(Value::Set(set)) => Some(set),
+        Some(_) => return Err(Data::Error(WRONG_TYPE_ERROR.to_string())),
+        None => None,
+      };
+      result = Some(match (result, set) {
+        (None, set) => set.cloned().unwrap_or_default(),
+        (Some(mut result), Some(set)) => {
+          match self.operation {
+            SetOperation::Inter => result.retain(|member| set.contains(member)),
+            SetOperation::Union => result.extend(set.iter().cloned()),
+            SetOperation::Diff => result.retain(|member| !set.contains(member)),
+          }
+          result
+        }
+        (Some(_), None) if self.operation == SetOperation::Inter => HashSet::new(),
+        (Some(result), None) => result,
+      });
+    }
+    Ok(result.unwrap_or_default())
+  }
+}
+
+/// Return the name of the command performing the given [SetOperation].
+fn command_name(operation: SetOperation, store: bool) -> String {
+  let name = match operation {
+    SetOperation::Inter => "SINTER",
+    SetOperation::Union => "SUNION",
+    SetOperation::Diff => "SDIFF",
+  };
+  match store {
+    true => format!("{}STORE", name),
+    false => name.to_string(),
+  }
+}
+
+impl fmt::Display for SetOperationCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      command_name(self.operation, self.destination.is_some())
+    )?;
+    if let Some(destination) = &self.destination {
+      write!(f, " {}", destination)?;
+    }
+    write!(f, " {}", self.keys.join(" "))
+  }
+}
+
+impl Command for SetOperationCommand {
+  /// Execute the `SINTER key [key ...]` command or one of its variants on a given [Nest].
+  ///
+  /// Return the members of the resulting set, or its number of members when it is stored.
+  /// The destination key is overwritten, or removed if the resulting set is empty.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let result = match self.combine(nest) {
+      Ok(result) => result,
+      Err(error) => return error,
+    };
+    match &self.destination {
+      Some(destination) => {
+        let len = result.len();
+        match len {
+          0 => {
+            nest.rem(destination);
+          }
+          _ => nest.set(Egg::new(destination, result)),
+        }
+        Data::Integer(len as i64)
+      }
+      None => Data::Array(result.into_iter().map(Data::BulkString).collect()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, _output: &Data, _nest: &Nest) -> Option<Data> {
+    self.destination.as_ref().map(|_| input.clone())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::set_operation_command::{SetOperation, SetOperationCommand};
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    for (key, members) in [
+      ("a", &["1", "2", "3"][..]),
+      ("b", &["2", "3", "4"]),
+      ("c", &["3"]),
+    ] {
+      let set: HashSet<Vec<u8>> = members
+        .iter()
+        .map(|member| member.as_bytes().to_vec())
+        .collect();
+      nest.set(Egg::new(key, set));
+    }
+    nest.set(Egg::new("string", &b"value"[..]));
+    nest
+  }
+
+  /// Execute a command and return its members sorted.
+  fn sorted_members(command: &SetOperationCommand, nest: &mut Nest) -> Vec<String> {
+    let mut members: Vec<String> = match command.execute(nest) {
+      Data::Array(members) => members
+        .into_iter()
+        .map(|member| match member {
+          Data::BulkString(member) => String::from_utf8(member).unwrap(),
+          member => panic!("Unexpected member {:?}", member),
+        })
+        .collect(),
+      output => panic!("Unexpected output {:?}", output),
+    };
+    members.sort();
+    members
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse SDIFFSTORE command arguments: Wrong number of arguments. Expected at least 2, got 1."
+  )]
+  fn test_command_new_store_1_args() {
+    SetOperationCommand::new(&[b"destination"], SetOperation::Diff, true).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SetOperationCommand::new(&[b"a", b"b"], SetOperation::Inter, false).unwrap();
+    assert_eq!(sorted_members(&command, &mut nest), vec!["2", "3"]);
+
+    let command =
+      SetOperationCommand::new(&[b"a", b"missing"], SetOperation::Inter, false).unwrap();
+    assert!(sorted_members(&command, &mut nest).is_empty());
+
+    let command =
+      SetOperationCommand::new(&[b"a", b"b", b"missing"], SetOperation::Union, false).unwrap();
+    assert_eq!(
+      sorted_members(&command, &mut nest),
+      vec!["1", "2", "3", "4"]
+    );
+
+    let command = SetOperationCommand::new(&[b"a", b"c"], SetOperation::Diff, false).unwrap();
+    assert_eq!(sorted_members(&command, &mut nest), vec!["1", "2"]);
+
+    let command = SetOperationCommand::new(&[b"a", b"string"], SetOperation::Union, false).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_store(mut nest: Nest) {
+    let command =
+      SetOperationCommand::new(&[b"string", b"a", b"b"], SetOperation::Inter, true).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    let set = nest.get("string").unwrap().value().as_set().unwrap();
+    assert_eq!(set.len(), 2);
+
+    let command = SetOperationCommand::new(&[b"a", b"c", b"a"], SetOperation::Diff, true).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert!(nest.get("a").is_none());
+  }
+}
@@ -0,0 +1,105 @@
+//! Engine SETBIT command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::bitmap::{parse_bit, parse_bit_offset, set_bit};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SETBIT command.
+#[derive(Clone, Debug)]
+pub struct SetBitCommand {
+  key: String,
+  offset: usize,
+  bit: u8,
+}
+
+impl SetBitCommand {
+  /// Return a new [SetBitCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, offset, bit).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SetBitCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"7"[..], &b"1"[..]];
+  /// let cmd = SetBitCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SETBIT key 7 1");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SetBitCommand> {
+    check_args_len("SETBIT", args, 3)?;
+    Ok(SetBitCommand {
+      key: parse_string(args.first().unwrap())?,
+      offset: parse_bit_offset(args.get(1).unwrap())?,
+      bit: parse_bit(args.get(2).unwrap())?,
+    })
+  }
+}
+
+impl fmt::Display for SetBitCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SETBIT {} {} {}", self.key, self.offset, self.bit)
+  }
+}
+
+impl Command for SetBitCommand {
+  /// Execute the `SETBIT key offset bit` command on a given [Nest].
+  ///
+  /// The value is padded with zero bytes up to the offset. Return the previous bit.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::String(Vec::new()));
+    match egg.value_mut().as_string_mut() {
+      Some(bytes) => Data::Integer(set_bit(bytes, self.offset, self.bit) as i64),
+      None => Data::Error(WRONG_TYPE_ERROR.to_string()),
+    }
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::setbit_command::SetBitCommand;
+  use crate::core::commands::Command;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  #[should_panic(expected = "The bit argument must be 1 or 0")]
+  fn test_command_new_invalid_bit() {
+    SetBitCommand::new(&[TEST_KEY.as_bytes(), b"7", b"2"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SetBitCommand::new(&[TEST_KEY.as_bytes(), b"9", b"1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(
+      nest.get(TEST_KEY).unwrap().value().as_string(),
+      Some(&[0x00, 0x40][..])
+    );
+
+    let command = SetBitCommand::new(&[TEST_KEY.as_bytes(), b"9", b"0"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+  }
+}
@@ -0,0 +1,100 @@
+//! Engine SETNX command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::egg::Egg;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SETNX command.
+#[derive(Clone, Debug)]
+pub struct SetNxCommand {
+  key: String,
+  value: Vec<u8>,
+}
+
+impl SetNxCommand {
+  /// Return a new [SetNxCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, value).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SetNxCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"value"[..]];
+  /// let cmd = SetNxCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SETNX key value");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SetNxCommand> {
+    check_args_len("SETNX", args, 2)?;
+    Ok(SetNxCommand {
+      key: parse_string(args.first().unwrap())?,
+      value: args.get(1).unwrap().to_vec(),
+    })
+  }
+}
+
+impl fmt::Display for SetNxCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "SETNX {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.value)
+    )
+  }
+}
+
+impl Command for SetNxCommand {
+  /// Execute the `SETNX key value` command on a given [Nest].
+  ///
+  /// Return `1` if the key was set, `0` if it already exists.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    if nest.get(&self.key).is_some() {
+      return Data::Integer(0);
+    }
+    nest.set(Egg::new(&self.key, self.value.as_slice()));
+    Data::Integer(1)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(1) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::setnx_command::SetNxCommand;
+  use crate::core::commands::Command;
+  use crate::core::nest::Nest;
+  use crate::core::value::Value;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SetNxCommand::new(&[TEST_KEY.as_bytes(), b"first"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    let command = SetNxCommand::new(&[TEST_KEY.as_bytes(), b"second"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert_eq!(
+      nest.get(TEST_KEY).unwrap().value(),
+      &Value::String("first".into())
+    );
+  }
+}
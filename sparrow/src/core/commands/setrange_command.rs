@@ -0,0 +1,155 @@
+//! Engine SETRANGE command.
+
+use crate::core::commands::args::{check_args_len, parse_integer, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, MAX_STRING_LENGTH, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SETRANGE command.
+#[derive(Clone, Debug)]
+pub struct SetRangeCommand {
+  key: String,
+  offset: usize,
+  value: Vec<u8>,
+}
+
+impl SetRangeCommand {
+  /// Return a new [SetRangeCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, offset, value).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SetRangeCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"6"[..], &b"value"[..]];
+  /// let cmd = SetRangeCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SETRANGE key 6 value");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SetRangeCommand> {
+    check_args_len("SETRANGE", args, 3)?;
+    let offset = parse_integer(args.get(1).unwrap())?;
+    if offset < 0 {
+      return Err("Offset is out of range".into());
+    }
+    let value = args.get(2).unwrap().to_vec();
+    if offset as usize + value.len() > MAX_STRING_LENGTH {
+      return Err("String exceeds maximum allowed size".into());
+    }
+    Ok(SetRangeCommand {
+      key: parse_string(args.first().unwrap())?,
+      offset: offset as usize,
+      value,
+    })
+  }
+}
+
+impl fmt::Display for SetRangeCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "SETRANGE {} {} {}",
+      self.key,
+      self.offset,
+      String::from_utf8_lossy(&self.value)
+    )
+  }
+}
+
+impl Command for SetRangeCommand {
+  /// Execute the `SETRANGE key offset value` command on a given [Nest].
+  ///
+  /// Overwrite the bytes of the value starting at `offset`, padding it with zero bytes
+  /// if it is shorter than `offset`. Return the new length of the value.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    // Writing nothing neither creates nor pads the value
+    if self.value.is_empty() {
+      return match nest.get(&self.key).map(|egg| egg.value()) {
+        Some(Value::String(value)) => Data::Integer(value.len() as i64),
+        Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+        None => Data::Integer(0),
+      };
+    }
+    let egg = nest.get_or_insert_with(&self.key, || Value::String(Vec::new()));
+    let bytes = match egg.value_mut().as_string_mut() {
+      Some(bytes) => bytes,
+      None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+    };
+    let end = self.offset + self.value.len();
+    if bytes.len() < end {
+      bytes.resize(end, 0);
+    }
+    bytes[self.offset..end].copy_from_slice(&self.value);
+    Data::Integer(bytes.len() as i64)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(_) if !self.value.is_empty() => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::setrange_command::SetRangeCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, &b"Hello World"[..]));
+    nest
+  }
+
+  /// Return the value of a key of the nest.
+  fn value(nest: &mut Nest, key: &str) -> Vec<u8> {
+    nest.get(key).unwrap().value().as_string().unwrap().to_vec()
+  }
+
+  #[test]
+  #[should_panic(expected = "Offset is out of range")]
+  fn test_command_new_negative_offset() {
+    SetRangeCommand::new(&[TEST_KEY.as_bytes(), b"-1", b"value"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "String exceeds maximum allowed size")]
+  fn test_command_new_too_large() {
+    SetRangeCommand::new(&[TEST_KEY.as_bytes(), b"536870912", b"value"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SetRangeCommand::new(&[TEST_KEY.as_bytes(), b"6", b"Redis"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(11));
+    assert_eq!(value(&mut nest, TEST_KEY), b"Hello Redis");
+
+    let command = SetRangeCommand::new(&[TEST_KEY.as_bytes(), b"9", b"XYZ"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(12));
+    assert_eq!(value(&mut nest, TEST_KEY), b"Hello RedXYZ");
+  }
+
+  #[rstest]
+  fn test_command_execute_padding(mut nest: Nest) {
+    let command = SetRangeCommand::new(&[b"other", b"3", b"ab"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(5));
+    assert_eq!(value(&mut nest, "other"), b"\0\0\0ab");
+
+    let command = SetRangeCommand::new(&[b"missing", b"3", b""]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert!(nest.get("missing").is_none());
+  }
+}
@@ -0,0 +1,94 @@
+//! Engine SISMEMBER command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SISMEMBER command.
+#[derive(Clone, Debug)]
+pub struct SIsMemberCommand {
+  key: String,
+  member: Vec<u8>,
+}
+
+impl SIsMemberCommand {
+  /// Return a new [SIsMemberCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, member).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SIsMemberCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"member"[..]];
+  /// let cmd = SIsMemberCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SISMEMBER key member");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SIsMemberCommand> {
+    check_args_len("SISMEMBER", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let member = args.get(1).unwrap().to_vec();
+    Ok(SIsMemberCommand { key, member })
+  }
+}
+
+impl fmt::Display for SIsMemberCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "SISMEMBER {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.member)
+    )
+  }
+}
+
+impl Command for SIsMemberCommand {
+  /// Execute the `SISMEMBER key member` command on a given [Nest].
+  ///
+  /// Return `1` if the member is in the set, `0` otherwise.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Set(set)) => Data::Integer(set.contains(&self.member) as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::sismember_command::SIsMemberCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec()].into_iter().collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, set));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SIsMemberCommand::new(&[TEST_KEY.as_bytes(), b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    let command = SIsMemberCommand::new(&[TEST_KEY.as_bytes(), b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = SIsMemberCommand::new(&[b"missing", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+  }
+}
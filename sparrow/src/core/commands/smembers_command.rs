@@ -0,0 +1,92 @@
+//! Engine SMEMBERS command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SMEMBERS command.
+#[derive(Clone, Debug)]
+pub struct SMembersCommand {
+  key: String,
+}
+
+impl SMembersCommand {
+  /// Return a new [SMembersCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SMembersCommand;
+  ///
+  /// let cmd = SMembersCommand::new(&[&b"key"[..]]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SMEMBERS key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SMembersCommand> {
+    check_args_len("SMEMBERS", args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(SMembersCommand { key })
+  }
+}
+
+impl fmt::Display for SMembersCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SMEMBERS {}", self.key)
+  }
+}
+
+impl Command for SMembersCommand {
+  /// Execute the `SMEMBERS key` command on a given [Nest].
+  ///
+  /// Return every member of the set, in no particular order.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Set(set)) => Data::Array(
+        set
+          .iter()
+          .map(|member| Data::BulkString(member.clone()))
+          .collect(),
+      ),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Array(vec![]),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::smembers_command::SMembersCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec()].into_iter().collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, set));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SMembersCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![Data::BulkString("a".into())])
+    );
+    let command = SMembersCommand::new(&[b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Array(vec![]));
+  }
+}
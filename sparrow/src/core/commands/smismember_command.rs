@@ -0,0 +1,104 @@
+//! Engine SMISMEMBER command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SMISMEMBER command.
+#[derive(Clone, Debug)]
+pub struct SMIsMemberCommand {
+  key: String,
+  members: Vec<Vec<u8>>,
+}
+
+impl SMIsMemberCommand {
+  /// Return a new [SMIsMemberCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, member...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SMIsMemberCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = SMIsMemberCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SMISMEMBER key a b");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SMIsMemberCommand> {
+    check_min_args_len("SMISMEMBER", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let members = args[1..].iter().map(|member| member.to_vec()).collect();
+    Ok(SMIsMemberCommand { key, members })
+  }
+}
+
+impl fmt::Display for SMIsMemberCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SMISMEMBER {}", self.key)?;
+    for member in &self.members {
+      write!(f, " {}", String::from_utf8_lossy(member))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for SMIsMemberCommand {
+  /// Execute the `SMISMEMBER key member [member ...]` command on a given [Nest].
+  ///
+  /// Return `1` or `0` for each member, in the members order.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let set = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Set(set)) => Some(set),
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => None,
+    };
+    Data::Array(
+      self
+        .members
+        .iter()
+        .map(|member| Data::Integer(set.is_some_and(|set| set.contains(member)) as i64))
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::smismember_command::SMIsMemberCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec()].into_iter().collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, set));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SMIsMemberCommand::new(&[TEST_KEY.as_bytes(), b"b", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![Data::Integer(0), Data::Integer(1)])
+    );
+    let command = SMIsMemberCommand::new(&[b"missing", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![Data::Integer(0)])
+    );
+  }
+}
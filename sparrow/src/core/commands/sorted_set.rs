@@ -0,0 +1,213 @@
+//! Utilities shared by sorted set commands.
+
+use crate::core::commands::args::parse_float;
+use crate::core::SortedSet;
+use crate::errors::Result;
+use std::fmt;
+
+/// Format a score as returned to clients, e.g. `1.5`, `3` or `-inf`.
+pub fn format_score(score: f64) -> Vec<u8> {
+  score.to_string().into_bytes()
+}
+
+/// Minimum or maximum score of a range of members.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScoreBound {
+  score: f64,
+  exclusive: bool,
+}
+
+impl ScoreBound {
+  /// Parse a score bound, exclusive when prefixed by `(`.
+  ///
+  /// # Arguments
+  /// * `arg` - Raw argument bytes, e.g. `1.5`, `(1.5` or `-inf`
+  pub fn parse(arg: &[u8]) -> Result<ScoreBound> {
+    let (arg, exclusive) = match arg.strip_prefix(b"(") {
+      Some(arg) => (arg, true),
+      None => (arg, false),
+    };
+    let score = parse_float(arg).map_err(|_| "Min or max is not a float")?;
+    Ok(ScoreBound { score, exclusive })
+  }
+}
+
+impl fmt::Display for ScoreBound {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let prefix = if self.exclusive { "(" } else { "" };
+    write!(f, "{}{}", prefix, self.score)
+  }
+}
+
+/// Range of members between two [ScoreBound].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScoreRange {
+  pub min: ScoreBound,
+  pub max: ScoreBound,
+}
+
+impl ScoreRange {
+  /// Return the ranks of the first member in the range and of the member following the range.
+  pub fn ranks(&self, sorted_set: &SortedSet) -> (usize, usize) {
+    let min = self.min;
+    let max = self.max;
+    let start =
+      sorted_set.count_while(|_, score| score < min.score || (min.exclusive && score == min.score));
+    let end = sorted_set
+      .count_while(|_, score| score < max.score || (!max.exclusive && score == max.score));
+    (start, end.max(start))
+  }
+}
+
+/// Minimum or maximum member of a lexicographical range of members.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LexBound {
+  /// Before every member, written `-`.
+  First,
+  /// After every member, written `+`.
+  Last,
+  /// Member included in the range, written with a `[` prefix.
+  Inclusive(Vec<u8>),
+  /// Member excluded from the range, written with a `(` prefix.
+  Exclusive(Vec<u8>),
+}
+
+impl LexBound {
+  /// Parse a lexicographical bound.
+  ///
+  /// # Arguments
+  /// * `arg` - Raw argument bytes, e.g. `-`, `+`, `[a` or `(a`
+  pub fn parse(arg: &[u8]) -> Result<LexBound> {
+    match arg {
+      b"-" => Ok(LexBound::First),
+      b"+" => Ok(LexBound::Last),
+      [b'[', member @ ..] => Ok(LexBound::Inclusive(member.to_vec())),
+      [b'(', member @ ..] => Ok(LexBound::Exclusive(member.to_vec())),
+      _ => Err("Min or max not valid string range item".into()),
+    }
+  }
+
+  /// Return whether a member is below this bound used as a minimum.
+  fn is_below_min(&self, member: &[u8]) -> bool {
+    match self {
+      LexBound::First => false,
+      LexBound::Last => true,
+      LexBound::Inclusive(bound) => member < bound.as_slice(),
+      LexBound::Exclusive(bound) => member <= bound.as_slice(),
+    }
+  }
+
+  /// Return whether a member is up to this bound used as a maximum.
+  fn is_up_to_max(&self, member: &[u8]) -> bool {
+    match self {
+      LexBound::First => false,
+      LexBound::Last => true,
+      LexBound::Inclusive(bound) => member <= bound.as_slice(),
+      LexBound::Exclusive(bound) => member < bound.as_slice(),
+    }
+  }
+}
+
+impl fmt::Display for LexBound {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LexBound::First => write!(f, "-"),
+      LexBound::Last => write!(f, "+"),
+      LexBound::Inclusive(member) => write!(f, "[{}", String::from_utf8_lossy(member)),
+      LexBound::Exclusive(member) => write!(f, "({}", String::from_utf8_lossy(member)),
+    }
+  }
+}
+
+/// Range of members between two [LexBound].
+///
+/// Members are compared lexicographically, which matches the sorted set order
+/// only when every member has the same score.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LexRange {
+  pub min: LexBound,
+  pub max: LexBound,
+}
+
+impl LexRange {
+  /// Return the ranks of the first member in the range and of the member following the range.
+  pub fn ranks(&self, sorted_set: &SortedSet) -> (usize, usize) {
+    let start = sorted_set.count_while(|member, _| self.min.is_below_min(member));
+    let end = sorted_set.count_while(|member, _| self.max.is_up_to_max(member));
+    (start, end.max(start))
+  }
+}
+
+/// Return whether a raw command argument is the given option, ignoring case.
+pub fn is_option(arg: &[u8], option: &str) -> bool {
+  arg.eq_ignore_ascii_case(option.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::sorted_set::{
+    format_score, LexBound, LexRange, ScoreBound, ScoreRange,
+  };
+  use crate::core::SortedSet;
+  use rstest::*;
+
+  #[fixture]
+  fn sorted_set() -> SortedSet {
+    let mut sorted_set = SortedSet::new();
+    for (index, member) in ["a", "b", "c", "d"].iter().enumerate() {
+      sorted_set.insert(member.as_bytes().to_vec(), index as f64);
+    }
+    sorted_set
+  }
+
+  #[test]
+  fn test_format_score() {
+    assert_eq!(format_score(1.0), b"1");
+    assert_eq!(format_score(-1.5), b"-1.5");
+    assert_eq!(format_score(f64::INFINITY), b"inf");
+  }
+
+  #[rstest]
+  fn test_score_range(sorted_set: SortedSet) {
+    let range = ScoreRange {
+      min: ScoreBound::parse(b"(0").unwrap(),
+      max: ScoreBound::parse(b"2").unwrap(),
+    };
+    assert_eq!(range.ranks(&sorted_set), (1, 3));
+    let range = ScoreRange {
+      min: ScoreBound::parse(b"-inf").unwrap(),
+      max: ScoreBound::parse(b"(1").unwrap(),
+    };
+    assert_eq!(range.ranks(&sorted_set), (0, 1));
+    let range = ScoreRange {
+      min: ScoreBound::parse(b"3").unwrap(),
+      max: ScoreBound::parse(b"1").unwrap(),
+    };
+    assert_eq!(range.ranks(&sorted_set), (3, 3));
+    assert!(ScoreBound::parse(b"(a").is_err());
+  }
+
+  #[test]
+  fn test_lex_range() {
+    let mut sorted_set = SortedSet::new();
+    for member in ["a", "b", "c", "d"] {
+      sorted_set.insert(member.as_bytes().to_vec(), 0.0);
+    }
+    let range = LexRange {
+      min: LexBound::parse(b"(a").unwrap(),
+      max: LexBound::parse(b"[c").unwrap(),
+    };
+    assert_eq!(range.ranks(&sorted_set), (1, 3));
+    let range = LexRange {
+      min: LexBound::parse(b"[b").unwrap(),
+      max: LexBound::parse(b"(d").unwrap(),
+    };
+    assert_eq!(range.ranks(&sorted_set), (1, 3));
+    let range = LexRange {
+      min: LexBound::parse(b"-").unwrap(),
+      max: LexBound::parse(b"+").unwrap(),
+    };
+    assert_eq!(range.ranks(&sorted_set), (0, 4));
+    assert!(LexBound::parse(b"a").is_err());
+  }
+}
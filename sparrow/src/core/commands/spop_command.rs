@@ -0,0 +1,177 @@
+//! Engine SPOP command.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::command::command_data;
+use crate::core::commands::set::random_members;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SPOP command.
+#[derive(Clone, Debug)]
+pub struct SPopCommand {
+  key: String,
+  count: Option<usize>,
+}
+
+impl SPopCommand {
+  /// Return a new [SPopCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 or 2 arguments (key, count).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SPopCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"2"[..]];
+  /// let cmd = SPopCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SPOP key 2");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SPopCommand> {
+    match args {
+      [key] => Ok(SPopCommand {
+        key: parse_string(key)?,
+        count: None,
+      }),
+      [key, count] => {
+        let count = parse_integer(count)?;
+        if count < 0 {
+          return Err("Value is out of range, must be positive".into());
+        }
+        Ok(SPopCommand {
+          key: parse_string(key)?,
+          count: Some(count as usize),
+        })
+      }
+      _ => Err(
+        format!(
+          "Cannot parse SPOP command arguments: Wrong number of arguments. Expected 1 or 2, got {}.",
+          args.len()
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+impl fmt::Display for SPopCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SPOP {}", self.key)?;
+    if let Some(count) = self.count {
+      write!(f, " {}", count)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for SPopCommand {
+  /// Execute the `SPOP key [count]` command on a given [Nest].
+  ///
+  /// Without count, return a random member removed from the set or [Data::Null].
+  /// With a count, return an array of distinct random members removed from the set.
+  /// The key is removed along with its last member.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let set = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_set_mut() {
+        Some(set) => set,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None if self.count.is_some() => return Data::Array(vec![]),
+      None => return Data::Null,
+    };
+    let members = random_members(set, self.count.unwrap_or(1));
+    for member in &members {
+      set.remove(member);
+    }
+    if set.is_empty() {
+      nest.rem(&self.key);
+    }
+    let mut members = members.into_iter().map(Data::BulkString);
+    match self.count {
+      Some(_) => Data::Array(members.collect()),
+      None => members.next().unwrap_or(Data::Null),
+    }
+  }
+
+  /// Rewrite the command as a `SREM` of the popped members, so that replaying it is deterministic.
+  fn to_aof(&self, _input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    let members = match output {
+      Data::BulkString(member) => vec![member.clone()],
+      Data::Array(members) if !members.is_empty() => members
+        .iter()
+        .filter_map(|member| match member {
+          Data::BulkString(member) => Some(member.clone()),
+          _ => None,
+        })
+        .collect(),
+      _ => return None,
+    };
+    let mut inputs = vec!["SREM".into(), self.key.clone().into_bytes()];
+    inputs.extend(members);
+    Some(command_data(inputs))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::command::command_data;
+  use crate::core::commands::spop_command::SPopCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]
+      .into_iter()
+      .collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, set));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is out of range, must be positive")]
+  fn test_command_new_negative_count() {
+    SPopCommand::new(&[TEST_KEY.as_bytes(), b"-1"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SPopCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    let output = command.execute(&mut nest);
+    let member = match &output {
+      Data::BulkString(member) => member.clone(),
+      output => panic!("Unexpected output {:?}", output),
+    };
+    let set = nest.get(TEST_KEY).unwrap().value().as_set().unwrap();
+    assert_eq!(set.len(), 2);
+    assert!(!set.contains(&member));
+    assert_eq!(
+      command.to_aof(&Data::Null, &output, &nest),
+      Some(command_data(vec!["SREM".into(), TEST_KEY.into(), member]))
+    );
+
+    let command = SPopCommand::new(&[TEST_KEY.as_bytes(), b"5"]).unwrap();
+    match command.execute(&mut nest) {
+      Data::Array(members) => assert_eq!(members.len(), 2),
+      output => panic!("Unexpected output {:?}", output),
+    }
+    assert!(nest.get(TEST_KEY).is_none());
+    assert_eq!(command.execute(&mut nest), Data::Array(vec![]));
+    assert_eq!(
+      command.to_aof(&Data::Null, &Data::Array(vec![]), &nest),
+      None
+    );
+  }
+}
@@ -0,0 +1,137 @@
+//! Engine SRANDMEMBER command.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::set::{random_members, random_members_with_repetitions};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SRANDMEMBER command.
+#[derive(Clone, Debug)]
+pub struct SRandMemberCommand {
+  key: String,
+  count: Option<i64>,
+}
+
+impl SRandMemberCommand {
+  /// Return a new [SRandMemberCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 or 2 arguments (key, count).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SRandMemberCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"-2"[..]];
+  /// let cmd = SRandMemberCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SRANDMEMBER key -2");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SRandMemberCommand> {
+    match args {
+      [key] => Ok(SRandMemberCommand {
+        key: parse_string(key)?,
+        count: None,
+      }),
+      [key, count] => Ok(SRandMemberCommand {
+        key: parse_string(key)?,
+        count: Some(parse_integer(count)?),
+      }),
+      _ => Err(
+        format!(
+          "Cannot parse SRANDMEMBER command arguments: Wrong number of arguments. Expected 1 or 2, got {}.",
+          args.len()
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+impl fmt::Display for SRandMemberCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SRANDMEMBER {}", self.key)?;
+    if let Some(count) = self.count {
+      write!(f, " {}", count)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for SRandMemberCommand {
+  /// Execute the `SRANDMEMBER key [count]` command on a given [Nest].
+  ///
+  /// Without count, return a random member of the set or [Data::Null].
+  /// With a positive count, return an array of distinct random members.
+  /// With a negative count, the same member may be returned several times.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let set = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::Set(set)) => set,
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None if self.count.is_some() => return Data::Array(vec![]),
+      None => return Data::Null,
+    };
+    let members = match self.count {
+      None => random_members(set, 1),
+      Some(count) if count >= 0 => random_members(set, count as usize),
+      Some(count) => random_members_with_repetitions(set, count.unsigned_abs() as usize),
+    };
+    let mut members = members.into_iter().map(Data::BulkString);
+    match self.count {
+      Some(_) => Data::Array(members.collect()),
+      None => members.next().unwrap_or(Data::Null),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::srandmember_command::SRandMemberCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into_iter().collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, set));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SRandMemberCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert!(matches!(command.execute(&mut nest), Data::BulkString(_)));
+
+    let command = SRandMemberCommand::new(&[TEST_KEY.as_bytes(), b"5"]).unwrap();
+    match command.execute(&mut nest) {
+      Data::Array(members) => assert_eq!(members.len(), 2),
+      output => panic!("Unexpected output {:?}", output),
+    }
+
+    let command = SRandMemberCommand::new(&[TEST_KEY.as_bytes(), b"-5"]).unwrap();
+    match command.execute(&mut nest) {
+      Data::Array(members) => assert_eq!(members.len(), 5),
+      output => panic!("Unexpected output {:?}", output),
+    }
+
+    // Members are not removed
+    assert_eq!(
+      nest.get(TEST_KEY).unwrap().value().as_set().unwrap().len(),
+      2
+    );
+
+    let command = SRandMemberCommand::new(&[b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+  }
+}
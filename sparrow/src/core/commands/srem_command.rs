@@ -0,0 +1,112 @@
+//! Engine SREM command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine SREM command.
+#[derive(Clone, Debug)]
+pub struct SRemCommand {
+  key: String,
+  members: Vec<Vec<u8>>,
+}
+
+impl SRemCommand {
+  /// Return a new [SRemCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, member...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::SRemCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = SRemCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "SREM key a b");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<SRemCommand> {
+    check_min_args_len("SREM", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let members = args[1..].iter().map(|member| member.to_vec()).collect();
+    Ok(SRemCommand { key, members })
+  }
+}
+
+impl fmt::Display for SRemCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "SREM {}", self.key)?;
+    for member in &self.members {
+      write!(f, " {}", String::from_utf8_lossy(member))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for SRemCommand {
+  /// Execute the `SREM key member [member ...]` command on a given [Nest].
+  ///
+  /// Return the number of removed members. The key is removed along with its last member.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let set = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_set_mut() {
+        Some(set) => set,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::Integer(0),
+    };
+    let removed = self
+      .members
+      .iter()
+      .filter(|member| set.remove(*member))
+      .count();
+    if set.is_empty() {
+      nest.rem(&self.key);
+    }
+    Data::Integer(removed as i64)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::srem_command::SRemCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::HashSet;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into_iter().collect();
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, set));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = SRemCommand::new(&[TEST_KEY.as_bytes(), b"a", b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    let command = SRemCommand::new(&[TEST_KEY.as_bytes(), b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert!(nest.get(TEST_KEY).is_none());
+  }
+}
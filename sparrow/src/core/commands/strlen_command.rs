@@ -0,0 +1,82 @@
+//! Engine STRLEN command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine STRLEN command.
+#[derive(Clone, Debug)]
+pub struct StrLenCommand {
+  key: String,
+}
+
+impl StrLenCommand {
+  /// Return a new [StrLenCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::StrLenCommand;
+  ///
+  /// let cmd = StrLenCommand::new(&[&b"key"[..]]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "STRLEN key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<StrLenCommand> {
+    check_args_len("STRLEN", args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(StrLenCommand { key })
+  }
+}
+
+impl fmt::Display for StrLenCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "STRLEN {}", self.key)
+  }
+}
+
+impl Command for StrLenCommand {
+  /// Execute the `STRLEN key` command on a given [Nest].
+  ///
+  /// Return the length of the value in bytes, `0` if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::String(value)) => Data::Integer(value.len() as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::strlen_command::StrLenCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, "héllo".as_bytes()));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = StrLenCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(6));
+    let command = StrLenCommand::new(&[b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+  }
+}
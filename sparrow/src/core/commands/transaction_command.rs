@@ -0,0 +1,107 @@
+//! Engine MULTI, EXEC, DISCARD, WATCH and UNWATCH commands.
+
+use crate::core::commands::args::{check_args_len, check_min_args_len, parse_string};
+use crate::errors::Result;
+use std::fmt;
+
+/// Commands controlling the transaction of a client.
+///
+/// These commands are executed as soon as they are received, even while the client
+/// is queuing commands, except `UNWATCH` which is queued like any other command.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransactionCommand {
+  /// Start queuing the following commands of the client.
+  Multi,
+  /// Execute the queued commands back-to-back.
+  Exec,
+  /// Throw the queued commands away.
+  Discard,
+  /// Abort the next transaction if one of the keys changes before it is executed.
+  Watch(Vec<String>),
+  /// Forget the watched keys.
+  Unwatch,
+}
+
+/// Parse a list of raw arguments into a [TransactionCommand].
+///
+/// Return [None] if the command name is not a transaction command.
+///
+/// # Arguments
+/// * `inputs` - Command name followed by its arguments
+pub fn parse_transaction_command(inputs: &[&[u8]]) -> Option<Result<TransactionCommand>> {
+  let (name, args) = inputs.split_first()?;
+  let name = parse_string(name).ok()?.to_uppercase();
+  let command = match name.as_str() {
+    "MULTI" => TransactionCommand::Multi,
+    "EXEC" => TransactionCommand::Exec,
+    "DISCARD" => TransactionCommand::Discard,
+    "UNWATCH" => TransactionCommand::Unwatch,
+    "WATCH" => return Some(parse_watch(args)),
+    _ => return None,
+  };
+  Some(check_args_len(&name, args, 0).map(|_| command))
+}
+
+/// Parse the arguments of `WATCH key [key ...]`.
+fn parse_watch(args: &[&[u8]]) -> Result<TransactionCommand> {
+  check_min_args_len("WATCH", args, 1)?;
+  let keys = args
+    .iter()
+    .map(|key| parse_string(key))
+    .collect::<Result<Vec<_>>>()?;
+  Ok(TransactionCommand::Watch(keys))
+}
+
+impl fmt::Display for TransactionCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      TransactionCommand::Multi => write!(f, "MULTI"),
+      TransactionCommand::Exec => write!(f, "EXEC"),
+      TransactionCommand::Discard => write!(f, "DISCARD"),
+      TransactionCommand::Watch(keys) => write!(f, "WATCH {}", keys.join(" ")),
+      TransactionCommand::Unwatch => write!(f, "UNWATCH"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::transaction_command::{parse_transaction_command, TransactionCommand};
+
+  #[test]
+  fn test_parse_transaction_command() {
+    for (name, expected) in [
+      (&b"multi"[..], TransactionCommand::Multi),
+      (b"EXEC", TransactionCommand::Exec),
+      (b"Discard", TransactionCommand::Discard),
+      (b"UNWATCH", TransactionCommand::Unwatch),
+    ] {
+      assert_eq!(
+        parse_transaction_command(&[name]).unwrap().unwrap(),
+        expected
+      );
+    }
+    assert_eq!(
+      format!(
+        "{}",
+        parse_transaction_command(&[b"watch", b"a", b"b"])
+          .unwrap()
+          .unwrap()
+      ),
+      "WATCH a b"
+    );
+    assert!(parse_transaction_command(&[b"WATCH"]).unwrap().is_err());
+    assert!(parse_transaction_command(&[b"GET", b"key"]).is_none());
+    assert!(parse_transaction_command(&[]).is_none());
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse EXEC command arguments: Wrong number of arguments. Expected 0, got 1."
+  )]
+  fn test_parse_transaction_command_args() {
+    parse_transaction_command(&[b"EXEC", b"key"])
+      .unwrap()
+      .unwrap();
+  }
+}
@@ -0,0 +1,138 @@
+//! Engine TTL and PTTL commands.
+
+use crate::core::commands::args::parse_string;
+use crate::core::commands::expiration::{time_to_live, TimeUnit};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use chrono::Utc;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine TTL and PTTL commands.
+#[derive(Clone, Debug)]
+pub struct TtlCommand {
+  key: String,
+  unit: TimeUnit,
+}
+
+impl TtlCommand {
+  /// Return a new [TtlCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  /// * `unit` - [TimeUnit] of the returned time to live
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{TimeUnit, TtlCommand};
+  ///
+  /// let args = &[&b"key"[..]];
+  /// let cmd = TtlCommand::new(args, TimeUnit::Milliseconds).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "PTTL key");
+  /// ```
+  pub fn new(args: &[&[u8]], unit: TimeUnit) -> Result<TtlCommand> {
+    match args.len() {
+      1 => {
+        let key = parse_string(args.first().unwrap())?;
+        Ok(TtlCommand { key, unit })
+      }
+      n => Err(
+        format!(
+          "Cannot parse {} command arguments: Wrong number of arguments. Expected 1, got {}.",
+          command_name(unit),
+          n
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+/// Return the name of the command returning a time to live in the given [TimeUnit].
+fn command_name(unit: TimeUnit) -> &'static str {
+  match unit {
+    TimeUnit::Seconds => "TTL",
+    TimeUnit::Milliseconds => "PTTL",
+  }
+}
+
+impl fmt::Display for TtlCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} {}", command_name(self.unit), self.key)
+  }
+}
+
+impl Command for TtlCommand {
+  /// Execute the `TTL key` command on a given [Nest].
+  ///
+  /// Return `-2` if the key does not exist and `-1` if it has no expiration date.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key) {
+      Some(egg) => match egg.expires_at() {
+        Some(expires_at) => Data::Integer(time_to_live(expires_at, &Utc::now(), self.unit)),
+        None => Data::Integer(-1),
+      },
+      None => Data::Integer(-2),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::expiration::TimeUnit;
+  use crate::core::commands::ttl_command::TtlCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use chrono::{Duration, Utc};
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+  const TEST_VALUE: &str = "This is a test value!";
+
+  #[fixture]
+  fn nest() -> Nest {
+    Nest::new()
+  }
+
+  #[test]
+  fn test_command_new_1_args() {
+    let args = &[TEST_KEY.as_bytes()];
+    let command = TtlCommand::new(args, TimeUnit::Seconds).unwrap();
+    assert_eq!(command.key, TEST_KEY);
+    assert_eq!(format!("{}", command), "TTL My key");
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse PTTL command arguments: Wrong number of arguments. Expected 1, got 2."
+  )]
+  fn test_command_new_2_args() {
+    let args = &[TEST_KEY.as_bytes(), TEST_VALUE.as_bytes()];
+    TtlCommand::new(args, TimeUnit::Milliseconds).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes()];
+    let ttl = TtlCommand::new(args, TimeUnit::Seconds).unwrap();
+    let pttl = TtlCommand::new(args, TimeUnit::Milliseconds).unwrap();
+
+    // Key does not exist
+    assert_eq!(ttl.execute(&mut nest), Data::Integer(-2));
+
+    // Key has no expiration
+    nest.set(Egg::new(TEST_KEY, TEST_VALUE.as_bytes()));
+    assert_eq!(ttl.execute(&mut nest), Data::Integer(-1));
+
+    nest.expire(TEST_KEY, Some(Utc::now() + Duration::seconds(10)));
+    assert_eq!(ttl.execute(&mut nest), Data::Integer(10));
+    match pttl.execute(&mut nest) {
+      Data::Integer(pttl) => assert!(pttl > 9_000 && pttl <= 10_000),
+      data => panic!("Unexpected output: {:?}", data),
+    }
+  }
+}
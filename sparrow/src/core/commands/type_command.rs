@@ -0,0 +1,86 @@
+//! Engine TYPE command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine TYPE command.
+#[derive(Clone, Debug)]
+pub struct TypeCommand {
+  key: String,
+}
+
+impl TypeCommand {
+  /// Return a new [TypeCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::TypeCommand;
+  ///
+  /// let args = &[&b"key"[..]];
+  /// let cmd = TypeCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "TYPE key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<TypeCommand> {
+    check_args_len("TYPE", args, 1)?;
+    Ok(TypeCommand {
+      key: parse_string(args.first().unwrap())?,
+    })
+  }
+}
+
+impl fmt::Display for TypeCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "TYPE {}", self.key)
+  }
+}
+
+impl Command for TypeCommand {
+  /// Execute the `TYPE key` command on a given [Nest].
+  ///
+  /// Return the type of the value, `none` if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let type_name = match nest.get(&self.key) {
+      Some(egg) => egg.value().type_name(),
+      None => "none",
+    };
+    Data::SimpleString(type_name.to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::type_command::TypeCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use rstest::*;
+  use sparrow_resp::Data;
+  use std::collections::VecDeque;
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut nest = Nest::new();
+    nest.set(Egg::new("string", &b"value"[..]));
+    nest.set(Egg::new("list", VecDeque::<Vec<u8>>::new()));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    for (key, expected) in [("string", "string"), ("list", "list"), ("missing", "none")] {
+      let command = TypeCommand::new(&[key.as_bytes()]).unwrap();
+      assert_eq!(
+        command.execute(&mut nest),
+        Data::SimpleString(expected.to_string())
+      );
+    }
+  }
+}
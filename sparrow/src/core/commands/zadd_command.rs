@@ -0,0 +1,303 @@
+//! Engine ZADD command.
+
+use crate::core::commands::args::{check_min_args_len, parse_float, parse_string};
+use crate::core::commands::sorted_set::{format_score, is_option};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::core::SortedSet;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine ZADD command.
+#[derive(Clone, Debug, Default)]
+pub struct ZAddCommand {
+  key: String,
+  /// Only add new members.
+  nx: bool,
+  /// Only update existing members.
+  xx: bool,
+  /// Only update existing members if the new score is greater.
+  gt: bool,
+  /// Only update existing members if the new score is lower.
+  lt: bool,
+  /// Count the updated members along with the added ones.
+  ch: bool,
+  /// Increment the score of a single member rather than setting it.
+  incr: bool,
+  members: Vec<(f64, Vec<u8>)>,
+}
+
+impl ZAddCommand {
+  /// Return a new [ZAddCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 3 arguments
+  ///   (key, options..., score, member, ...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZAddCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"gt"[..], &b"1.5"[..], &b"member"[..]];
+  /// let cmd = ZAddCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZADD key GT 1.5 member");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZAddCommand> {
+    check_min_args_len("ZADD", args, 3)?;
+    let mut command = ZAddCommand {
+      key: parse_string(args.first().unwrap())?,
+      ..Default::default()
+    };
+    let mut args = &args[1..];
+    while let Some((arg, rest)) = args.split_first() {
+      let flag = match arg {
+        arg if is_option(arg, "NX") => &mut command.nx,
+        arg if is_option(arg, "XX") => &mut command.xx,
+        arg if is_option(arg, "GT") => &mut command.gt,
+        arg if is_option(arg, "LT") => &mut command.lt,
+        arg if is_option(arg, "CH") => &mut command.ch,
+        arg if is_option(arg, "INCR") => &mut command.incr,
+        _ => break,
+      };
+      *flag = true;
+      args = rest;
+    }
+
+    if args.is_empty() || !args.len().is_multiple_of(2) {
+      return Err(
+        "Cannot parse ZADD command arguments: Wrong number of arguments. Expected score member pairs."
+          .into(),
+      );
+    }
+    if command.nx && command.xx {
+      return Err(
+        "Cannot parse ZADD command arguments: XX and NX options at the same time are not compatible."
+          .into(),
+      );
+    }
+    if (command.gt && command.lt) || ((command.gt || command.lt) && command.nx) {
+      return Err(
+        "Cannot parse ZADD command arguments: GT, LT, and/or NX options at the same time are not compatible."
+          .into(),
+      );
+    }
+    if command.incr && args.len() > 2 {
+      return Err(
+        "Cannot parse ZADD command arguments: INCR option supports a single increment-member pair."
+          .into(),
+      );
+    }
+    command.members = args
+      .chunks(2)
+      .map(|pair| Ok((parse_float(pair[0])?, pair[1].to_vec())))
+      .collect::<Result<_>>()?;
+    Ok(command)
+  }
+
+  /// Add the members to a [SortedSet] and return the command output.
+  fn add(&self, sorted_set: &mut SortedSet) -> Data {
+    let mut added = 0;
+    let mut changed = 0;
+    let mut incremented = None;
+    for (score, member) in &self.members {
+      let current = sorted_set.score(member);
+      if (self.nx && current.is_some()) || (self.xx && current.is_none()) {
+        continue;
+      }
+      let score = match (self.incr, current) {
+        (true, Some(current)) => current + score,
+        _ => *score,
+      };
+      if score.is_nan() {
+        return Data::Error("Resulting score is not a number (NaN)".to_string());
+      }
+      if let Some(current) = current {
+        if (self.gt && score <= current) || (self.lt && score >= current) {
+          continue;
+        }
+      }
+      sorted_set.insert(member.clone(), score);
+      match current {
+        None => added += 1,
+        Some(current) if current != score => changed += 1,
+        Some(_) => {}
+      }
+      incremented = Some(score);
+    }
+    match self.incr {
+      true => incremented
+        .map(|score| Data::BulkString(format_score(score)))
+        .unwrap_or(Data::Null),
+      false if self.ch => Data::Integer(added + changed),
+      false => Data::Integer(added),
+    }
+  }
+}
+
+impl fmt::Display for ZAddCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "ZADD {}", self.key)?;
+    let options = [
+      (self.nx, "NX"),
+      (self.xx, "XX"),
+      (self.gt, "GT"),
+      (self.lt, "LT"),
+      (self.ch, "CH"),
+      (self.incr, "INCR"),
+    ];
+    for (_, option) in options.iter().filter(|(enabled, _)| *enabled) {
+      write!(f, " {}", option)?;
+    }
+    for (score, member) in &self.members {
+      write!(f, " {} {}", score, String::from_utf8_lossy(member))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for ZAddCommand {
+  /// Execute the `ZADD key [NX|XX] [GT|LT] [CH] [INCR] score member [score member ...]` command
+  /// on a given [Nest].
+  ///
+  /// Return the number of added members, also counting the members whose score changed with `CH`.
+  /// With `INCR`, return the new score of the member, or [Data::Null] if the operation was aborted
+  /// because of the other options.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::SortedSet(SortedSet::new()));
+    let sorted_set = match egg.value_mut().as_sorted_set_mut() {
+      Some(sorted_set) => sorted_set,
+      None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+    };
+    let output = self.add(sorted_set);
+    if sorted_set.is_empty() {
+      nest.rem(&self.key);
+    }
+    output
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Null => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zadd_command::ZAddCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 1.0);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest
+  }
+
+  /// Return the score of a member of the test sorted set.
+  fn score(nest: &mut Nest, member: &[u8]) -> Option<f64> {
+    nest
+      .get(TEST_KEY)
+      .unwrap()
+      .value()
+      .as_sorted_set()
+      .unwrap()
+      .score(member)
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse ZADD command arguments: Wrong number of arguments. Expected score member pairs."
+  )]
+  fn test_command_new_odd_args() {
+    ZAddCommand::new(&[TEST_KEY.as_bytes(), b"NX", b"1", b"a", b"2"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse ZADD command arguments: GT, LT, and/or NX options at the same time are not compatible."
+  )]
+  fn test_command_new_incompatible_options() {
+    ZAddCommand::new(&[TEST_KEY.as_bytes(), b"NX", b"GT", b"1", b"a"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is not a valid float")]
+  fn test_command_new_invalid_score() {
+    ZAddCommand::new(&[TEST_KEY.as_bytes(), b"one", b"a"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"2", b"a", b"3", b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(score(&mut nest, b"a"), Some(2.0));
+
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"CH", b"5", b"a", b"3", b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+  }
+
+  #[rstest]
+  fn test_command_execute_conditions(mut nest: Nest) {
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"NX", b"5", b"a", b"1", b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(score(&mut nest, b"a"), Some(1.0));
+
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"XX", b"5", b"a", b"1", b"c"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert_eq!(score(&mut nest, b"a"), Some(5.0));
+    assert_eq!(score(&mut nest, b"c"), None);
+
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"GT", b"CH", b"4", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"LT", b"CH", b"4", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(score(&mut nest, b"a"), Some(4.0));
+
+    // The key is not created when no member is added
+    let command = ZAddCommand::new(&[b"missing", b"XX", b"1", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    assert!(nest.get("missing").is_none());
+  }
+
+  #[rstest]
+  fn test_command_execute_incr(mut nest: Nest) {
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"INCR", b"1.5", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("2.5".into()));
+
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"INCR", b"NX", b"1", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"INCR", b"inf", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("inf".into()));
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"INCR", b"-inf", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Resulting score is not a number (NaN)".to_string())
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_wrong_type(mut nest: Nest) {
+    nest.set(Egg::new(TEST_KEY, &b"value"[..]));
+    let command = ZAddCommand::new(&[TEST_KEY.as_bytes(), b"1", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
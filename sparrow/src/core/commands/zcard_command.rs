@@ -0,0 +1,93 @@
+//! Engine ZCARD command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine ZCARD command.
+#[derive(Clone, Debug)]
+pub struct ZCardCommand {
+  key: String,
+}
+
+impl ZCardCommand {
+  /// Return a new [ZCardCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 argument (key).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZCardCommand;
+  ///
+  /// let cmd = ZCardCommand::new(&[&b"key"[..]]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZCARD key");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZCardCommand> {
+    check_args_len("ZCARD", args, 1)?;
+    let key = parse_string(args.first().unwrap())?;
+    Ok(ZCardCommand { key })
+  }
+}
+
+impl fmt::Display for ZCardCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "ZCARD {}", self.key)
+  }
+}
+
+impl Command for ZCardCommand {
+  /// Execute the `ZCARD key` command on a given [Nest].
+  ///
+  /// Return the number of members of the sorted set, `0` if the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::SortedSet(sorted_set)) => Data::Integer(sorted_set.len() as i64),
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zcard_command::ZCardCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 1.0);
+    sorted_set.insert(b"b".to_vec(), 2.0);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest.set(Egg::new("string", &b"value"[..]));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ZCardCommand::new(&[TEST_KEY.as_bytes()]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    let command = ZCardCommand::new(&[b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = ZCardCommand::new(&[b"string"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -0,0 +1,112 @@
+//! Engine ZCOUNT command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::sorted_set::{ScoreBound, ScoreRange};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine ZCOUNT command.
+#[derive(Clone, Debug)]
+pub struct ZCountCommand {
+  key: String,
+  range: ScoreRange,
+}
+
+impl ZCountCommand {
+  /// Return a new [ZCountCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, min, max).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZCountCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"(1"[..], &b"+inf"[..]];
+  /// let cmd = ZCountCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZCOUNT key (1 inf");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZCountCommand> {
+    check_args_len("ZCOUNT", args, 3)?;
+    Ok(ZCountCommand {
+      key: parse_string(args.first().unwrap())?,
+      range: ScoreRange {
+        min: ScoreBound::parse(args.get(1).unwrap())?,
+        max: ScoreBound::parse(args.get(2).unwrap())?,
+      },
+    })
+  }
+}
+
+impl fmt::Display for ZCountCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "ZCOUNT {} {} {}",
+      self.key, self.range.min, self.range.max
+    )
+  }
+}
+
+impl Command for ZCountCommand {
+  /// Execute the `ZCOUNT key min max` command on a given [Nest].
+  ///
+  /// Return the number of members whose score is between `min` and `max`.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::SortedSet(sorted_set)) => {
+        let (start, end) = self.range.ranks(sorted_set);
+        Data::Integer((end - start) as i64)
+      }
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Integer(0),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zcount_command::ZCountCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 1.0);
+    sorted_set.insert(b"b".to_vec(), 2.0);
+    sorted_set.insert(b"c".to_vec(), 3.0);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Min or max is not a float")]
+  fn test_command_new_invalid_bound() {
+    ZCountCommand::new(&[TEST_KEY.as_bytes(), b"[1", b"2"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ZCountCommand::new(&[TEST_KEY.as_bytes(), b"-inf", b"+inf"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(3));
+    let command = ZCountCommand::new(&[TEST_KEY.as_bytes(), b"(1", b"3"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    let command = ZCountCommand::new(&[TEST_KEY.as_bytes(), b"3", b"1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = ZCountCommand::new(&[b"missing", b"1", b"3"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+  }
+}
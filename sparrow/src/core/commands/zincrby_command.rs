@@ -0,0 +1,142 @@
+//! Engine ZINCRBY command.
+
+use crate::core::commands::args::{check_args_len, parse_float, parse_string};
+use crate::core::commands::sorted_set::format_score;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::core::SortedSet;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine ZINCRBY command.
+#[derive(Clone, Debug)]
+pub struct ZIncrByCommand {
+  key: String,
+  increment: f64,
+  member: Vec<u8>,
+}
+
+impl ZIncrByCommand {
+  /// Return a new [ZIncrByCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 3 arguments (key, increment, member).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZIncrByCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"-2.5"[..], &b"member"[..]];
+  /// let cmd = ZIncrByCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZINCRBY key -2.5 member");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZIncrByCommand> {
+    check_args_len("ZINCRBY", args, 3)?;
+    Ok(ZIncrByCommand {
+      key: parse_string(args.first().unwrap())?,
+      increment: parse_float(args.get(1).unwrap())?,
+      member: args.get(2).unwrap().to_vec(),
+    })
+  }
+}
+
+impl fmt::Display for ZIncrByCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "ZINCRBY {} {} {}",
+      self.key,
+      self.increment,
+      String::from_utf8_lossy(&self.member)
+    )
+  }
+}
+
+impl Command for ZIncrByCommand {
+  /// Execute the `ZINCRBY key increment member` command on a given [Nest].
+  ///
+  /// Return the new score of the member. A missing member is added with the increment as score.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let egg = nest.get_or_insert_with(&self.key, || Value::SortedSet(SortedSet::new()));
+    let sorted_set = match egg.value_mut().as_sorted_set_mut() {
+      Some(sorted_set) => sorted_set,
+      None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+    };
+    let score = sorted_set.score(&self.member).unwrap_or(0.0) + self.increment;
+    if score.is_nan() {
+      if sorted_set.is_empty() {
+        nest.rem(&self.key);
+      }
+      return Data::Error("Resulting score is not a number (NaN)".to_string());
+    }
+    sorted_set.insert(self.member.clone(), score);
+    Data::BulkString(format_score(score))
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::BulkString(_) => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zincrby_command::ZIncrByCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 1.0);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest.set(Egg::new("string", &b"value"[..]));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ZIncrByCommand::new(&[TEST_KEY.as_bytes(), b"2.5", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("3.5".into()));
+    let command = ZIncrByCommand::new(&[TEST_KEY.as_bytes(), b"-1", b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("-1".into()));
+    let sorted_set = nest.get(TEST_KEY).unwrap().value().as_sorted_set().unwrap();
+    assert_eq!(sorted_set.rank(b"b"), Some(0));
+  }
+
+  #[rstest]
+  fn test_command_execute_nan(mut nest: Nest) {
+    let command = ZIncrByCommand::new(&[b"other", b"inf", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("inf".into()));
+    let command = ZIncrByCommand::new(&[b"other", b"-inf", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error("Resulting score is not a number (NaN)".to_string())
+    );
+
+    let command = ZIncrByCommand::new(&[b"missing", b"nan", b"a"]);
+    assert!(command.is_err());
+  }
+
+  #[rstest]
+  fn test_command_execute_wrong_type(mut nest: Nest) {
+    let command = ZIncrByCommand::new(&[b"string", b"1", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
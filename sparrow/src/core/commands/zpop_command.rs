@@ -0,0 +1,198 @@
+//! Engine ZPOPMIN and ZPOPMAX commands.
+
+use crate::core::commands::args::{parse_integer, parse_string};
+use crate::core::commands::sorted_set::format_score;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// End of a sorted set where members are popped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScoreEnd {
+  /// Members with the lowest scores.
+  Min,
+  /// Members with the highest scores.
+  Max,
+}
+
+/// Engine ZPOPMIN and ZPOPMAX commands.
+#[derive(Clone, Debug)]
+pub struct ZPopCommand {
+  key: String,
+  count: Option<usize>,
+  end: ScoreEnd,
+}
+
+impl ZPopCommand {
+  /// Return a new [ZPopCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 1 or 2 arguments (key, count).
+  /// * `end` - [ScoreEnd] where members are popped
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::{ScoreEnd, ZPopCommand};
+  ///
+  /// let args = &[&b"key"[..], &b"2"[..]];
+  /// let cmd = ZPopCommand::new(args, ScoreEnd::Max).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZPOPMAX key 2");
+  /// ```
+  pub fn new(args: &[&[u8]], end: ScoreEnd) -> Result<ZPopCommand> {
+    match args {
+      [key] => Ok(ZPopCommand {
+        key: parse_string(key)?,
+        count: None,
+        end,
+      }),
+      [key, count] => {
+        let count = parse_integer(count)?;
+        if count < 0 {
+          return Err("Value is out of range, must be positive".into());
+        }
+        Ok(ZPopCommand {
+          key: parse_string(key)?,
+          count: Some(count as usize),
+          end,
+        })
+      }
+      _ => Err(
+        format!(
+          "Cannot parse {} command arguments: Wrong number of arguments. Expected 1 or 2, got {}.",
+          command_name(end),
+          args.len()
+        )
+        .into(),
+      ),
+    }
+  }
+}
+
+/// Return the name of the command popping members from the given [ScoreEnd].
+fn command_name(end: ScoreEnd) -> &'static str {
+  match end {
+    ScoreEnd::Min => "ZPOPMIN",
+    ScoreEnd::Max => "ZPOPMAX",
+  }
+}
+
+impl fmt::Display for ZPopCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} {}", command_name(self.end), self.key)?;
+    if let Some(count) = self.count {
+      write!(f, " {}", count)?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for ZPopCommand {
+  /// Execute the `ZPOPMIN key [count]` or `ZPOPMAX key [count]` command on a given [Nest].
+  ///
+  /// Return an array alternating the popped members and their scores, starting with the lowest
+  /// scores for `ZPOPMIN` and the highest ones for `ZPOPMAX`. A single member is popped by default.
+  /// The key is removed along with its last member.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let sorted_set = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_sorted_set_mut() {
+        Some(sorted_set) => sorted_set,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::Array(vec![]),
+    };
+    let count = self.count.unwrap_or(1).min(sorted_set.len());
+    let (start, end, rev) = match self.end {
+      ScoreEnd::Min => (0, count, false),
+      ScoreEnd::Max => (sorted_set.len() - count, sorted_set.len(), true),
+    };
+    let popped: Vec<(Vec<u8>, f64)> = sorted_set
+      .range(start, end, rev)
+      .map(|(member, score)| (member.to_vec(), score))
+      .collect();
+    let mut output = Vec::with_capacity(popped.len() * 2);
+    for (member, score) in popped {
+      sorted_set.remove(&member);
+      output.push(Data::BulkString(member));
+      output.push(Data::BulkString(format_score(score)));
+    }
+    if sorted_set.is_empty() {
+      nest.rem(&self.key);
+    }
+    Data::Array(output)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Array(members) if !members.is_empty() => Some(input.clone()),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zpop_command::{ScoreEnd, ZPopCommand};
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 1.0);
+    sorted_set.insert(b"b".to_vec(), 2.0);
+    sorted_set.insert(b"c".to_vec(), 3.0);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest
+  }
+
+  #[test]
+  #[should_panic(expected = "Value is out of range, must be positive")]
+  fn test_command_new_negative_count() {
+    ZPopCommand::new(&[TEST_KEY.as_bytes(), b"-1"], ScoreEnd::Min).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute_min(mut nest: Nest) {
+    let command = ZPopCommand::new(&[TEST_KEY.as_bytes()], ScoreEnd::Min).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![
+        Data::BulkString("a".into()),
+        Data::BulkString("1".into())
+      ])
+    );
+  }
+
+  #[rstest]
+  fn test_command_execute_max(mut nest: Nest) {
+    let command = ZPopCommand::new(&[TEST_KEY.as_bytes(), b"5"], ScoreEnd::Max).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Array(vec![
+        Data::BulkString("c".into()),
+        Data::BulkString("3".into()),
+        Data::BulkString("b".into()),
+        Data::BulkString("2".into()),
+        Data::BulkString("a".into()),
+        Data::BulkString("1".into()),
+      ])
+    );
+    assert!(nest.get(TEST_KEY).is_none());
+    assert_eq!(command.execute(&mut nest), Data::Array(vec![]));
+    assert_eq!(
+      command.to_aof(&Data::Null, &Data::Array(vec![]), &nest),
+      None
+    );
+  }
+}
@@ -0,0 +1,303 @@
+//! Engine ZRANGE command.
+
+use crate::core::commands::args::{check_min_args_len, parse_integer, parse_string};
+use crate::core::commands::list::resolve_range;
+use crate::core::commands::sorted_set::{
+  format_score, is_option, LexBound, LexRange, ScoreBound, ScoreRange,
+};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::core::SortedSet;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Range of members selected by a [ZRangeCommand].
+#[derive(Clone, Debug, PartialEq)]
+enum ZRangeBy {
+  /// Inclusive range of ranks, negative ranks counting from the last member.
+  Rank(i64, i64),
+  /// Range of scores, see [ScoreRange].
+  Score(ScoreRange),
+  /// Lexicographical range of members, see [LexRange].
+  Lex(LexRange),
+}
+
+/// Engine ZRANGE command.
+#[derive(Clone, Debug)]
+pub struct ZRangeCommand {
+  key: String,
+  by: ZRangeBy,
+  rev: bool,
+  /// Offset and count of the members to return, a negative count meaning every member.
+  limit: Option<(i64, i64)>,
+  with_scores: bool,
+}
+
+impl ZRangeCommand {
+  /// Return a new [ZRangeCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 3 arguments
+  ///   (key, start, stop, options...). With `REV`, score and lexicographical ranges are
+  ///   given from the maximum to the minimum.
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZRangeCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"+inf"[..], &b"(1"[..], &b"byscore"[..], &b"rev"[..]];
+  /// let cmd = ZRangeCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZRANGE key inf (1 BYSCORE REV");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZRangeCommand> {
+    check_min_args_len("ZRANGE", args, 3)?;
+    let syntax_error = |arg: &[u8]| {
+      format!(
+        "Cannot parse ZRANGE command arguments: Syntax error near {}.",
+        String::from_utf8_lossy(arg)
+      )
+    };
+    let (mut by_score, mut by_lex, mut rev, mut with_scores) = (false, false, false, false);
+    let mut limit = None;
+    let mut options = args[3..].iter();
+    while let Some(option) = options.next() {
+      match option {
+        option if is_option(option, "BYSCORE") => by_score = true,
+        option if is_option(option, "BYLEX") => by_lex = true,
+        option if is_option(option, "REV") => rev = true,
+        option if is_option(option, "WITHSCORES") => with_scores = true,
+        option if is_option(option, "LIMIT") => match (options.next(), options.next()) {
+          (Some(offset), Some(count)) => {
+            limit = Some((parse_integer(offset)?, parse_integer(count)?));
+          }
+          _ => return Err(syntax_error(option).into()),
+        },
+        option => return Err(syntax_error(option).into()),
+      }
+    }
+
+    let start = args.get(1).unwrap();
+    let stop = args.get(2).unwrap();
+    // Score and lexicographical ranges are given from the maximum with REV
+    let (min, max) = if rev { (stop, start) } else { (start, stop) };
+    let by = match (by_score, by_lex) {
+      (true, true) => return Err(syntax_error(b"BYLEX").into()),
+      (true, false) => ZRangeBy::Score(ScoreRange {
+        min: ScoreBound::parse(min)?,
+        max: ScoreBound::parse(max)?,
+      }),
+      (false, true) => ZRangeBy::Lex(LexRange {
+        min: LexBound::parse(min)?,
+        max: LexBound::parse(max)?,
+      }),
+      (false, false) => ZRangeBy::Rank(parse_integer(start)?, parse_integer(stop)?),
+    };
+    if limit.is_some() && matches!(by, ZRangeBy::Rank(..)) {
+      return Err(
+        "Cannot parse ZRANGE command arguments: LIMIT is only supported in combination with either BYSCORE or BYLEX."
+          .into(),
+      );
+    }
+    if with_scores && matches!(by, ZRangeBy::Lex(_)) {
+      return Err(
+        "Cannot parse ZRANGE command arguments: WITHSCORES not supported in combination with BYLEX."
+          .into(),
+      );
+    }
+
+    Ok(ZRangeCommand {
+      key: parse_string(args.first().unwrap())?,
+      by,
+      rev,
+      limit,
+      with_scores,
+    })
+  }
+
+  /// Return the ranks, in ascending order, of the first selected member
+  /// and of the member following the last selected one.
+  fn ranks(&self, sorted_set: &SortedSet) -> (usize, usize) {
+    let len = sorted_set.len();
+    let (start, end) = match &self.by {
+      ZRangeBy::Rank(start, stop) => match resolve_range(*start, *stop, len) {
+        // Ranks are counted from the last member with REV
+        Some((start, stop)) if self.rev => (len - 1 - stop, len - start),
+        Some((start, stop)) => (start, stop + 1),
+        None => (0, 0),
+      },
+      ZRangeBy::Score(range) => range.ranks(sorted_set),
+      ZRangeBy::Lex(range) => range.ranks(sorted_set),
+    };
+    match self.limit {
+      Some((offset, _)) if offset < 0 => (0, 0),
+      Some((offset, count)) => {
+        let offset = (offset as usize).min(end - start);
+        let count = match count {
+          count if count < 0 => end - start - offset,
+          count => (count as usize).min(end - start - offset),
+        };
+        match self.rev {
+          true => (end - offset - count, end - offset),
+          false => (start + offset, start + offset + count),
+        }
+      }
+      None => (start, end),
+    }
+  }
+}
+
+impl fmt::Display for ZRangeCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "ZRANGE {}", self.key)?;
+    match &self.by {
+      ZRangeBy::Rank(start, stop) => write!(f, " {} {}", start, stop)?,
+      ZRangeBy::Score(range) if self.rev => write!(f, " {} {} BYSCORE", range.max, range.min)?,
+      ZRangeBy::Score(range) => write!(f, " {} {} BYSCORE", range.min, range.max)?,
+      ZRangeBy::Lex(range) if self.rev => write!(f, " {} {} BYLEX", range.max, range.min)?,
+      ZRangeBy::Lex(range) => write!(f, " {} {} BYLEX", range.min, range.max)?,
+    }
+    if self.rev {
+      write!(f, " REV")?;
+    }
+    if let Some((offset, count)) = self.limit {
+      write!(f, " LIMIT {} {}", offset, count)?;
+    }
+    if self.with_scores {
+      write!(f, " WITHSCORES")?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for ZRangeCommand {
+  /// Execute the `ZRANGE key start stop [BYSCORE|BYLEX] [REV] [LIMIT offset count] [WITHSCORES]`
+  /// command on a given [Nest].
+  ///
+  /// Return the selected members, ordered by ascending score or by descending score with `REV`.
+  /// With `WITHSCORES`, each member is followed by its score.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let sorted_set = match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::SortedSet(sorted_set)) => sorted_set,
+      Some(_) => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => return Data::Array(vec![]),
+    };
+    let (start, end) = self.ranks(sorted_set);
+    let mut output = Vec::new();
+    for (member, score) in sorted_set.range(start, end, self.rev) {
+      output.push(Data::BulkString(member.to_vec()));
+      if self.with_scores {
+        output.push(Data::BulkString(format_score(score)));
+      }
+    }
+    Data::Array(output)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zrange_command::ZRangeCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+  const LEX_KEY: &str = "lex";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    let mut lex_sorted_set = SortedSet::new();
+    for (index, member) in ["a", "b", "c", "d", "e"].iter().enumerate() {
+      sorted_set.insert(member.as_bytes().to_vec(), index as f64);
+      lex_sorted_set.insert(member.as_bytes().to_vec(), 0.0);
+    }
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest.set(Egg::new(LEX_KEY, lex_sorted_set));
+    nest
+  }
+
+  /// Return an array of bulk strings.
+  fn array(items: &[&str]) -> Data {
+    Data::Array(
+      items
+        .iter()
+        .map(|item| Data::BulkString(item.as_bytes().to_vec()))
+        .collect(),
+    )
+  }
+
+  #[test]
+  #[should_panic(
+    expected = "Cannot parse ZRANGE command arguments: LIMIT is only supported in combination with either BYSCORE or BYLEX."
+  )]
+  fn test_command_new_limit_by_rank() {
+    ZRangeCommand::new(&[TEST_KEY.as_bytes(), b"0", b"1", b"LIMIT", b"0", b"1"]).unwrap();
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot parse ZRANGE command arguments: Syntax error near BYRANK.")]
+  fn test_command_new_unknown_option() {
+    ZRangeCommand::new(&[TEST_KEY.as_bytes(), b"0", b"1", b"BYRANK"]).unwrap();
+  }
+
+  #[rstest]
+  fn test_command_execute_by_rank(mut nest: Nest) {
+    let command = ZRangeCommand::new(&[TEST_KEY.as_bytes(), b"1", b"-2"]).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["b", "c", "d"]));
+    let command = ZRangeCommand::new(&[TEST_KEY.as_bytes(), b"0", b"1", b"REV"]).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["e", "d"]));
+    let args = &[TEST_KEY.as_bytes(), b"-1", b"10", b"WITHSCORES"];
+    let command = ZRangeCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["e", "4"]));
+    let command = ZRangeCommand::new(&[TEST_KEY.as_bytes(), b"3", b"1"]).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&[]));
+  }
+
+  #[rstest]
+  fn test_command_execute_by_score(mut nest: Nest) {
+    let args = &[TEST_KEY.as_bytes(), b"(1", b"+inf", b"BYSCORE"];
+    let command = ZRangeCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["c", "d", "e"]));
+    let args = &[
+      TEST_KEY.as_bytes(),
+      b"3",
+      b"-inf",
+      b"BYSCORE",
+      b"REV",
+      b"LIMIT",
+      b"1",
+      b"2",
+    ];
+    let command = ZRangeCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["c", "b"]));
+    let args = &[
+      TEST_KEY.as_bytes(),
+      b"0",
+      b"10",
+      b"BYSCORE",
+      b"LIMIT",
+      b"3",
+      b"-1",
+    ];
+    let command = ZRangeCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["d", "e"]));
+  }
+
+  #[rstest]
+  fn test_command_execute_by_lex(mut nest: Nest) {
+    let command = ZRangeCommand::new(&[LEX_KEY.as_bytes(), b"[b", b"(d", b"BYLEX"]).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["b", "c"]));
+    let args = &[LEX_KEY.as_bytes(), b"+", b"(c", b"BYLEX", b"REV"];
+    let command = ZRangeCommand::new(args).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&["e", "d"]));
+    let command = ZRangeCommand::new(&[b"missing", b"-", b"+", b"BYLEX"]).unwrap();
+    assert_eq!(command.execute(&mut nest), array(&[]));
+  }
+}
@@ -0,0 +1,101 @@
+//! Engine ZRANK command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine ZRANK command.
+#[derive(Clone, Debug)]
+pub struct ZRankCommand {
+  key: String,
+  member: Vec<u8>,
+}
+
+impl ZRankCommand {
+  /// Return a new [ZRankCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, member).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZRankCommand;
+  ///
+  /// let cmd = ZRankCommand::new(&[&b"key"[..], &b"member"[..]]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZRANK key member");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZRankCommand> {
+    check_args_len("ZRANK", args, 2)?;
+    Ok(ZRankCommand {
+      key: parse_string(args.first().unwrap())?,
+      member: args.get(1).unwrap().to_vec(),
+    })
+  }
+}
+
+impl fmt::Display for ZRankCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "ZRANK {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.member)
+    )
+  }
+}
+
+impl Command for ZRankCommand {
+  /// Execute the `ZRANK key member` command on a given [Nest].
+  ///
+  /// Return the 0-based rank of the member ordered by ascending score,
+  /// or [Data::Null] if the member or the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::SortedSet(sorted_set)) => match sorted_set.rank(&self.member) {
+        Some(rank) => Data::Integer(rank as i64),
+        None => Data::Null,
+      },
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Null,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zrank_command::ZRankCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 3.0);
+    sorted_set.insert(b"b".to_vec(), 2.0);
+    sorted_set.insert(b"c".to_vec(), 1.0);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ZRankCommand::new(&[TEST_KEY.as_bytes(), b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(2));
+    let command = ZRankCommand::new(&[TEST_KEY.as_bytes(), b"c"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+    let command = ZRankCommand::new(&[TEST_KEY.as_bytes(), b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+  }
+}
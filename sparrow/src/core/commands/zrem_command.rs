@@ -0,0 +1,114 @@
+//! Engine ZREM command.
+
+use crate::core::commands::args::{check_min_args_len, parse_string};
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::WRONG_TYPE_ERROR;
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine ZREM command.
+#[derive(Clone, Debug)]
+pub struct ZRemCommand {
+  key: String,
+  members: Vec<Vec<u8>>,
+}
+
+impl ZRemCommand {
+  /// Return a new [ZRemCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be at least 2 arguments (key, member...).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZRemCommand;
+  ///
+  /// let args = &[&b"key"[..], &b"a"[..], &b"b"[..]];
+  /// let cmd = ZRemCommand::new(args).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZREM key a b");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZRemCommand> {
+    check_min_args_len("ZREM", args, 2)?;
+    let key = parse_string(args.first().unwrap())?;
+    let members = args[1..].iter().map(|member| member.to_vec()).collect();
+    Ok(ZRemCommand { key, members })
+  }
+}
+
+impl fmt::Display for ZRemCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "ZREM {}", self.key)?;
+    for member in &self.members {
+      write!(f, " {}", String::from_utf8_lossy(member))?;
+    }
+    Ok(())
+  }
+}
+
+impl Command for ZRemCommand {
+  /// Execute the `ZREM key member [member ...]` command on a given [Nest].
+  ///
+  /// Return the number of removed members. The key is removed along with its last member.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    let sorted_set = match nest.get_mut(&self.key) {
+      Some(egg) => match egg.value_mut().as_sorted_set_mut() {
+        Some(sorted_set) => sorted_set,
+        None => return Data::Error(WRONG_TYPE_ERROR.to_string()),
+      },
+      None => return Data::Integer(0),
+    };
+    let removed = self
+      .members
+      .iter()
+      .filter(|member| sorted_set.remove(member).is_some())
+      .count();
+    if sorted_set.is_empty() {
+      nest.rem(&self.key);
+    }
+    Data::Integer(removed as i64)
+  }
+
+  fn to_aof(&self, input: &Data, output: &Data, _nest: &Nest) -> Option<Data> {
+    match output {
+      Data::Integer(0) => None,
+      _ => Some(input.clone()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zrem_command::ZRemCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 1.0);
+    sorted_set.insert(b"b".to_vec(), 2.0);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ZRemCommand::new(&[TEST_KEY.as_bytes(), b"a", b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert_eq!(command.execute(&mut nest), Data::Integer(0));
+
+    let command = ZRemCommand::new(&[TEST_KEY.as_bytes(), b"b"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Integer(1));
+    assert!(nest.get(TEST_KEY).is_none());
+  }
+}
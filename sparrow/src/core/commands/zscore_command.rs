@@ -0,0 +1,106 @@
+//! Engine ZSCORE command.
+
+use crate::core::commands::args::{check_args_len, parse_string};
+use crate::core::commands::sorted_set::format_score;
+use crate::core::commands::Command;
+use crate::core::nest::Nest;
+use crate::core::value::{Value, WRONG_TYPE_ERROR};
+use crate::errors::Result;
+use sparrow_resp::Data;
+use std::fmt;
+
+/// Engine ZSCORE command.
+#[derive(Clone, Debug)]
+pub struct ZScoreCommand {
+  key: String,
+  member: Vec<u8>,
+}
+
+impl ZScoreCommand {
+  /// Return a new [ZScoreCommand].
+  ///
+  /// # Arguments
+  /// * `args` - Arguments of this command. There should be 2 arguments (key, member).
+  ///
+  /// # Examples
+  /// ```rust
+  /// use crate::core::commands::ZScoreCommand;
+  ///
+  /// let cmd = ZScoreCommand::new(&[&b"key"[..], &b"member"[..]]).unwrap();
+  ///
+  /// assert_eq!(format!("{}", cmd), "ZSCORE key member");
+  /// ```
+  pub fn new(args: &[&[u8]]) -> Result<ZScoreCommand> {
+    check_args_len("ZSCORE", args, 2)?;
+    Ok(ZScoreCommand {
+      key: parse_string(args.first().unwrap())?,
+      member: args.get(1).unwrap().to_vec(),
+    })
+  }
+}
+
+impl fmt::Display for ZScoreCommand {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "ZSCORE {} {}",
+      self.key,
+      String::from_utf8_lossy(&self.member)
+    )
+  }
+}
+
+impl Command for ZScoreCommand {
+  /// Execute the `ZSCORE key member` command on a given [Nest].
+  ///
+  /// Return the score of the member, or [Data::Null] if the member or the key does not exist.
+  fn execute(&self, nest: &mut Nest) -> Data {
+    match nest.get(&self.key).map(|egg| egg.value()) {
+      Some(Value::SortedSet(sorted_set)) => match sorted_set.score(&self.member) {
+        Some(score) => Data::BulkString(format_score(score)),
+        None => Data::Null,
+      },
+      Some(_) => Data::Error(WRONG_TYPE_ERROR.to_string()),
+      None => Data::Null,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::zscore_command::ZScoreCommand;
+  use crate::core::commands::Command;
+  use crate::core::egg::Egg;
+  use crate::core::nest::Nest;
+  use crate::core::value::WRONG_TYPE_ERROR;
+  use crate::core::SortedSet;
+  use rstest::*;
+  use sparrow_resp::Data;
+
+  const TEST_KEY: &str = "My key";
+
+  #[fixture]
+  fn nest() -> Nest {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"a".to_vec(), 1.5);
+    let mut nest = Nest::new();
+    nest.set(Egg::new(TEST_KEY, sorted_set));
+    nest.set(Egg::new("string", &b"value"[..]));
+    nest
+  }
+
+  #[rstest]
+  fn test_command_execute(mut nest: Nest) {
+    let command = ZScoreCommand::new(&[TEST_KEY.as_bytes(), b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::BulkString("1.5".into()));
+    let command = ZScoreCommand::new(&[TEST_KEY.as_bytes(), b"missing"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+    let command = ZScoreCommand::new(&[b"missing", b"a"]).unwrap();
+    assert_eq!(command.execute(&mut nest), Data::Null);
+    let command = ZScoreCommand::new(&[b"string", b"a"]).unwrap();
+    assert_eq!(
+      command.execute(&mut nest),
+      Data::Error(WRONG_TYPE_ERROR.to_string())
+    );
+  }
+}
@@ -1,17 +1,37 @@
 //! Base data representation.
 
+use crate::core::value::Value;
 use chrono::prelude::{DateTime, Utc};
 use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::SystemTime;
 
+/// Source of the [Egg] and [Nest] versions, shared by every [Nest] so that a version is never reused.
+///
+/// [Nest]: crate::core::nest::Nest
+static NEXT_VERSION: AtomicU64 = AtomicU64::new(0);
+
+/// Return a version that was never given to an [Egg] or a [Nest].
+///
+/// [Nest]: crate::core::nest::Nest
+pub fn next_version() -> u64 {
+  NEXT_VERSION.fetch_add(1, Ordering::Relaxed)
+}
+
 /// Egg is the base representation of data into Sparrow.
 ///
 /// It stores the `key` - `value` pair along with some metadata.
+/// Values are typed, see [Value].
+///
+/// Each [Egg] has a version that changes every time it is modified,
+/// so that clients can detect that a key changed after they read it.
 #[derive(Debug, Clone)]
 pub struct Egg {
   key: String,
-  value: String,
+  value: Value,
   created_at: DateTime<Utc>,
+  expires_at: Option<DateTime<Utc>>,
+  version: u64,
 }
 
 impl Egg {
@@ -19,29 +39,99 @@ impl Egg {
   ///
   /// # Arguments
   /// * `key` - The key to store
-  /// * `value` - The value to store
-  pub fn new(key: &str, value: &str) -> Egg {
+  /// * `value` - The value to store. Raw bytes are stored as a [Value::String]
+  pub fn new(key: &str, value: impl Into<Value>) -> Egg {
     let created_at: DateTime<Utc> = SystemTime::now().into();
     Egg {
       key: key.to_string(),
-      value: value.to_string(),
+      value: value.into(),
+      created_at,
+      expires_at: None,
+      version: next_version(),
+    }
+  }
+  /// Return an [Egg] restored from its stored fields.
+  ///
+  /// # Arguments
+  /// * `key` - The stored key
+  /// * `value` - The stored value
+  /// * `created_at` - The creation date of the stored [Egg]
+  /// * `expires_at` - The expiration date of the stored [Egg], if any
+  pub fn restore(
+    key: String,
+    value: Value,
+    created_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+  ) -> Egg {
+    Egg {
+      key,
+      value,
       created_at,
+      expires_at,
+      version: next_version(),
     }
   }
+  /// Return this [Egg] stored under another key, keeping its value and metadata.
+  ///
+  /// # Arguments
+  /// * `key` - The new key
+  pub fn with_key(mut self, key: &str) -> Egg {
+    self.key = key.to_string();
+    self.version = next_version();
+    self
+  }
   /// Return private field `key`
   pub fn key(&self) -> &String {
     &self.key
   }
   /// Return private field `value`
-  #[allow(unused)]
-  pub fn value(&self) -> &String {
+  pub fn value(&self) -> &Value {
     &self.value
   }
+  /// Return private field `value` as mutable
+  ///
+  /// **The version of an [Egg] stored into a [Nest] is changed by [Nest::commit]
+  /// once the value is actually modified.**
+  ///
+  /// [Nest]: crate::core::nest::Nest
+  /// [Nest::commit]: crate::core::nest::Nest::commit
+  pub fn value_mut(&mut self) -> &mut Value {
+    &mut self.value
+  }
   /// Return private field `created_at`
-  #[allow(unused)]
   pub fn created_at(&self) -> &DateTime<Utc> {
     &self.created_at
   }
+  /// Return private field `expires_at`
+  pub fn expires_at(&self) -> Option<&DateTime<Utc>> {
+    self.expires_at.as_ref()
+  }
+  /// Set private field `expires_at`
+  ///
+  /// **The expiration of an [Egg] already stored into a [Nest] must be updated
+  /// using [Nest::expire] so that the expiration index is kept in sync.**
+  ///
+  /// [Nest]: crate::core::nest::Nest
+  /// [Nest::expire]: crate::core::nest::Nest::expire
+  pub fn set_expires_at(&mut self, expires_at: Option<DateTime<Utc>>) {
+    self.expires_at = expires_at;
+    self.version = next_version();
+  }
+  /// Return private field `version`
+  pub fn version(&self) -> u64 {
+    self.version
+  }
+  /// Change the version of the [Egg] after it has been modified.
+  pub fn touch(&mut self) {
+    self.version = next_version();
+  }
+  /// Return whether the [Egg] is expired at the given date.
+  ///
+  /// # Arguments
+  /// * `now` - Date to compare the expiration date with
+  pub fn is_expired(&self, now: &DateTime<Utc>) -> bool {
+    self.expires_at.is_some_and(|expires_at| expires_at <= *now)
+  }
 }
 
 impl fmt::Display for Egg {
@@ -66,36 +156,96 @@ impl Eq for Egg {}
 mod tests {
   use super::*;
   use rstest::*;
+  use std::collections::HashMap;
 
   const TEST_EGG_KEY: &str = "test";
   const TEST_EGG_VALUE: &str = "This is a test value!";
 
   #[test]
   fn test_egg_new() {
-    Egg::new(TEST_EGG_KEY, TEST_EGG_VALUE);
+    Egg::new(TEST_EGG_KEY, TEST_EGG_VALUE.as_bytes());
   }
 
   #[fixture]
   fn egg() -> Egg {
-    Egg::new(TEST_EGG_KEY, TEST_EGG_VALUE)
+    Egg::new(TEST_EGG_KEY, TEST_EGG_VALUE.as_bytes())
   }
 
   #[rstest]
   fn test_egg_getters(egg: Egg) {
     assert_eq!(egg.key(), TEST_EGG_KEY);
-    assert_eq!(egg.value(), TEST_EGG_VALUE);
+    assert_eq!(egg.value(), &Value::String(TEST_EGG_VALUE.into()));
 
     let current_time: DateTime<Utc> = SystemTime::now().into();
     // Egg has been create before current time
     assert!(egg.created_at() < &current_time);
   }
 
+  #[rstest]
+  fn test_egg_with_key(egg: Egg) {
+    let renamed = egg.clone().with_key("other");
+    assert_eq!(renamed.key(), "other");
+    assert_eq!(renamed.value(), egg.value());
+    assert_eq!(renamed.created_at(), egg.created_at());
+  }
+
+  #[rstest]
+  fn test_egg_version(mut egg: Egg) {
+    let other = Egg::new(TEST_EGG_KEY, TEST_EGG_VALUE.as_bytes());
+    assert_ne!(egg.version(), other.version());
+
+    // Borrowing the value keeps the version, touching changes it
+    let version = egg.version();
+    egg.value();
+    egg.value_mut();
+    assert_eq!(egg.version(), version);
+    egg.touch();
+    assert_ne!(egg.version(), version);
+
+    let version = egg.version();
+    egg.set_expires_at(None);
+    assert_ne!(egg.version(), version);
+    let version = egg.version();
+    assert_ne!(egg.with_key("other").version(), version);
+  }
+
+  #[test]
+  fn test_egg_binary_value() {
+    let egg = Egg::new(TEST_EGG_KEY, &[0x00, 0xff, 0x89][..]);
+    assert_eq!(egg.value().as_string(), Some(&[0x00, 0xff, 0x89][..]));
+  }
+
+  #[test]
+  fn test_egg_hash_value() {
+    let mut hash = HashMap::new();
+    hash.insert(b"field".to_vec(), b"value".to_vec());
+    let mut egg = Egg::new(TEST_EGG_KEY, hash);
+    assert!(egg.value().as_hash().is_some());
+
+    egg.value_mut().as_hash_mut().unwrap().remove(&b"field"[..]);
+    assert_eq!(egg.value(), &Value::Hash(HashMap::new()));
+  }
+
+  #[rstest]
+  fn test_egg_expiration(mut egg: Egg) {
+    let now: DateTime<Utc> = SystemTime::now().into();
+    assert_eq!(egg.expires_at(), None);
+    assert!(!egg.is_expired(&now));
+
+    egg.set_expires_at(Some(now));
+    assert_eq!(egg.expires_at(), Some(&now));
+    assert!(egg.is_expired(&now));
+
+    egg.set_expires_at(None);
+    assert!(!egg.is_expired(&now));
+  }
+
   #[rstest]
   fn test_egg_display_impl(egg: Egg) {
     let expected = format!(
       "{{ key={}, value={}, created_at={} }}",
       egg.key(),
-      egg.value(),
+      TEST_EGG_VALUE,
       egg.created_at()
     );
     assert_eq!(format!("{}", egg), expected);
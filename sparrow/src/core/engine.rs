@@ -1,16 +1,31 @@
-//! Core engine managing the database.
+//! Core engine managing the databases.
 
-use crate::core::commands::parse_command;
+use crate::core::commands::{
+  command_data, parse_command, BlockingCommand, Command, DatabaseCommand, EngineCommand,
+  ParsedCommand, TransactionCommand,
+};
 use crate::core::nest::Nest;
+use crate::core::session::{Session, WatchedKey};
 use crate::errors::Result;
 use crate::logger::BACKSPACE_CHARACTER;
+use crate::persistence::{self, AppendOnlyFile, FsyncPolicy};
 use async_std::channel::{unbounded, Receiver, Sender};
+use async_std::future::timeout;
+use chrono::Utc;
 use sparrow_resp::Data;
+use std::collections::VecDeque;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Interval between two sweeps of expired eggs.
+const EXPIRATION_SWEEP_INTERVAL: Duration = Duration::from_millis(100);
+/// Number of databases of an [Engine] created with [Engine::new].
+const DEFAULT_DATABASES: usize = 16;
 
 /// Input send to the engine through an input sender.
 pub struct EngineInput {
-  /// Requester client's id.
-  id: String,
+  /// [Session] of the requester client.
+  session: Session,
   /// Data encoding the input command for the engine
   data: Data,
   /// Output sender used by the Engine to send output to the client.
@@ -18,21 +33,54 @@ pub struct EngineInput {
 }
 
 impl EngineInput {
-  pub fn new(id: String, data: Data, sender: Sender<Data>) -> EngineInput {
-    EngineInput { id, data, sender }
+  pub fn new(session: Session, data: Data, sender: Sender<Data>) -> EngineInput {
+    EngineInput {
+      session,
+      data,
+      sender,
+    }
   }
 }
 
-impl EngineInput {
-  pub fn id(&self) -> &String {
-    &self.id
-  }
-  pub fn data(&self) -> &Data {
-    &self.data
-  }
-  pub fn sender(&self) -> &Sender<Data> {
-    &self.sender
-  }
+/// Outcome of an input processed by the engine.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+  /// Output to send back to the client.
+  Reply(Data),
+  /// The client is blocked by a [BlockingCommand] until an element is available.
+  Blocked(BlockingCommand),
+}
+
+/// State of the transaction whose commands are appended to the [AppendOnlyFile].
+///
+/// The commands executed by `EXEC` are wrapped between `MULTI` and `EXEC` in the file,
+/// so that a transaction cut short by a crash is not replayed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum AofTransaction {
+  /// No transaction is being executed.
+  Idle,
+  /// A transaction is being executed, none of its commands has been appended yet.
+  Pending,
+  /// `MULTI` has been appended, `EXEC` is appended after the last command of the transaction.
+  Open,
+}
+
+/// Client blocked by a [BlockingCommand] until an element is available or its timeout is reached.
+struct BlockedClient {
+  /// [Session] of the blocked client.
+  session: Session,
+  /// Database the client is blocked on.
+  db: usize,
+  /// Data the blocking command has been parsed from.
+  data: Data,
+  /// Command waiting for an element.
+  command: BlockingCommand,
+  /// Output sender used to send the output to the client once it is unblocked.
+  sender: Sender<Data>,
+  /// Date after which the client is unblocked with the timeout reply, if any.
+  deadline: Option<Instant>,
+  /// Inputs received from the client while it is blocked, processed once it is unblocked.
+  pending: VecDeque<Data>,
 }
 
 /// Engine that manages the in-memory state and database operations.
@@ -43,28 +91,57 @@ impl EngineInput {
 /// use crate::net::run_tcp_server;
 /// use crate::core::Engine;
 ///
-/// let mut engine = Engine::new();
+/// let mut engine = Engine::with_databases(16);
 /// let engine_sender = engine.init();
 /// let engine_task = task::spawn(async move { engine.run().await });
 ///
 /// let tcp_task =
-// task::spawn(async move { run_tcp_server(config.tcp_server_port, engine_sender).await });
-
+///   task::spawn(async move { run_tcp_server(config.tcp_server_port, engine_sender).await });
+///
 /// try_join!(engine_task, tcp_task).map(|_| ())
 /// ```
 pub struct Engine {
-  /// [Nest] used for in-memory data storage.
-  nest: Nest,
+  /// [Nest]s used for in-memory data storage, indexed by database.
+  nests: Vec<Nest>,
   /// [async_std] consumer channel used to retrieve inputs for the engine.
   inputs: Option<Receiver<EngineInput>>,
+  /// [AppendOnlyFile] logging mutating commands, if enabled.
+  aof: Option<AppendOnlyFile>,
+  /// Database the commands appended to the [AppendOnlyFile] are replayed on.
+  aof_db: usize,
+  /// State of the transaction being appended to the [AppendOnlyFile].
+  aof_transaction: AofTransaction,
+  /// Path of the snapshot file written by `SAVE` and `BGSAVE`.
+  snapshot_filepath: Option<String>,
+  /// Thread writing a snapshot in the background, if any.
+  bgsave: Option<JoinHandle<()>>,
+  /// Clients blocked by a [BlockingCommand], in the order they were blocked.
+  blocked: Vec<BlockedClient>,
+  /// Databases and keys of the lists that received elements since blocked clients were last served.
+  ready_keys: VecDeque<(usize, String)>,
 }
 
 impl Engine {
-  /// Return a new [Engine].
+  /// Return a new [Engine] with the default number of databases.
   pub fn new() -> Engine {
+    Engine::with_databases(DEFAULT_DATABASES)
+  }
+
+  /// Return a new [Engine] with the given number of databases.
+  ///
+  /// # Arguments
+  /// * `databases` - Number of databases, at least 1
+  pub fn with_databases(databases: usize) -> Engine {
     Engine {
-      nest: Nest::new(),
+      nests: (0..databases.max(1)).map(|_| Nest::new()).collect(),
       inputs: None,
+      aof: None,
+      aof_db: 0,
+      aof_transaction: AofTransaction::Idle,
+      snapshot_filepath: None,
+      bgsave: None,
+      blocked: Vec::new(),
+      ready_keys: VecDeque::new(),
     }
   }
 }
@@ -89,6 +166,68 @@ impl Engine {
     input_sender
   }
 
+  /// Enable append-only file persistence.
+  ///
+  /// The commands already stored in the file are replayed first to restore the [Nest]s.
+  /// Every mutating command executed afterwards is appended to the file,
+  /// preceded by a `SELECT` command when it runs on another database than the previous one.
+  ///
+  /// A transaction left unfinished at the end of the file is not replayed,
+  /// and is discarded in the file so that the following commands are not part of it.
+  ///
+  /// # Arguments
+  /// * `filepath` - Path of the append-only file
+  /// * `policy` - [FsyncPolicy] used to flush the file to the disk
+  pub async fn enable_aof(&mut self, filepath: &str, policy: FsyncPolicy) -> Result<()> {
+    log::debug!("Replaying append-only file {}", filepath);
+    let mut replay = Replay {
+      nests: &mut self.nests,
+      db: 0,
+      transaction: None,
+    };
+    let count = persistence::replay(filepath, |data| replay.replay(data)).await?;
+    log::info!("Replayed {} commands from {}", count, filepath);
+    let (db, unfinished) = (replay.db, replay.transaction.is_some());
+
+    let mut aof = AppendOnlyFile::open(filepath, policy).await?;
+    if unfinished {
+      log::warn!(
+        "Discarding unfinished transaction at the end of {}",
+        filepath
+      );
+      aof.append(&command_data(vec![b"DISCARD".to_vec()])).await?;
+    }
+    self.aof_db = db;
+    self.aof = Some(aof);
+    Ok(())
+  }
+
+  /// Set the path of the snapshot file written by `SAVE` and `BGSAVE`.
+  ///
+  /// # Arguments
+  /// * `filepath` - Path of the snapshot file
+  pub fn set_snapshot_filepath(&mut self, filepath: &str) {
+    self.snapshot_filepath = Some(filepath.to_string());
+  }
+
+  /// Restore the [Nest]s from the snapshot file if it exists.
+  pub fn load_snapshot(&mut self) -> Result<()> {
+    let filepath = self
+      .snapshot_filepath
+      .as_ref()
+      .ok_or("Snapshot file is not configured")?;
+    log::debug!("Loading snapshot {}", filepath);
+    match persistence::load_snapshot(filepath, self.nests.len())? {
+      Some(nests) => {
+        let count: usize = nests.iter().map(Nest::len).sum();
+        log::info!("Loaded {} eggs from {}", count, filepath);
+        self.nests = nests;
+      }
+      None => log::info!("No snapshot found at {}", filepath),
+    }
+    Ok(())
+  }
+
   /// Run the engine.
   ///
   /// Loop infinitely to:
@@ -96,8 +235,16 @@ impl Engine {
   /// - Parse the [Data] it contains into a command.
   /// - Process this command (i.e. execute the command contained in the input)
   /// - Send the output [Data] through the [Sender] contained in the [EngineInput]
+  ///
+  /// A client sending a [BlockingCommand] on empty lists is parked without stalling the loop:
+  /// it is served once an element is pushed to one of its lists or when its timeout is reached.
+  /// Its following inputs are kept aside until then so that outputs are sent in order.
+  ///
+  /// Expired eggs are swept from the [Nest]s every [EXPIRATION_SWEEP_INTERVAL],
+  /// even when no input is received.
   pub async fn run(&mut self) -> Result<()> {
     log::info!("Engine is ready to process commands");
+    let mut last_sweep = Instant::now();
     loop {
       let inputs = self
         .inputs
@@ -105,35 +252,578 @@ impl Engine {
         .ok_or("Sparrow engine is not initialized")?;
 
       log::trace!("Waiting for engine input");
-      let input = inputs.recv().await?;
-      log::trace!("Received input");
+      let next_sweep = last_sweep + EXPIRATION_SWEEP_INTERVAL;
+      let wake_up = self
+        .blocked
+        .iter()
+        .filter_map(|client| client.deadline)
+        .fold(next_sweep, Instant::min);
+      let timeout_duration = wake_up.saturating_duration_since(Instant::now());
+      if let Ok(input) = timeout(timeout_duration, inputs.recv()).await {
+        let input = input?;
+        log::trace!("Received input");
+        self.receive(input).await?;
+      }
+
+      self.serve_blocked().await?;
+      self.unblock_timed_out().await?;
+
+      if last_sweep.elapsed() >= EXPIRATION_SWEEP_INTERVAL {
+        let now = Utc::now();
+        let count: usize = self.nests.iter_mut().map(|nest| nest.sweep(&now)).sum();
+        if count > 0 {
+          log::debug!("Swept {} expired eggs", count);
+        }
+        last_sweep = Instant::now();
+      }
+
+      if let Some(aof) = self.aof.as_mut() {
+        aof.fsync_if_due().await?;
+      }
+    }
+  }
+
+  /// Handle an [EngineInput].
+  ///
+  /// The input is kept aside if its client is blocked, otherwise it is processed right away.
+  async fn receive(&mut self, input: EngineInput) -> Result<()> {
+    let EngineInput {
+      session,
+      data,
+      sender,
+    } = input;
+    if let Some(client) = self
+      .blocked
+      .iter_mut()
+      .find(|client| client.session == session)
+    {
+      log::trace!("Client {} is blocked, input is kept aside", session.id());
+      client.pending.push_back(data);
+      return Ok(());
+    }
+    self
+      .process_inputs(session, sender, VecDeque::from([data]))
+      .await
+  }
 
+  /// Process the inputs of a client in order and send their outputs.
+  ///
+  /// Processing stops at the first input blocking the client, the remaining ones are kept aside.
+  async fn process_inputs(
+    &mut self,
+    session: Session,
+    sender: Sender<Data>,
+    mut inputs: VecDeque<Data>,
+  ) -> Result<()> {
+    while let Some(data) = inputs.pop_front() {
       log::trace!("Processing input");
-      log::info!("{}[{}] {:?}", BACKSPACE_CHARACTER, input.id(), input.data());
-      let output = match parse_command(input.data()) {
-        Ok(command) => command.execute(&mut self.nest),
-        Err(err) => Data::Error(format!("{}", err)),
-      };
-      log::info!("{}[{}] {:?}", BACKSPACE_CHARACTER, input.id(), output);
-      log::trace!("Input processed");
+      log::info!("{}[{}] {:?}", BACKSPACE_CHARACTER, session.id(), data);
+      match self.process(&session, &data).await? {
+        Outcome::Reply(output) => reply(session.id(), &sender, output).await,
+        Outcome::Blocked(command) => {
+          log::debug!("Client {} is blocked by {}", session.id(), command);
+          self.blocked.push(BlockedClient {
+            db: session.db(),
+            session,
+            data,
+            deadline: command
+              .timeout()
+              .and_then(|timeout| Instant::now().checked_add(timeout)),
+            command,
+            sender,
+            pending: inputs,
+          });
+          return Ok(());
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Parse and execute a command for a client [Session].
+  ///
+  /// While the client is in a transaction, commands are queued instead of being executed,
+  /// and a command that cannot be parsed aborts the transaction.
+  /// Transaction commands are executed right away, except `UNWATCH` which is queued.
+  async fn process(&mut self, session: &Session, data: &Data) -> Result<Outcome> {
+    let command = match parse_command(data) {
+      Ok(command) => command,
+      Err(err) => {
+        session.abort_transaction();
+        return Ok(Outcome::Reply(Data::Error(format!("{}", err))));
+      }
+    };
+    match command {
+      ParsedCommand::Transaction(command) if command != TransactionCommand::Unwatch => {
+        let output = self.execute_transaction_command(session, command).await?;
+        Ok(Outcome::Reply(output))
+      }
+      command if session.is_in_transaction() => {
+        session.queue(data.clone(), command);
+        Ok(Outcome::Reply(Data::SimpleString("QUEUED".to_string())))
+      }
+      command => self.execute(session, command, data).await,
+    }
+  }
+
+  /// Execute a command on the database selected by the client [Session].
+  ///
+  /// Successful mutating commands are appended to the [AppendOnlyFile] if it is enabled.
+  async fn execute(
+    &mut self,
+    session: &Session,
+    command: ParsedCommand,
+    data: &Data,
+  ) -> Result<Outcome> {
+    match command {
+      ParsedCommand::Engine(command) => self.execute_engine_command(session, command, data).await,
+      ParsedCommand::Nest(command) => {
+        let db = session.db();
+        let output = command.execute(&mut self.nests[db]);
+        self.executed(command.as_ref(), data, &output, db).await?;
+        Ok(Outcome::Reply(output))
+      }
+      ParsedCommand::Transaction(TransactionCommand::Unwatch) => {
+        session.unwatch();
+        Ok(Outcome::Reply(Data::SimpleString("OK".to_string())))
+      }
+      // Other transaction commands are executed as soon as they are received, never queued
+      ParsedCommand::Transaction(command) => Ok(Outcome::Reply(Data::Error(format!(
+        "{} is not allowed in a transaction",
+        command
+      )))),
+    }
+  }
+
+  /// Record the side effects of an executed command.
+  ///
+  /// Unless it failed, the command is appended to the [AppendOnlyFile] and the lists
+  /// it pushed elements to are marked as ready for blocked clients.
+  /// The versions of the keys are changed if the command modified them,
+  /// i.e. if it is appended to the [AppendOnlyFile].
+  async fn executed(
+    &mut self,
+    command: &dyn Command,
+    data: &Data,
+    output: &Data,
+    db: usize,
+  ) -> Result<()> {
+    let aof_data = match output {
+      Data::Error(_) => None,
+      output => command.to_aof(data, output, &self.nests[db]),
+    };
+    self.nests[db].commit(aof_data.is_some());
+    if matches!(output, Data::Error(_)) {
+      return Ok(());
+    }
+    if let Some(aof_data) = aof_data {
+      self.append_aof(&aof_data, db).await?;
+    }
+    if !self.blocked.is_empty() {
+      let keys = command.pushed_keys().into_iter();
+      self
+        .ready_keys
+        .extend(keys.map(|key| (db, key.to_string())));
+    }
+    Ok(())
+  }
+
+  /// Append a command executed on a given database to the [AppendOnlyFile] if it is enabled.
+  ///
+  /// A `SELECT` command is appended first if the previous command ran on another database.
+  async fn append_aof(&mut self, data: &Data, db: usize) -> Result<()> {
+    let aof = match self.aof.as_mut() {
+      Some(aof) => aof,
+      None => return Ok(()),
+    };
+    if self.aof_transaction == AofTransaction::Pending {
+      aof.append(&command_data(vec![b"MULTI".to_vec()])).await?;
+      self.aof_transaction = AofTransaction::Open;
+    }
+    if db != self.aof_db {
+      let select = command_data(vec![b"SELECT".to_vec(), db.to_string().into_bytes()]);
+      aof.append(&select).await?;
+      self.aof_db = db;
+    }
+    aof.append(data).await
+  }
+
+  /// Try to execute a [BlockingCommand] on a database, on a single list if a key is given.
+  ///
+  /// Return [None] if no element is available.
+  async fn try_unblock(
+    &mut self,
+    command: &BlockingCommand,
+    data: &Data,
+    db: usize,
+    key: Option<&str>,
+  ) -> Result<Option<Data>> {
+    let nest = &mut self.nests[db];
+    let output = match key {
+      Some(key) => command.try_execute_key(nest, key),
+      None => command.try_execute(nest),
+    };
+    match &output {
+      Some(output) => self.executed(command, data, output, db).await?,
+      None => self.nests[db].commit(false),
+    }
+    Ok(output)
+  }
+
+  /// Serve the clients blocked on the lists that received elements.
+  ///
+  /// Clients are served in the order they were blocked, as long as the list has elements.
+  /// The inputs kept aside for a served client are then processed.
+  async fn serve_blocked(&mut self) -> Result<()> {
+    while let Some((db, key)) = self.ready_keys.pop_front() {
+      let mut index = 0;
+      while index < self.blocked.len() {
+        let client = &self.blocked[index];
+        if client.db != db || !client.command.keys().contains(&key) {
+          index += 1;
+          continue;
+        }
+        let client = self.blocked.remove(index);
+        if client.sender.is_closed() {
+          continue;
+        }
+        match self
+          .try_unblock(&client.command, &client.data, db, Some(&key))
+          .await?
+        {
+          Some(output) => {
+            reply(client.session.id(), &client.sender, output).await;
+            self
+              .process_inputs(client.session, client.sender, client.pending)
+              .await?;
+          }
+          None => {
+            self.blocked.insert(index, client);
+            break;
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Send the timeout reply to the blocked clients whose timeout is reached.
+  ///
+  /// Clients that disconnected meanwhile are dropped.
+  async fn unblock_timed_out(&mut self) -> Result<()> {
+    let now = Instant::now();
+    let mut index = 0;
+    while index < self.blocked.len() {
+      let client = &self.blocked[index];
+      if client.sender.is_closed() {
+        self.blocked.remove(index);
+      } else if client.deadline.is_some_and(|deadline| deadline <= now) {
+        let client = self.blocked.remove(index);
+        let output = client.command.timeout_output();
+        reply(client.session.id(), &client.sender, output).await;
+        self
+          .process_inputs(client.session, client.sender, client.pending)
+          .await?;
+      } else {
+        index += 1;
+      }
+    }
+    Ok(())
+  }
+
+  /// Execute an [EngineCommand] for a client [Session].
+  async fn execute_engine_command(
+    &mut self,
+    session: &Session,
+    command: EngineCommand,
+    data: &Data,
+  ) -> Result<Outcome> {
+    Ok(match command {
+      EngineCommand::Save => Outcome::Reply(self.save()),
+      EngineCommand::BgSave => Outcome::Reply(self.bgsave()),
+      EngineCommand::Block(command) => {
+        match self.try_unblock(&command, data, session.db(), None).await? {
+          Some(output) => Outcome::Reply(output),
+          None => Outcome::Blocked(command),
+        }
+      }
+      EngineCommand::Database(command) => Outcome::Reply(
+        self
+          .execute_database_command(session, &command, data)
+          .await?,
+      ),
+    })
+  }
 
-      log::trace!("Sending output");
-      input.sender().send(output).await?;
-      log::trace!("Output sent");
+  /// Execute a [TransactionCommand] for a client [Session].
+  async fn execute_transaction_command(
+    &mut self,
+    session: &Session,
+    command: TransactionCommand,
+  ) -> Result<Data> {
+    Ok(match command {
+      TransactionCommand::Multi => match session.begin_transaction() {
+        true => Data::SimpleString("OK".to_string()),
+        false => Data::Error("MULTI calls can not be nested".to_string()),
+      },
+      TransactionCommand::Exec => self.exec(session).await?,
+      TransactionCommand::Discard => match session.end_transaction() {
+        Some(_) => {
+          session.unwatch();
+          Data::SimpleString("OK".to_string())
+        }
+        None => Data::Error("DISCARD without MULTI".to_string()),
+      },
+      TransactionCommand::Watch(_) if session.is_in_transaction() => {
+        Data::Error("WATCH inside MULTI is not allowed".to_string())
+      }
+      TransactionCommand::Watch(keys) => {
+        let db = session.db();
+        for key in keys {
+          let nest = &mut self.nests[db];
+          let version = nest.version(&key);
+          let watcher = nest.watcher();
+          session.watch(WatchedKey {
+            db,
+            key,
+            version,
+            watcher,
+          });
+        }
+        Data::SimpleString("OK".to_string())
+      }
+      TransactionCommand::Unwatch => {
+        session.unwatch();
+        Data::SimpleString("OK".to_string())
+      }
+    })
+  }
+
+  /// Execute the commands queued by a client back-to-back and return their outputs.
+  ///
+  /// Nothing is executed if a command could not be queued, nor if a watched key changed
+  /// since it was watched, in which case [Data::NullArray] is returned.
+  /// Blocking commands do not block and return their timeout reply if no element is available.
+  async fn exec(&mut self, session: &Session) -> Result<Data> {
+    let transaction = match session.end_transaction() {
+      Some(transaction) => transaction,
+      None => return Ok(Data::Error("EXEC without MULTI".to_string())),
+    };
+    let watched = session.unwatch();
+    if transaction.is_aborted() {
+      return Ok(Data::Error(
+        "Transaction discarded because of previous errors".to_string(),
+      ));
+    }
+    if watched
+      .iter()
+      .any(|watched_key| self.has_changed(watched_key))
+    {
+      return Ok(Data::NullArray);
+    }
+    self.aof_transaction = AofTransaction::Pending;
+    let mut outputs = Vec::new();
+    for (data, command) in transaction.into_commands() {
+      outputs.push(match self.execute(session, command, &data).await? {
+        Outcome::Reply(output) => output,
+        Outcome::Blocked(command) => command.timeout_output(),
+      });
+    }
+    if self.aof_transaction == AofTransaction::Open {
+      if let Some(aof) = self.aof.as_mut() {
+        aof.append(&command_data(vec![b"EXEC".to_vec()])).await?;
+      }
+    }
+    self.aof_transaction = AofTransaction::Idle;
+    Ok(Data::Array(outputs))
+  }
+
+  /// Return whether a watched key changed since it was watched, i.e. its version is different.
+  ///
+  /// Creating, removing or expiring the key counts as a change.
+  fn has_changed(&mut self, watched_key: &WatchedKey) -> bool {
+    self.nests[watched_key.db].version(&watched_key.key) != watched_key.version
+  }
+
+  /// Execute a [DatabaseCommand] for a client [Session].
+  ///
+  /// Successful commands other than `SELECT` are appended to the [AppendOnlyFile].
+  /// Lists that became available to blocked clients by moving or swapping are marked as ready.
+  async fn execute_database_command(
+    &mut self,
+    session: &Session,
+    command: &DatabaseCommand,
+    data: &Data,
+  ) -> Result<Data> {
+    let mut db = session.db();
+    let output = command.execute(&mut self.nests, &mut db);
+    if matches!(output, Data::Error(_)) {
+      return Ok(output);
+    }
+    match command {
+      DatabaseCommand::Select(_) => session.select(db),
+      _ => self.append_aof(data, db).await?,
+    }
+    if self.blocked.is_empty() {
+      return Ok(output);
+    }
+    match command {
+      DatabaseCommand::Move { key, db } if output == Data::Integer(1) => {
+        self.ready_keys.push_back((*db, key.clone()));
+      }
+      DatabaseCommand::SwapDb(first, second) => {
+        for client in &self.blocked {
+          if client.db == *first || client.db == *second {
+            let keys = client.command.keys().iter();
+            self
+              .ready_keys
+              .extend(keys.map(|key| (client.db, key.clone())));
+          }
+        }
+      }
+      _ => {}
     }
+    Ok(output)
+  }
+
+  /// Write a snapshot of the [Nest]s, blocking the engine until it is done.
+  fn save(&mut self) -> Data {
+    if self.is_bgsave_running() {
+      return Data::Error("Background save already in progress".to_string());
+    }
+    let filepath = match self.snapshot_filepath.as_ref() {
+      Some(filepath) => filepath,
+      None => return Data::Error("Snapshot file is not configured".to_string()),
+    };
+    match persistence::save_snapshot(&self.nests, filepath) {
+      Ok(count) => {
+        log::info!("Saved {} eggs to {}", count, filepath);
+        Data::SimpleString("OK".to_string())
+      }
+      Err(err) => Data::Error(format!("Cannot save snapshot: {}", err)),
+    }
+  }
+
+  /// Write a snapshot of the [Nest]s in a background thread.
+  ///
+  /// The [Nest]s are copied first so that the snapshot is consistent
+  /// while the engine keeps serving commands.
+  fn bgsave(&mut self) -> Data {
+    if self.is_bgsave_running() {
+      return Data::Error("Background save already in progress".to_string());
+    }
+    let filepath = match self.snapshot_filepath.as_ref() {
+      Some(filepath) => filepath.clone(),
+      None => return Data::Error("Snapshot file is not configured".to_string()),
+    };
+    let nests = self.nests.clone();
+    self.bgsave = Some(thread::spawn(move || {
+      match persistence::save_snapshot(&nests, &filepath) {
+        Ok(count) => log::info!("Background saved {} eggs to {}", count, filepath),
+        Err(err) => log::error!("Cannot save snapshot in background: {}", err),
+      }
+    }));
+    Data::SimpleString("Background saving started".to_string())
+  }
+
+  /// Return whether a background save is running.
+  fn is_bgsave_running(&self) -> bool {
+    self
+      .bgsave
+      .as_ref()
+      .is_some_and(|bgsave| !bgsave.is_finished())
+  }
+}
+
+/// Commands read from the [AppendOnlyFile] replayed on numbered [Nest]s.
+struct Replay<'a> {
+  /// [Nest]s indexed by database.
+  nests: &'a mut [Nest],
+  /// Index of the database selected by the previous commands.
+  db: usize,
+  /// Commands of the transaction being read, applied once its `EXEC` is read.
+  transaction: Option<Vec<(Data, ParsedCommand)>>,
+}
+
+impl Replay<'_> {
+  /// Replay a command, or queue it if it is part of a transaction.
+  fn replay(&mut self, data: Data) {
+    let command = match parse_command(&data) {
+      Ok(command) => command,
+      Err(err) => return log::warn!("Cannot replay command {:?}: {}", data, err),
+    };
+    match command {
+      ParsedCommand::Transaction(TransactionCommand::Multi) => self.transaction = Some(Vec::new()),
+      ParsedCommand::Transaction(TransactionCommand::Exec) => {
+        for (data, command) in self.transaction.take().unwrap_or_default() {
+          self.execute(&data, command);
+        }
+      }
+      ParsedCommand::Transaction(TransactionCommand::Discard) => self.transaction = None,
+      command => match self.transaction.as_mut() {
+        Some(transaction) => transaction.push((data, command)),
+        None => self.execute(&data, command),
+      },
+    }
+  }
+
+  /// Execute a replayed command.
+  fn execute(&mut self, data: &Data, command: ParsedCommand) {
+    let output = match command {
+      ParsedCommand::Engine(EngineCommand::Database(command)) => {
+        command.execute(self.nests, &mut self.db)
+      }
+      ParsedCommand::Nest(command) => {
+        let output = command.execute(&mut self.nests[self.db]);
+        self.nests[self.db].commit(true);
+        output
+      }
+      command => {
+        return log::warn!(
+          "Cannot replay command {}: not a replayable command",
+          command
+        )
+      }
+    };
+    if let Data::Error(err) = output {
+      log::warn!("Replayed command {:?} failed: {}", data, err);
+    }
+  }
+}
+
+/// Send an output to a client.
+///
+/// The client may have disconnected in the meantime, in which case the output is dropped.
+async fn reply(id: &str, sender: &Sender<Data>, output: Data) {
+  log::info!("{}[{}] {:?}", BACKSPACE_CHARACTER, id, output);
+  log::trace!("Sending output");
+  if sender.send(output).await.is_err() {
+    log::debug!("Client {} disconnected before receiving its output", id);
   }
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::core::{Engine, EngineInput};
+  use crate::core::engine::Outcome;
+  use crate::core::{Engine, EngineInput, Session, Value};
+  use crate::persistence::{self, AppendOnlyFile, FsyncPolicy};
   use async_std::channel::unbounded;
   use async_std::task;
   use rstest::*;
   use sparrow_resp::Data;
 
   const TEST_KEY: &str = "key";
-  const TEST_VALUE: &str = "value";
+  const TEST_VALUE: &str = "some value";
+
+  /// Return the data encoding a command.
+  fn command(inputs: &[&str]) -> Data {
+    Data::Array(
+      inputs
+        .iter()
+        .map(|input| Data::BulkString(input.as_bytes().to_vec()))
+        .collect(),
+    )
+  }
 
   #[fixture]
   fn engine() -> Engine {
@@ -157,13 +847,18 @@ mod tests {
     task::spawn(async move {
       engine.run().await.unwrap();
     });
+    let session = Session::new("1");
 
     // Send input insert to engine
     // Result should be None because there is no egg for this value
-    let data = Data::BulkString(format!("SET {} {}", TEST_KEY, TEST_VALUE));
+    let data = Data::Array(vec![
+      Data::BulkString("SET".into()),
+      Data::BulkString(TEST_KEY.into()),
+      Data::BulkString(TEST_VALUE.into()),
+    ]);
     let (sender, receiver) = unbounded();
     engine_sender
-      .send(EngineInput::new("1".to_string(), data, sender.clone()))
+      .send(EngineInput::new(session.clone(), data, sender.clone()))
       .await
       .unwrap();
     let output = receiver.recv().await.unwrap();
@@ -171,12 +866,641 @@ mod tests {
 
     // Send input get to engine
     // Result should be the previously inserted egg
-    let data = Data::BulkString(format!("GET {}", TEST_KEY));
+    let data = Data::Array(vec![
+      Data::BulkString("GET".into()),
+      Data::BulkString(TEST_KEY.into()),
+    ]);
+    engine_sender
+      .send(EngineInput::new(session.clone(), data, sender))
+      .await
+      .unwrap();
+    let output = receiver.recv().await.unwrap();
+    assert_eq!(output, Data::BulkString(TEST_VALUE.into()));
+  }
+
+  #[async_std::test]
+  async fn test_engine_aof() {
+    let filepath = std::env::temp_dir().join("sparrow_test_engine_aof.aof");
+    let filepath = filepath.to_str().unwrap();
+    let _ = async_std::fs::remove_file(filepath).await;
+
+    // Mutating commands are appended to the file
+    let session = Session::new("1");
+    let mut engine = Engine::new();
+    engine
+      .enable_aof(filepath, FsyncPolicy::Always)
+      .await
+      .unwrap();
+    for inputs in [
+      vec!["SET", TEST_KEY, TEST_VALUE],
+      vec!["GET", TEST_KEY],
+      vec!["SET", "other", TEST_VALUE],
+      vec!["REM", "other"],
+      vec!["UNKNOWN"],
+    ] {
+      let data = Data::Array(
+        inputs
+          .into_iter()
+          .map(|input| Data::BulkString(input.into()))
+          .collect(),
+      );
+      engine.process(&session, &data).await.unwrap();
+    }
+
+    // State is restored when replaying the file
+    let mut engine = Engine::new();
+    engine
+      .enable_aof(filepath, FsyncPolicy::Never)
+      .await
+      .unwrap();
+    assert_eq!(
+      engine.nests[0].get(TEST_KEY).unwrap().value(),
+      &Value::String(TEST_VALUE.into())
+    );
+    assert!(engine.nests[0].get("other").is_none());
+
+    async_std::fs::remove_file(filepath).await.unwrap();
+  }
+
+  #[async_std::test]
+  async fn test_engine_aof_databases() {
+    let filepath = std::env::temp_dir().join("sparrow_test_engine_aof_databases.aof");
+    let filepath = filepath.to_str().unwrap();
+    let _ = async_std::fs::remove_file(filepath).await;
+
+    // Commands are replayed on the database of the client that sent them
+    let session = Session::new("1");
+    let other = Session::new("2");
+    let mut engine = Engine::with_databases(4);
+    engine
+      .enable_aof(filepath, FsyncPolicy::Always)
+      .await
+      .unwrap();
+    for (session, inputs) in [
+      (&session, &["SELECT", "2"][..]),
+      (&session, &["SET", TEST_KEY, "2"]),
+      (&other, &["SET", TEST_KEY, "0"]),
+      (&other, &["SET", "moved", "0"]),
+      (&other, &["MOVE", "moved", "3"]),
+      (&session, &["SET", "other", "2"]),
+      (&session, &["SWAPDB", "1", "2"]),
+    ] {
+      engine.process(session, &command(inputs)).await.unwrap();
+    }
+
+    let mut engine = Engine::with_databases(4);
+    engine
+      .enable_aof(filepath, FsyncPolicy::Never)
+      .await
+      .unwrap();
+    for (db, key, value) in [
+      (0, TEST_KEY, "0"),
+      (1, TEST_KEY, "2"),
+      (1, "other", "2"),
+      (3, "moved", "0"),
+    ] {
+      assert_eq!(
+        engine.nests[db].get(key).unwrap().value(),
+        &Value::String(value.into())
+      );
+    }
+    assert_eq!(engine.nests[2].len(), 0);
+
+    // Following commands are appended after the last replayed selection
+    engine
+      .process(&Session::new("3"), &command(&["SET", "last", "0"]))
+      .await
+      .unwrap();
+    let mut engine = Engine::with_databases(4);
+    engine
+      .enable_aof(filepath, FsyncPolicy::Never)
+      .await
+      .unwrap();
+    assert!(engine.nests[0].get("last").is_some());
+
+    async_std::fs::remove_file(filepath).await.unwrap();
+  }
+
+  #[async_std::test]
+  async fn test_engine_databases() {
+    let session = Session::new("1");
+    let other = Session::new("2");
+    let mut engine = Engine::with_databases(2);
+    for (session, inputs, expected) in [
+      (
+        &session,
+        &["SELECT", "1"][..],
+        Data::SimpleString("OK".to_string()),
+      ),
+      (
+        &session,
+        &["SET", TEST_KEY, TEST_VALUE],
+        Data::SimpleString("OK".to_string()),
+      ),
+      (&other, &["GET", TEST_KEY], Data::Null),
+      (
+        &session,
+        &["GET", TEST_KEY],
+        Data::BulkString(TEST_VALUE.into()),
+      ),
+      (
+        &session,
+        &["SELECT", "2"],
+        Data::Error("DB index is out of range".to_string()),
+      ),
+      (&session, &["MOVE", TEST_KEY, "0"], Data::Integer(1)),
+      (
+        &other,
+        &["GET", TEST_KEY],
+        Data::BulkString(TEST_VALUE.into()),
+      ),
+      (
+        &session,
+        &["SWAPDB", "0", "1"],
+        Data::SimpleString("OK".to_string()),
+      ),
+      (&session, &["DBSIZE"], Data::Integer(1)),
+      (&other, &["FLUSHDB"], Data::SimpleString("OK".to_string())),
+      (&session, &["DBSIZE"], Data::Integer(1)),
+      (&other, &["FLUSHALL"], Data::SimpleString("OK".to_string())),
+      (&session, &["DBSIZE"], Data::Integer(0)),
+    ] {
+      assert_eq!(
+        engine.process(session, &command(inputs)).await.unwrap(),
+        Outcome::Reply(expected)
+      );
+    }
+  }
+
+  #[async_std::test]
+  async fn test_engine_transaction() {
+    let session = Session::new("1");
+    let mut engine = Engine::new();
+    let ok = || Data::SimpleString("OK".to_string());
+    let queued = || Data::SimpleString("QUEUED".to_string());
+    for (inputs, expected) in [
+      (&["EXEC"][..], Data::Error("EXEC without MULTI".to_string())),
+      (
+        &["DISCARD"],
+        Data::Error("DISCARD without MULTI".to_string()),
+      ),
+      (&["MULTI"], ok()),
+      (
+        &["MULTI"],
+        Data::Error("MULTI calls can not be nested".to_string()),
+      ),
+      (&["SET", TEST_KEY, TEST_VALUE], queued()),
+      (&["INCR", TEST_KEY], queued()),
+      (&["BLPOP", "list", "0"], queued()),
+      (&["SELECT", "1"], queued()),
+      (&["DBSIZE"], queued()),
+    ] {
+      assert_eq!(
+        engine.process(&session, &command(inputs)).await.unwrap(),
+        Outcome::Reply(expected)
+      );
+    }
+
+    // Queued commands are executed back-to-back, even after one of them fails
+    let outputs = vec![
+      ok(),
+      Data::Error("Value is not an integer or out of range".to_string()),
+      Data::NullArray,
+      ok(),
+      Data::Integer(0),
+    ];
+    assert_eq!(
+      engine.process(&session, &command(&["EXEC"])).await.unwrap(),
+      Outcome::Reply(Data::Array(outputs))
+    );
+    assert_eq!(session.db(), 1);
+    assert!(engine.nests[0].get(TEST_KEY).is_some());
+
+    // Discarded commands are not executed
+    for inputs in [&["MULTI"][..], &["SET", TEST_KEY, TEST_VALUE], &["DISCARD"]] {
+      engine.process(&session, &command(inputs)).await.unwrap();
+    }
+    assert!(engine.nests[1].get(TEST_KEY).is_none());
+
+    // A command that cannot be parsed aborts the transaction
+    for (inputs, expected) in [
+      (&["MULTI"][..], ok()),
+      (&["SET", TEST_KEY, TEST_VALUE], queued()),
+      (
+        &["UNKNOWN"],
+        Data::Error("Command not found: UNKNOWN".to_string()),
+      ),
+      (
+        &["EXEC"],
+        Data::Error("Transaction discarded because of previous errors".to_string()),
+      ),
+    ] {
+      assert_eq!(
+        engine.process(&session, &command(inputs)).await.unwrap(),
+        Outcome::Reply(expected)
+      );
+    }
+    assert!(engine.nests[1].get(TEST_KEY).is_none());
+  }
+
+  #[async_std::test]
+  async fn test_engine_watch() {
+    let session = Session::new("1");
+    let other = Session::new("2");
+    let mut engine = Engine::new();
+    let ok = || Data::SimpleString("OK".to_string());
+    let transaction = [&["MULTI"][..], &["INCR", "counter"], &["EXEC"]];
+
+    // Unchanged watched keys do not prevent the transaction from being executed,
+    // whereas a watched key modified, created or removed by another client does
+    for (changes, expected) in [
+      (&[][..], Data::Array(vec![Data::Integer(1)])),
+      (&[&["SET", TEST_KEY, TEST_VALUE][..]], Data::NullArray),
+      (&[&["APPEND", TEST_KEY, TEST_VALUE]], Data::NullArray),
+      (&[&["EXPIRE", TEST_KEY, "100"]], Data::NullArray),
+      (
+        &[&["GET", TEST_KEY], &["EXISTS", TEST_KEY]],
+        Data::Array(vec![Data::Integer(2)]),
+      ),
+      // Commands that fail or change nothing do not modify the key
+      (
+        &[
+          &["ZADD", TEST_KEY, "1", "member"],
+          &["SETRANGE", TEST_KEY, "0", ""],
+          &["REM", "other"],
+        ],
+        Data::Array(vec![Data::Integer(3)]),
+      ),
+      // Missing keys are not modified by writes to other keys
+      (
+        &[&["SET", "unrelated", TEST_VALUE], &["REM", "unrelated"]],
+        Data::Array(vec![Data::Integer(4)]),
+      ),
+      (
+        &[&["SET", "other", TEST_VALUE], &["REM", "other"]],
+        Data::NullArray,
+      ),
+      (&[&["FLUSHDB"]], Data::NullArray),
+      (
+        &[&["SELECT", "1"], &["SET", TEST_KEY, TEST_VALUE]],
+        Data::Array(vec![Data::Integer(1)]),
+      ),
+    ] {
+      assert_eq!(
+        engine
+          .process(&session, &command(&["WATCH", TEST_KEY, "other"]))
+          .await
+          .unwrap(),
+        Outcome::Reply(ok())
+      );
+      for inputs in changes {
+        engine.process(&other, &command(inputs)).await.unwrap();
+      }
+      let mut outcome = None;
+      for inputs in transaction {
+        outcome = Some(engine.process(&session, &command(inputs)).await.unwrap());
+      }
+      assert_eq!(outcome, Some(Outcome::Reply(expected)));
+    }
+
+    // EXEC and DISCARD forget the watched keys, as does UNWATCH, even when queued
+    engine
+      .process(&other, &command(&["SELECT", "0"]))
+      .await
+      .unwrap();
+    for unwatch in [
+      &[&["MULTI"][..], &["EXEC"]][..],
+      &[&["MULTI"], &["DISCARD"]],
+      &[&["UNWATCH"]],
+      &[&["MULTI"], &["UNWATCH"], &["EXEC"]],
+    ] {
+      engine
+        .process(&session, &command(&["WATCH", TEST_KEY]))
+        .await
+        .unwrap();
+      for inputs in unwatch {
+        engine.process(&session, &command(inputs)).await.unwrap();
+      }
+      engine
+        .process(&other, &command(&["SET", TEST_KEY, TEST_VALUE]))
+        .await
+        .unwrap();
+      let mut outcome = None;
+      for inputs in transaction {
+        outcome = Some(engine.process(&session, &command(inputs)).await.unwrap());
+      }
+      assert!(matches!(outcome, Some(Outcome::Reply(Data::Array(_)))));
+    }
+
+    // WATCH is not allowed in a transaction, but does not abort it
+    for (inputs, expected) in [
+      (&["MULTI"][..], ok()),
+      (
+        &["WATCH", TEST_KEY],
+        Data::Error("WATCH inside MULTI is not allowed".to_string()),
+      ),
+      (&["EXEC"], Data::Array(vec![])),
+    ] {
+      assert_eq!(
+        engine.process(&session, &command(inputs)).await.unwrap(),
+        Outcome::Reply(expected)
+      );
+    }
+  }
+
+  #[async_std::test]
+  async fn test_engine_aof_transaction() {
+    let filepath = std::env::temp_dir().join("sparrow_test_engine_aof_transaction.aof");
+    let filepath = filepath.to_str().unwrap();
+    let _ = async_std::fs::remove_file(filepath).await;
+
+    // Transactions are wrapped between MULTI and EXEC
+    let session = Session::new("1");
+    let mut engine = Engine::new();
+    engine
+      .enable_aof(filepath, FsyncPolicy::Always)
+      .await
+      .unwrap();
+    for inputs in [
+      &["MULTI"][..],
+      &["GET", TEST_KEY],
+      &["SET", TEST_KEY, TEST_VALUE],
+      &["SET", "other", TEST_VALUE],
+      &["EXEC"],
+      &["MULTI"],
+      &["GET", TEST_KEY],
+      &["EXEC"],
+    ] {
+      engine.process(&session, &command(inputs)).await.unwrap();
+    }
+    let mut commands = Vec::new();
+    persistence::replay(filepath, |data| commands.push(data))
+      .await
+      .unwrap();
+    assert_eq!(
+      commands,
+      vec![
+        command(&["MULTI"]),
+        command(&["SET", TEST_KEY, TEST_VALUE]),
+        command(&["SET", "other", TEST_VALUE]),
+        command(&["EXEC"]),
+      ]
+    );
+
+    // A transaction cut short is not replayed, nor are the following commands part of it
+    let mut aof = AppendOnlyFile::open(filepath, FsyncPolicy::Always)
+      .await
+      .unwrap();
+    for inputs in [&["MULTI"][..], &["REM", TEST_KEY]] {
+      aof.append(&command(inputs)).await.unwrap();
+    }
+    drop(aof);
+    let mut engine = Engine::new();
+    engine
+      .enable_aof(filepath, FsyncPolicy::Always)
+      .await
+      .unwrap();
+    assert!(engine.nests[0].get(TEST_KEY).is_some());
+    engine
+      .process(&session, &command(&["REM", "other"]))
+      .await
+      .unwrap();
+    let mut engine = Engine::new();
+    engine
+      .enable_aof(filepath, FsyncPolicy::Never)
+      .await
+      .unwrap();
+    assert!(engine.nests[0].get(TEST_KEY).is_some());
+    assert!(engine.nests[0].get("other").is_none());
+
+    async_std::fs::remove_file(filepath).await.unwrap();
+  }
+
+  #[async_std::test]
+  async fn test_engine_snapshot() {
+    let filepath = std::env::temp_dir().join("sparrow_test_engine_snapshot.snapshot");
+    let filepath = filepath.to_str().unwrap();
+    let set = Data::Array(vec![
+      Data::BulkString("SET".into()),
+      Data::BulkString(TEST_KEY.into()),
+      Data::BulkString(TEST_VALUE.into()),
+    ]);
+
+    // Snapshot is not configured
+    let session = Session::new("1");
+    let mut engine = Engine::new();
+    assert_eq!(
+      engine
+        .process(&session, &Data::BulkString("SAVE".into()))
+        .await
+        .unwrap(),
+      Outcome::Reply(Data::Error("Snapshot file is not configured".to_string()))
+    );
+
+    // Blocking save
+    engine.set_snapshot_filepath(filepath);
+    engine.process(&session, &set).await.unwrap();
+    assert_eq!(
+      engine
+        .process(&session, &Data::BulkString("SAVE".into()))
+        .await
+        .unwrap(),
+      Outcome::Reply(Data::SimpleString("OK".to_string()))
+    );
+    let mut restored = Engine::new();
+    restored.set_snapshot_filepath(filepath);
+    restored.load_snapshot().unwrap();
+    assert_eq!(
+      restored.nests[0].get(TEST_KEY).unwrap().value(),
+      &Value::String(TEST_VALUE.into())
+    );
+
+    // Background save
+    engine
+      .process(&session, &Data::BulkString("REM key".into()))
+      .await
+      .unwrap();
+    assert_eq!(
+      engine
+        .process(&session, &Data::BulkString("BGSAVE".into()))
+        .await
+        .unwrap(),
+      Outcome::Reply(Data::SimpleString("Background saving started".to_string()))
+    );
+    engine.bgsave.take().unwrap().join().unwrap();
+    restored.load_snapshot().unwrap();
+    assert!(restored.nests[0].get(TEST_KEY).is_none());
+
+    async_std::fs::remove_file(filepath).await.unwrap();
+  }
+
+  #[rstest]
+  #[async_std::test]
+  async fn test_run_engine_expiration(mut engine: Engine) {
+    let engine_sender = engine.init();
+    task::spawn(async move {
+      engine.run().await.unwrap();
+    });
+    let session = Session::new("1");
+
+    // Insert an egg expiring right away
+    let data = Data::Array(vec![
+      Data::BulkString("SET".into()),
+      Data::BulkString(TEST_KEY.into()),
+      Data::BulkString(TEST_VALUE.into()),
+      Data::BulkString("PX".into()),
+      Data::BulkString("1".into()),
+    ]);
+    let (sender, receiver) = unbounded();
     engine_sender
-      .send(EngineInput::new("1".to_string(), data, sender))
+      .send(EngineInput::new(session.clone(), data, sender.clone()))
       .await
       .unwrap();
     let output = receiver.recv().await.unwrap();
-    assert_eq!(output, Data::BulkString(TEST_VALUE.to_string()));
+    assert_eq!(output, Data::SimpleString("OK".to_string()));
+
+    task::sleep(std::time::Duration::from_millis(10)).await;
+
+    // Egg has expired
+    let data = Data::Array(vec![
+      Data::BulkString("TTL".into()),
+      Data::BulkString(TEST_KEY.into()),
+    ]);
+    engine_sender
+      .send(EngineInput::new(session.clone(), data, sender))
+      .await
+      .unwrap();
+    let output = receiver.recv().await.unwrap();
+    assert_eq!(output, Data::Integer(-2));
+  }
+
+  #[rstest]
+  #[async_std::test]
+  async fn test_run_engine_transaction(mut engine: Engine) {
+    let engine_sender = engine.init();
+    task::spawn(async move {
+      engine.run().await.unwrap();
+    });
+    let session = Session::new("1");
+    let other = Session::new("2");
+
+    // Commands of other clients are executed before the queued ones
+    let (sender, receiver) = unbounded();
+    let (other_sender, other_receiver) = unbounded();
+    for (session, sender, inputs) in [
+      (&session, &sender, &["MULTI"][..]),
+      (&session, &sender, &["INCR", TEST_KEY]),
+      (&other, &other_sender, &["INCR", TEST_KEY]),
+      (&session, &sender, &["INCR", TEST_KEY]),
+      (&session, &sender, &["EXEC"]),
+    ] {
+      let input = EngineInput::new(session.clone(), command(inputs), sender.clone());
+      engine_sender.send(input).await.unwrap();
+    }
+    assert_eq!(other_receiver.recv().await.unwrap(), Data::Integer(1));
+    for expected in [
+      Data::SimpleString("OK".to_string()),
+      Data::SimpleString("QUEUED".to_string()),
+      Data::SimpleString("QUEUED".to_string()),
+      Data::Array(vec![Data::Integer(2), Data::Integer(3)]),
+    ] {
+      assert_eq!(receiver.recv().await.unwrap(), expected);
+    }
+  }
+
+  #[rstest]
+  #[async_std::test]
+  async fn test_run_engine_blocking(mut engine: Engine) {
+    let engine_sender = engine.init();
+    task::spawn(async move {
+      engine.run().await.unwrap();
+    });
+    let session = Session::new("1");
+    let other = Session::new("2");
+
+    // Blocked client keeps its following inputs aside
+    let (blocked_sender, blocked_receiver) = unbounded();
+    for inputs in [
+      &["BLPOP", "missing", TEST_KEY, "0"][..],
+      &["LLEN", TEST_KEY],
+    ] {
+      let input = EngineInput::new(session.clone(), command(inputs), blocked_sender.clone());
+      engine_sender.send(input).await.unwrap();
+    }
+
+    // Other clients are still served
+    let (sender, receiver) = unbounded();
+    let input = EngineInput::new(other.clone(), command(&["LLEN", TEST_KEY]), sender.clone());
+    engine_sender.send(input).await.unwrap();
+    assert_eq!(receiver.recv().await.unwrap(), Data::Integer(0));
+    assert!(blocked_receiver.is_empty());
+
+    // A new client reusing the id of the blocked client is not mistaken for it
+    let input = EngineInput::new(
+      Session::new("1"),
+      command(&["LLEN", TEST_KEY]),
+      sender.clone(),
+    );
+    engine_sender.send(input).await.unwrap();
+    assert_eq!(receiver.recv().await.unwrap(), Data::Integer(0));
+    assert!(blocked_receiver.is_empty());
+
+    // Pushing an element unblocks the client
+    let input = EngineInput::new(
+      other.clone(),
+      command(&["RPUSH", TEST_KEY, "a", "b"]),
+      sender.clone(),
+    );
+    engine_sender.send(input).await.unwrap();
+    assert_eq!(receiver.recv().await.unwrap(), Data::Integer(2));
+    assert_eq!(
+      blocked_receiver.recv().await.unwrap(),
+      Data::Array(vec![
+        Data::BulkString(TEST_KEY.into()),
+        Data::BulkString("a".into())
+      ])
+    );
+    assert_eq!(blocked_receiver.recv().await.unwrap(), Data::Integer(1));
+
+    // Timeout is reached
+    let input = EngineInput::new(
+      other.clone(),
+      command(&["BLMOVE", "missing", TEST_KEY, "LEFT", "LEFT", "0.05"]),
+      sender.clone(),
+    );
+    engine_sender.send(input).await.unwrap();
+    assert_eq!(receiver.recv().await.unwrap(), Data::Null);
+
+    // Clients are only unblocked by pushes to their database
+    for inputs in [&["SELECT", "1"][..], &["BLPOP", TEST_KEY, "0"]] {
+      let input = EngineInput::new(session.clone(), command(inputs), blocked_sender.clone());
+      engine_sender.send(input).await.unwrap();
+    }
+    assert_eq!(
+      blocked_receiver.recv().await.unwrap(),
+      Data::SimpleString("OK".to_string())
+    );
+    for inputs in [
+      &["RPUSH", TEST_KEY, "c"][..],
+      &["SELECT", "1"],
+      &["RPUSH", TEST_KEY, "d"],
+    ] {
+      let input = EngineInput::new(other.clone(), command(inputs), sender.clone());
+      engine_sender.send(input).await.unwrap();
+    }
+    assert_eq!(receiver.recv().await.unwrap(), Data::Integer(2));
+    assert!(blocked_receiver.is_empty());
+    assert_eq!(
+      receiver.recv().await.unwrap(),
+      Data::SimpleString("OK".to_string())
+    );
+    assert_eq!(receiver.recv().await.unwrap(), Data::Integer(1));
+    assert_eq!(
+      blocked_receiver.recv().await.unwrap(),
+      Data::Array(vec![
+        Data::BulkString(TEST_KEY.into()),
+        Data::BulkString("d".into())
+      ])
+    );
   }
 }
@@ -4,5 +4,13 @@ mod commands;
 mod egg;
 mod engine;
 mod nest;
+mod session;
+mod sorted_set;
+mod value;
 
+pub use egg::Egg;
 pub use engine::{Engine, EngineInput};
+pub use nest::Nest;
+pub use session::Session;
+pub use sorted_set::SortedSet;
+pub use value::Value;
@@ -1,13 +1,37 @@
 //! In-memory data storage.
 
-use crate::core::egg::Egg;
-use std::collections::HashMap;
+use crate::core::egg::{next_version, Egg};
+use crate::core::value::Value;
+use chrono::prelude::{DateTime, Utc};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::{Hash, Hasher};
+use std::ops::Bound;
+use std::sync::Arc;
 
 /// Nest is the in-memory data storage of Sparrow.
 ///
 /// It contains an [HashMap] to store multiple [Egg] along with their key.
+/// Keys with an expiration date are also indexed by date so that expired
+/// [Egg] can be swept without scanning the whole map.
+/// Keys are also ordered by a hash that does not depend on the capacity of the map,
+/// so that scans resumed from a cursor are not disturbed by the map growing or shrinking.
+///
+/// Keys removed while the [Nest] is watched keep a version, so that a watched key created
+/// then removed counts as modified.
+#[derive(Clone)]
 pub struct Nest {
   map: HashMap<String, Egg>,
+  expirations: BTreeSet<(DateTime<Utc>, String)>,
+  hashes: BTreeSet<(u64, String)>,
+  /// Version of the missing keys that were not removed while the [Nest] is watched.
+  version: u64,
+  /// Versions of the keys removed while the [Nest] is watched.
+  tombstones: HashMap<String, u64>,
+  /// Shared with every [Watcher] of the [Nest].
+  watchers: Arc<()>,
+  /// Keys of the [Egg] borrowed as mutable since the last [Nest::commit].
+  borrowed: Vec<String>,
 }
 
 impl Nest {
@@ -15,6 +39,12 @@ impl Nest {
   pub fn new() -> Nest {
     Nest {
       map: HashMap::new(),
+      expirations: BTreeSet::new(),
+      hashes: BTreeSet::new(),
+      version: next_version(),
+      tombstones: HashMap::new(),
+      watchers: Arc::new(()),
+      borrowed: Vec::new(),
     }
   }
 }
@@ -25,34 +55,298 @@ impl Default for Nest {
   }
 }
 
+/// Handle held by a client watching keys of a [Nest], see [Nest::watcher].
+///
+/// Clones are equal to each other.
+#[derive(Clone, Debug)]
+pub struct Watcher(Arc<()>);
+
+impl PartialEq for Watcher {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+/// Return the hash ordering a key in scans.
+fn hash(key: &str) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  key.hash(&mut hasher);
+  hasher.finish()
+}
+
 impl Nest {
   /// Set an [Egg] into the `map` field
   ///
+  /// The expiration date of the [Egg] previously associated to the key is discarded.
+  ///
   /// # Arguments
   /// * `egg` - [Egg] to insert
   pub fn set(&mut self, egg: Egg) {
-    self.map.insert(egg.key().clone(), egg);
+    let key = egg.key().clone();
+    let expires_at = egg.expires_at().cloned();
+    match self.map.insert(key.clone(), egg) {
+      Some(previous) => self.unindex(&previous),
+      None => {
+        self.hashes.insert((hash(&key), key.clone()));
+      }
+    }
+    if let Some(expires_at) = expires_at {
+      self.expirations.insert((expires_at, key));
+    }
   }
   /// Get an [Egg] from the `map` field
   ///
+  /// An expired [Egg] is lazily removed and [None] is returned.
+  ///
   /// # Arguments
   /// * `key` - Key value of the [Egg] to get
-  pub fn get(&self, key: &str) -> Option<&Egg> {
+  pub fn get(&mut self, key: &str) -> Option<&Egg> {
+    self.remove_if_expired(key);
     self.map.get(key)
   }
+  /// Get an [Egg] from the `map` field, even if it is expired.
+  ///
+  /// # Arguments
+  /// * `key` - Key value of the [Egg] to get
+  pub fn peek(&self, key: &str) -> Option<&Egg> {
+    self.map.get(key)
+  }
+  /// Get a mutable [Egg] from the `map` field
+  ///
+  /// An expired [Egg] is lazily removed and [None] is returned.
+  ///
+  /// **The expiration date of the returned [Egg] must be updated
+  /// using [Nest::expire] so that the expiration index is kept in sync.**
+  /// Its version is changed by the next [Nest::commit] if it has been modified.
+  ///
+  /// # Arguments
+  /// * `key` - Key value of the [Egg] to get
+  pub fn get_mut(&mut self, key: &str) -> Option<&mut Egg> {
+    self.remove_if_expired(key);
+    let egg = self.map.get_mut(key)?;
+    self.borrowed.push(key.to_string());
+    Some(egg)
+  }
+  /// Get a mutable [Egg] from the `map` field, inserting a new one if there is none.
+  ///
+  /// See [Nest::get_mut].
+  ///
+  /// # Arguments
+  /// * `key` - Key value of the [Egg] to get
+  /// * `default` - Function returning the [Value] of the [Egg] to insert
+  pub fn get_or_insert_with(&mut self, key: &str, default: impl FnOnce() -> Value) -> &mut Egg {
+    self.remove_if_expired(key);
+    if !self.map.contains_key(key) {
+      self.hashes.insert((hash(key), key.to_string()));
+    }
+    self.borrowed.push(key.to_string());
+    self
+      .map
+      .entry(key.to_string())
+      .or_insert_with(|| Egg::new(key, default()))
+  }
   /// Remove an [Egg] from the `map` field
   ///
+  /// Return `false` if there is no [Egg] for the given key, an expired [Egg] counting as none.
+  ///
   /// # Arguments
   /// * `key` - Key value of the [Egg] to pop
-  pub fn rem(&mut self, key: &str) {
-    self.map.remove(key);
+  pub fn rem(&mut self, key: &str) -> bool {
+    self.take(key).is_some()
+  }
+  /// Remove an [Egg] from the `map` field and return it.
+  ///
+  /// Return [None] if there is no [Egg] for the given key, an expired [Egg] counting as none.
+  ///
+  /// # Arguments
+  /// * `key` - Key value of the [Egg] to take
+  pub fn take(&mut self, key: &str) -> Option<Egg> {
+    self.remove_if_expired(key);
+    self.remove(key)
+  }
+  /// Set or clear the expiration date of an [Egg].
+  ///
+  /// Return `false` if there is no [Egg] for the given key.
+  ///
+  /// # Arguments
+  /// * `key` - Key value of the [Egg] to update
+  /// * `expires_at` - New expiration date, [None] to persist the [Egg]
+  pub fn expire(&mut self, key: &str, expires_at: Option<DateTime<Utc>>) -> bool {
+    self.remove_if_expired(key);
+    match self.map.get_mut(key) {
+      Some(egg) => {
+        if let Some(previous) = egg.expires_at() {
+          self.expirations.remove(&(*previous, key.to_string()));
+        }
+        if let Some(expires_at) = expires_at {
+          self.expirations.insert((expires_at, key.to_string()));
+        }
+        egg.set_expires_at(expires_at);
+        true
+      }
+      None => false,
+    }
+  }
+  /// Return the version of a key, which changes every time the key is modified.
+  ///
+  /// A missing key keeps the same version until it is created, or until it is created then
+  /// removed while a [Watcher] of the [Nest] exists.
+  ///
+  /// # Arguments
+  /// * `key` - Key value of the [Egg] to get the version of
+  pub fn version(&mut self, key: &str) -> u64 {
+    self.remove_if_expired(key);
+    match self.map.get(key) {
+      Some(egg) => egg.version(),
+      None => self.tombstones.get(key).copied().unwrap_or(self.version),
+    }
+  }
+  /// Return a [Watcher] of the [Nest], to be held as long as versions of its keys are compared.
+  pub fn watcher(&self) -> Watcher {
+    Watcher(self.watchers.clone())
+  }
+  /// Change the version of the [Egg] borrowed as mutable since the last call, if they were modified.
+  ///
+  /// This method is expected to be called once a command has been executed.
+  ///
+  /// # Arguments
+  /// * `modified` - Whether the command modified the [Nest]
+  pub fn commit(&mut self, modified: bool) {
+    for key in self.borrowed.drain(..) {
+      if let Some(egg) = self.map.get_mut(&key).filter(|_| modified) {
+        egg.touch();
+      }
+    }
+  }
+  /// Return an iterator over every [Egg] of the `map` field, including expired ones
+  /// that have not been swept yet.
+  pub fn iter(&self) -> impl Iterator<Item = &Egg> {
+    self.map.values()
+  }
+  /// Return an iterator over the keys of every [Egg] of the `map` field that is not expired.
+  pub fn keys(&self) -> impl Iterator<Item = &String> {
+    let now = Utc::now();
+    self
+      .map
+      .values()
+      .filter(move |egg| !egg.is_expired(&now))
+      .map(|egg| egg.key())
+  }
+  /// Return the number of [Egg] in the `map` field, including expired ones
+  /// that have not been swept yet.
+  pub fn len(&self) -> usize {
+    self.map.len()
+  }
+  /// Return a batch of [Egg] following a cursor, along with the cursor of the next batch.
+  ///
+  /// Scanning from cursor `0` until the returned cursor is `0` again visits every key
+  /// that exists during the whole scan exactly once, even if other keys are added or removed
+  /// in the meantime. Expired [Egg] are skipped.
+  ///
+  /// The returned cursor is the hash following the last visited one, so it is never `0`
+  /// before the scan is finished.
+  ///
+  /// # Arguments
+  /// * `cursor` - Cursor returned by the previous call, `0` to start a new scan
+  /// * `count` - Number of keys to visit, which may be slightly exceeded
+  pub fn scan(&self, cursor: u64, count: usize) -> (u64, Vec<&Egg>) {
+    let now = Utc::now();
+    let mut last_hash = None;
+    let mut eggs = Vec::new();
+    let start = Bound::Included((cursor, String::new()));
+    for (visited, (hash, key)) in self.hashes.range((start, Bound::Unbounded)).enumerate() {
+      // Keys sharing a hash are visited in the same batch since the cursor is a hash
+      match last_hash {
+        // A higher hash follows the previous one, so adding 1 does not overflow
+        Some(previous) if visited >= count.max(1) && previous != *hash => {
+          return (previous + 1, eggs);
+        }
+        _ => last_hash = Some(*hash),
+      }
+      if let Some(egg) = self.map.get(key).filter(|egg| !egg.is_expired(&now)) {
+        eggs.push(egg);
+      }
+    }
+    (0, eggs)
+  }
+  /// Return a random key, [None] if the `map` field is empty.
+  ///
+  /// Expired [Egg] met along the way are lazily removed.
+  pub fn random_key(&mut self) -> Option<String> {
+    let now = Utc::now();
+    loop {
+      let start = Bound::Included((fastrand::u64(..), String::new()));
+      let (_, key) = self
+        .hashes
+        .range((start, Bound::Unbounded))
+        .next()
+        .or_else(|| self.hashes.iter().next())?
+        .clone();
+      match self.map.get(&key) {
+        Some(egg) if egg.is_expired(&now) => {
+          self.remove(&key);
+        }
+        _ => return Some(key),
+      }
+    }
+  }
+  /// Remove every expired [Egg] and return how many were removed.
+  ///
+  /// # Arguments
+  /// * `now` - Date used to check expiration
+  pub fn sweep(&mut self, now: &DateTime<Utc>) -> usize {
+    let mut count = 0;
+    while let Some((expires_at, key)) = self.expirations.iter().next().cloned() {
+      if expires_at > *now {
+        break;
+      }
+      self.expirations.remove(&(expires_at, key.clone()));
+      self.map.remove(&key);
+      self.hashes.remove(&(hash(&key), key.clone()));
+      self.bury(key);
+      count += 1;
+    }
+    count
+  }
+  /// Remove the [Egg] associated to the given key if it is expired.
+  fn remove_if_expired(&mut self, key: &str) {
+    let now = Utc::now();
+    if self.map.get(key).is_some_and(|egg| egg.is_expired(&now)) {
+      self.remove(key);
+    }
+  }
+  /// Remove the [Egg] associated to the given key, along with its index entries.
+  fn remove(&mut self, key: &str) -> Option<Egg> {
+    let egg = self.map.remove(key)?;
+    self.unindex(&egg);
+    self.hashes.remove(&(hash(key), key.to_string()));
+    self.bury(key.to_string());
+    Some(egg)
+  }
+  /// Give a new version to a removed key if the [Nest] is watched.
+  ///
+  /// The versions of the removed keys are forgotten once no [Watcher] remains.
+  fn bury(&mut self, key: String) {
+    if Arc::strong_count(&self.watchers) > 1 {
+      self.tombstones.insert(key, next_version());
+    } else {
+      self.tombstones.clear();
+    }
+  }
+  /// Remove the expiration index entry of an [Egg].
+  fn unindex(&mut self, egg: &Egg) {
+    if let Some(expires_at) = egg.expires_at() {
+      self.expirations.remove(&(*expires_at, egg.key().clone()));
+    }
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use chrono::Duration;
   use rstest::*;
+  use std::collections::HashSet;
 
   const TEST_KEY: &str = "My key";
   const TEST_VALUE: &str = "This is a test value!";
@@ -69,7 +363,7 @@ mod tests {
 
   #[fixture]
   fn egg() -> Egg {
-    Egg::new(TEST_KEY, TEST_VALUE)
+    Egg::new(TEST_KEY, TEST_VALUE.as_bytes())
   }
 
   #[test]
@@ -102,8 +396,212 @@ mod tests {
     // Egg is in the nest and its value is returned
     assert_eq!(nest.get(egg.key()), Some(&egg));
     // Egg is removed from the nest
-    nest.rem(egg.key());
+    assert!(nest.rem(egg.key()));
     // Egg is not in the nest
     assert_eq!(nest.get(egg.key()), None);
+    assert!(!nest.rem(egg.key()));
+  }
+
+  #[rstest]
+  fn test_nest_take(mut nest: Nest, egg: Egg) {
+    assert_eq!(nest.take(egg.key()), None);
+    nest.set(egg.clone());
+    assert_eq!(nest.take(egg.key()), Some(egg.clone()));
+    assert_eq!(nest.get(egg.key()), None);
+    assert!(nest.hashes.is_empty());
+  }
+
+  #[rstest]
+  fn test_nest_get_mut(mut nest: Nest, egg: Egg) {
+    assert!(nest.get_mut(egg.key()).is_none());
+    nest.set(egg.clone());
+    *nest.get_mut(egg.key()).unwrap().value_mut() = Value::String(b"other".to_vec());
+    assert_eq!(
+      nest.get(egg.key()).unwrap().value(),
+      &Value::String(b"other".to_vec())
+    );
+  }
+
+  #[rstest]
+  fn test_nest_get_or_insert_with(mut nest: Nest, egg: Egg) {
+    let inserted = nest.get_or_insert_with(egg.key(), || Value::String(b"default".to_vec()));
+    assert_eq!(inserted.value(), &Value::String(b"default".to_vec()));
+
+    nest.set(egg.clone());
+    let existing = nest.get_or_insert_with(egg.key(), || Value::String(b"default".to_vec()));
+    assert_eq!(existing, &egg);
+  }
+
+  #[rstest]
+  fn test_nest_get_expired(mut nest: Nest, mut egg: Egg) {
+    egg.set_expires_at(Some(Utc::now() - Duration::seconds(1)));
+    nest.set(egg.clone());
+    // Expired egg is lazily removed
+    assert_eq!(nest.get(egg.key()), None);
+    assert!(nest.expirations.is_empty());
+  }
+
+  #[rstest]
+  fn test_nest_expire(mut nest: Nest, egg: Egg) {
+    let expires_at = Utc::now() + Duration::seconds(60);
+    // Egg is not in the nest
+    assert!(!nest.expire(egg.key(), Some(expires_at)));
+
+    nest.set(egg.clone());
+    assert!(nest.expire(egg.key(), Some(expires_at)));
+    assert_eq!(nest.get(egg.key()).unwrap().expires_at(), Some(&expires_at));
+    assert_eq!(nest.expirations.len(), 1);
+
+    // Egg is persisted
+    assert!(nest.expire(egg.key(), None));
+    assert_eq!(nest.get(egg.key()).unwrap().expires_at(), None);
+    assert!(nest.expirations.is_empty());
+  }
+
+  #[rstest]
+  fn test_nest_version(mut nest: Nest, egg: Egg) {
+    let key = egg.key().clone();
+    let missing = nest.version(&key);
+    nest.set(egg);
+    let version = nest.version(&key);
+    assert_ne!(version, missing);
+
+    // Borrowed eggs only change version once committed as modified
+    nest.get_mut(&key).unwrap().value_mut();
+    nest.commit(false);
+    assert_eq!(nest.version(&key), version);
+    nest.get_mut(&key).unwrap().value_mut();
+    nest.commit(true);
+    assert_ne!(nest.version(&key), version);
+
+    // A watched key created then removed is modified
+    let watcher = nest.watcher();
+    let missing = nest.version("other");
+    nest.set(Egg::new("other", &b"value"[..]));
+    nest.rem("other");
+    assert_ne!(nest.version("other"), missing);
+
+    // Missing keys do not change when other keys are modified
+    let missing = nest.version("missing");
+    nest.rem(&key);
+    nest.set(Egg::new("other", &b"value"[..]));
+    nest.commit(true);
+    assert_eq!(nest.version("missing"), missing);
+
+    // Removed keys are forgotten once nobody watches the nest
+    drop(watcher);
+    nest.rem("other");
+    assert!(nest.tombstones.is_empty());
+  }
+
+  #[rstest]
+  fn test_nest_set_discards_expiration(mut nest: Nest, egg: Egg) {
+    nest.set(egg.clone());
+    nest.expire(egg.key(), Some(Utc::now() + Duration::seconds(60)));
+    nest.set(egg.clone());
+    assert_eq!(nest.get(egg.key()).unwrap().expires_at(), None);
+    assert!(nest.expirations.is_empty());
+  }
+
+  #[rstest]
+  fn test_nest_iter(mut nest: Nest, egg: Egg) {
+    assert_eq!(nest.len(), 0);
+    nest.set(egg.clone());
+    assert_eq!(nest.len(), 1);
+    assert_eq!(nest.iter().collect::<Vec<&Egg>>(), vec![&egg]);
+  }
+
+  #[rstest]
+  fn test_nest_keys(mut nest: Nest, egg: Egg) {
+    let mut expired = Egg::new("expired", TEST_VALUE.as_bytes());
+    expired.set_expires_at(Some(Utc::now() - Duration::seconds(1)));
+    nest.set(expired);
+    nest.set(egg.clone());
+    assert_eq!(nest.keys().collect::<Vec<&String>>(), vec![egg.key()]);
+  }
+
+  #[rstest]
+  fn test_nest_scan(mut nest: Nest) {
+    for index in 0..100 {
+      nest.set(Egg::new(&format!("key:{}", index), TEST_VALUE.as_bytes()));
+    }
+    let mut keys = Vec::new();
+    let mut cursor = 0;
+    loop {
+      let (next, eggs) = nest.scan(cursor, 10);
+      assert!(eggs.len() >= 10 || next == 0);
+      keys.extend(eggs.iter().map(|egg| egg.key().clone()));
+      // Grow and shrink the nest during the scan
+      nest.set(Egg::new(&format!("new:{}", cursor), TEST_VALUE.as_bytes()));
+      nest.rem(&format!("new:{}", cursor));
+      for index in 0..50 {
+        nest.set(Egg::new(
+          &format!("other:{}:{}", cursor, index),
+          TEST_VALUE.as_bytes(),
+        ));
+      }
+      cursor = next;
+      if cursor == 0 {
+        break;
+      }
+    }
+    let original: Vec<&String> = keys.iter().filter(|key| key.starts_with("key:")).collect();
+    assert_eq!(original.len(), 100);
+    assert_eq!(original.iter().collect::<HashSet<_>>().len(), 100);
+  }
+
+  #[rstest]
+  fn test_nest_scan_extreme_hashes(mut nest: Nest) {
+    let mut expected = HashSet::new();
+    for (key, hash) in [("first", 0), ("middle", 1 << 63), ("last", u64::MAX)] {
+      nest.set(Egg::new(key, TEST_VALUE.as_bytes()));
+      nest.hashes.remove(&(super::hash(key), key.to_string()));
+      nest.hashes.insert((hash, key.to_string()));
+      expected.insert(key.to_string());
+    }
+
+    let mut keys = HashSet::new();
+    let mut cursor = 0;
+    loop {
+      let (next, eggs) = nest.scan(cursor, 1);
+      keys.extend(eggs.iter().map(|egg| egg.key().clone()));
+      cursor = next;
+      if cursor == 0 {
+        break;
+      }
+    }
+    assert_eq!(keys, expected);
+  }
+
+  #[rstest]
+  fn test_nest_random_key(mut nest: Nest, egg: Egg) {
+    assert_eq!(nest.random_key(), None);
+    let mut expired = Egg::new("expired", TEST_VALUE.as_bytes());
+    expired.set_expires_at(Some(Utc::now() - Duration::seconds(1)));
+    nest.set(expired);
+    nest.set(egg.clone());
+    for _ in 0..10 {
+      assert_eq!(nest.random_key().as_ref(), Some(egg.key()));
+    }
+    assert_eq!(nest.len(), 1);
+  }
+
+  #[rstest]
+  fn test_nest_sweep(mut nest: Nest) {
+    let now = Utc::now();
+    let mut expired = Egg::new("expired", TEST_VALUE.as_bytes());
+    expired.set_expires_at(Some(now - Duration::seconds(1)));
+    let mut alive = Egg::new("alive", TEST_VALUE.as_bytes());
+    alive.set_expires_at(Some(now + Duration::seconds(60)));
+    nest.set(expired);
+    nest.set(alive);
+    nest.set(Egg::new("persistent", TEST_VALUE.as_bytes()));
+
+    assert_eq!(nest.sweep(&now), 1);
+    assert!(nest.get("expired").is_none());
+    assert!(nest.get("alive").is_some());
+    assert!(nest.get("persistent").is_some());
+    assert_eq!(nest.expirations.len(), 1);
+    assert_eq!(nest.hashes.len(), 2);
   }
 }
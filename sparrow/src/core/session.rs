@@ -0,0 +1,232 @@
+//! State of a client connection kept between its inputs.
+
+use crate::core::commands::ParsedCommand;
+use crate::core::nest::Watcher;
+use sparrow_resp::Data;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Mutable part of a [Session].
+#[derive(Debug, Default)]
+struct State {
+  /// Index of the selected database.
+  db: usize,
+  /// Transaction started by `MULTI`, if any.
+  transaction: Option<Transaction>,
+  /// Keys watched by `WATCH` since the last transaction.
+  watched: Vec<WatchedKey>,
+}
+
+/// Key watched by a client, along with its version when it was watched.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchedKey {
+  /// Index of the database of the key.
+  pub db: usize,
+  pub key: String,
+  /// Version of the key when it was watched, see [Nest::version].
+  ///
+  /// [Nest::version]: crate::core::nest::Nest::version
+  pub version: u64,
+  /// Held so that the version of the key is kept if it is removed.
+  pub watcher: Watcher,
+}
+
+/// Commands queued by a client between `MULTI` and `EXEC`.
+#[derive(Debug, Default)]
+pub struct Transaction {
+  /// Queued commands along with the data they have been parsed from.
+  commands: Vec<(Data, ParsedCommand)>,
+  /// Whether a command could not be queued, in which case `EXEC` executes nothing.
+  aborted: bool,
+}
+
+impl Transaction {
+  /// Return whether a command could not be queued.
+  pub fn is_aborted(&self) -> bool {
+    self.aborted
+  }
+
+  /// Return the queued commands in order, along with the data they have been parsed from.
+  pub fn into_commands(self) -> Vec<(Data, ParsedCommand)> {
+    self.commands
+  }
+}
+
+/// State of a client connection, shared by every [EngineInput] the connection sends.
+///
+/// Cloning a [Session] returns a handle to the same state.
+///
+/// [EngineInput]: crate::core::EngineInput
+///
+/// Sessions are compared by identity: two sessions are equal if they share the same state,
+/// even if another client reused the id of a closed connection.
+#[derive(Clone, Debug)]
+pub struct Session {
+  /// Client's id.
+  id: Arc<str>,
+  state: Arc<Mutex<State>>,
+}
+
+impl Session {
+  /// Return a new [Session] using the first database.
+  ///
+  /// # Arguments
+  /// * `id` - Client's id, e.g. its peer address
+  pub fn new(id: &str) -> Session {
+    Session {
+      id: id.into(),
+      state: Arc::new(Mutex::new(State::default())),
+    }
+  }
+
+  /// Return the client's id.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Return the index of the selected database.
+  pub fn db(&self) -> usize {
+    self.state().db
+  }
+
+  /// Select the database used by the following inputs.
+  pub fn select(&self, db: usize) {
+    self.state().db = db;
+  }
+
+  /// Return whether the client started a transaction with `MULTI`.
+  pub fn is_in_transaction(&self) -> bool {
+    self.state().transaction.is_some()
+  }
+
+  /// Start a transaction. Return `false` if a transaction is already started.
+  pub fn begin_transaction(&self) -> bool {
+    let mut state = self.state();
+    match state.transaction {
+      Some(_) => false,
+      None => {
+        state.transaction = Some(Transaction::default());
+        true
+      }
+    }
+  }
+
+  /// Queue a command in the started transaction.
+  ///
+  /// # Arguments
+  /// * `data` - Data the command has been parsed from
+  /// * `command` - Command to execute on `EXEC`
+  pub fn queue(&self, data: Data, command: ParsedCommand) {
+    if let Some(transaction) = self.state().transaction.as_mut() {
+      transaction.commands.push((data, command));
+    }
+  }
+
+  /// Mark the started transaction as aborted, if any, after a command could not be queued.
+  pub fn abort_transaction(&self) {
+    if let Some(transaction) = self.state().transaction.as_mut() {
+      transaction.aborted = true;
+    }
+  }
+
+  /// End the started transaction and return it, [None] if no transaction is started.
+  pub fn end_transaction(&self) -> Option<Transaction> {
+    self.state().transaction.take()
+  }
+
+  /// Watch a key. A key already watched keeps the version it was first watched with.
+  pub fn watch(&self, watched_key: WatchedKey) {
+    let watched = &mut self.state().watched;
+    let is_watched = watched
+      .iter()
+      .any(|other| other.db == watched_key.db && other.key == watched_key.key);
+    if !is_watched {
+      watched.push(watched_key);
+    }
+  }
+
+  /// Forget the watched keys and return them.
+  pub fn unwatch(&self) -> Vec<WatchedKey> {
+    std::mem::take(&mut self.state().watched)
+  }
+
+  /// Lock the mutable state. The engine is its only user, so a poisoned lock is still consistent.
+  fn state(&self) -> MutexGuard<'_, State> {
+    self.state.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl PartialEq for Session {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.state, &other.state)
+  }
+}
+
+impl Eq for Session {}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::commands::parse_command;
+  use crate::core::nest::Nest;
+  use crate::core::session::{Session, WatchedKey};
+  use sparrow_resp::Data;
+
+  #[test]
+  fn test_session_eq() {
+    let session = Session::new("client");
+    assert_eq!(session.clone(), session);
+    assert_ne!(Session::new("client"), session);
+  }
+
+  #[test]
+  fn test_session_select() {
+    let session = Session::new("client");
+    assert_eq!(session.id(), "client");
+    assert_eq!(session.db(), 0);
+
+    // Clones share the same state
+    session.clone().select(3);
+    assert_eq!(session.db(), 3);
+  }
+
+  #[test]
+  fn test_session_transaction() {
+    let session = Session::new("client");
+    assert!(!session.is_in_transaction());
+    assert!(session.end_transaction().is_none());
+
+    assert!(session.begin_transaction());
+    assert!(!session.begin_transaction());
+    let data = Data::BulkString("GET key".into());
+    session.queue(data.clone(), parse_command(&data).unwrap());
+    let transaction = session.end_transaction().unwrap();
+    assert!(!transaction.is_aborted());
+    assert_eq!(transaction.into_commands().len(), 1);
+    assert!(!session.is_in_transaction());
+
+    // Aborting only applies to a started transaction
+    session.abort_transaction();
+    session.begin_transaction();
+    session.abort_transaction();
+    assert!(session.end_transaction().unwrap().is_aborted());
+  }
+
+  #[test]
+  fn test_session_watch() {
+    let session = Session::new("client");
+    let watcher = Nest::new().watcher();
+    let watched_key = |db, key: &str, version| WatchedKey {
+      db,
+      key: key.to_string(),
+      version,
+      watcher: watcher.clone(),
+    };
+    session.watch(watched_key(0, "key", 1));
+    session.watch(watched_key(0, "key", 2));
+    session.watch(watched_key(1, "key", 3));
+    assert_eq!(
+      session.unwatch(),
+      vec![watched_key(0, "key", 1), watched_key(1, "key", 3)]
+    );
+    assert!(session.unwatch().is_empty());
+  }
+}
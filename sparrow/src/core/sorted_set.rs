@@ -0,0 +1,370 @@
+//! Sorted set storing unique members ordered by score.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Member of a [SortedSet] along with its score.
+///
+/// Entries are ordered by score, then lexicographically by member.
+#[derive(Clone, Debug)]
+struct Entry {
+  score: f64,
+  member: Vec<u8>,
+}
+
+impl PartialEq for Entry {
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Entry {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self
+      .score
+      .total_cmp(&other.score)
+      .then_with(|| self.member.cmp(&other.member))
+  }
+}
+
+/// Node of a treap, a binary search tree balanced by random priorities.
+///
+/// Each node knows the size of its subtree so that ranks are computed in logarithmic time.
+#[derive(Clone)]
+struct Node {
+  entry: Entry,
+  priority: u32,
+  size: usize,
+  left: Link,
+  right: Link,
+}
+
+type Link = Option<Box<Node>>;
+
+impl Node {
+  /// Return a new leaf [Node].
+  fn new(entry: Entry) -> Node {
+    Node {
+      entry,
+      priority: fastrand::u32(..),
+      size: 1,
+      left: None,
+      right: None,
+    }
+  }
+
+  /// Update the size of the subtree after one of the children changed.
+  fn update(&mut self) {
+    self.size = 1 + size(&self.left) + size(&self.right);
+  }
+}
+
+/// Return the number of entries of a subtree.
+fn size(link: &Link) -> usize {
+  link.as_ref().map_or(0, |node| node.size)
+}
+
+/// Split a subtree into the entries matching a predicate and the following ones.
+///
+/// The predicate must hold for a prefix of the entries in order.
+fn split(link: Link, is_before: &impl Fn(&Entry) -> bool) -> (Link, Link) {
+  match link {
+    None => (None, None),
+    Some(mut node) => {
+      if is_before(&node.entry) {
+        let (left, right) = split(node.right.take(), is_before);
+        node.right = left;
+        node.update();
+        (Some(node), right)
+      } else {
+        let (left, right) = split(node.left.take(), is_before);
+        node.left = right;
+        node.update();
+        (left, Some(node))
+      }
+    }
+  }
+}
+
+/// Merge two subtrees, every entry of the left one being before the entries of the right one.
+fn merge(left: Link, right: Link) -> Link {
+  match (left, right) {
+    (None, right) => right,
+    (left, None) => left,
+    (Some(mut left), Some(mut right)) => {
+      if left.priority > right.priority {
+        left.right = merge(left.right.take(), Some(right));
+        left.update();
+        Some(left)
+      } else {
+        right.left = merge(Some(left), right.left.take());
+        right.update();
+        Some(right)
+      }
+    }
+  }
+}
+
+/// Set of unique members ordered by score.
+///
+/// Scores are indexed by member for constant-time lookups, while members are ordered in a treap
+/// giving logarithmic-time insertions, removals, rank and range queries.
+/// Members with the same score are ordered lexicographically.
+#[derive(Clone, Default)]
+pub struct SortedSet {
+  scores: HashMap<Vec<u8>, f64>,
+  root: Link,
+}
+
+impl SortedSet {
+  /// Return a new empty [SortedSet].
+  pub fn new() -> SortedSet {
+    SortedSet::default()
+  }
+
+  /// Return the number of members.
+  pub fn len(&self) -> usize {
+    self.scores.len()
+  }
+
+  /// Return whether the set has no member.
+  pub fn is_empty(&self) -> bool {
+    self.scores.is_empty()
+  }
+
+  /// Return the score of a member, [None] if it is not in the set.
+  pub fn score(&self, member: &[u8]) -> Option<f64> {
+    self.scores.get(member).copied()
+  }
+
+  /// Insert a member or update its score. Return the previous score, if any.
+  ///
+  /// # Arguments
+  /// * `member` - Member to insert
+  /// * `score` - Score of the member, which must not be NaN
+  pub fn insert(&mut self, member: Vec<u8>, score: f64) -> Option<f64> {
+    // Zero is signed in floating point but not in the set order
+    let score = if score == 0.0 { 0.0 } else { score };
+    let previous = self.remove(&member);
+    let entry = Entry { score, member };
+    let (left, right) = split(self.root.take(), &|other| *other < entry);
+    self.scores.insert(entry.member.clone(), score);
+    self.root = merge(merge(left, Some(Box::new(Node::new(entry)))), right);
+    previous
+  }
+
+  /// Remove a member. Return its score, [None] if it was not in the set.
+  ///
+  /// # Arguments
+  /// * `member` - Member to remove
+  pub fn remove(&mut self, member: &[u8]) -> Option<f64> {
+    let score = self.scores.remove(member)?;
+    let entry = Entry {
+      score,
+      member: member.to_vec(),
+    };
+    let (left, right) = split(self.root.take(), &|other| *other < entry);
+    let (_, right) = split(right, &|other| *other <= entry);
+    self.root = merge(left, right);
+    Some(score)
+  }
+
+  /// Return the 0-based rank of a member in ascending order, [None] if it is not in the set.
+  pub fn rank(&self, member: &[u8]) -> Option<usize> {
+    let score = self.score(member)?;
+    Some(self.count_while(|other_member, other_score| {
+      other_score
+        .total_cmp(&score)
+        .then_with(|| other_member.cmp(member))
+        == Ordering::Less
+    }))
+  }
+
+  /// Return the number of leading members, in ascending order, matching a predicate.
+  ///
+  /// The predicate receives a member and its score. It must hold for a prefix of the members,
+  /// e.g. `|_, score| score < 10.0`.
+  pub fn count_while(&self, is_before: impl Fn(&[u8], f64) -> bool) -> usize {
+    let mut link = &self.root;
+    let mut count = 0;
+    while let Some(node) = link {
+      if is_before(&node.entry.member, node.entry.score) {
+        count += size(&node.left) + 1;
+        link = &node.right;
+      } else {
+        link = &node.left;
+      }
+    }
+    count
+  }
+
+  /// Return an iterator over the members and scores whose rank is in a range.
+  ///
+  /// # Arguments
+  /// * `start` - Rank of the first member, in ascending order
+  /// * `end` - Rank following the last member, in ascending order
+  /// * `rev` - Whether the members are iterated in descending order, starting from `end - 1`
+  pub fn range(&self, start: usize, end: usize, rev: bool) -> Iter<'_> {
+    let end = end.min(self.len());
+    let remaining = end.saturating_sub(start);
+    let mut iter = Iter {
+      stack: Vec::new(),
+      remaining,
+      rev,
+    };
+    if remaining == 0 {
+      return iter;
+    }
+    // Stack the ancestors visited after the first member, along with the first member
+    let mut rank = if rev { end - 1 } else { start };
+    let mut link = &self.root;
+    while let Some(node) = link {
+      let left_size = size(&node.left);
+      match rank.cmp(&left_size) {
+        Ordering::Less => {
+          if !rev {
+            iter.stack.push(node);
+          }
+          link = &node.left;
+        }
+        Ordering::Equal => {
+          iter.stack.push(node);
+          break;
+        }
+        Ordering::Greater => {
+          if rev {
+            iter.stack.push(node);
+          }
+          rank -= left_size + 1;
+          link = &node.right;
+        }
+      }
+    }
+    iter
+  }
+
+  /// Return an iterator over every member and score in ascending order.
+  pub fn iter(&self) -> Iter<'_> {
+    self.range(0, self.len(), false)
+  }
+}
+
+impl PartialEq for SortedSet {
+  fn eq(&self, other: &Self) -> bool {
+    self.scores == other.scores
+  }
+}
+
+impl fmt::Debug for SortedSet {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_map()
+      .entries(
+        self
+          .iter()
+          .map(|(member, score)| (String::from_utf8_lossy(member), score)),
+      )
+      .finish()
+  }
+}
+
+/// Iterator over the members and scores of a [SortedSet], see [SortedSet::range].
+pub struct Iter<'a> {
+  stack: Vec<&'a Node>,
+  remaining: usize,
+  rev: bool,
+}
+
+impl<'a> Iterator for Iter<'a> {
+  type Item = (&'a [u8], f64);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.remaining == 0 {
+      return None;
+    }
+    let node = self.stack.pop()?;
+    self.remaining -= 1;
+    // Stack the path to the next node in the iteration order
+    let mut link = if self.rev { &node.left } else { &node.right };
+    while let Some(next) = link {
+      self.stack.push(next);
+      link = if self.rev { &next.right } else { &next.left };
+    }
+    Some((&node.entry.member, node.entry.score))
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.remaining, Some(self.remaining))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::sorted_set::SortedSet;
+
+  /// Return the members of an iterator as strings.
+  fn members<'a>(iter: impl Iterator<Item = (&'a [u8], f64)>) -> Vec<String> {
+    iter
+      .map(|(member, _)| String::from_utf8_lossy(member).to_string())
+      .collect()
+  }
+
+  #[test]
+  fn test_sorted_set_insert_and_remove() {
+    let mut set = SortedSet::new();
+    assert_eq!(set.insert(b"b".to_vec(), 2.0), None);
+    assert_eq!(set.insert(b"a".to_vec(), 1.0), None);
+    assert_eq!(set.insert(b"c".to_vec(), 2.0), None);
+    assert_eq!(set.insert(b"z".to_vec(), -1.0), None);
+    assert_eq!(set.insert(b"z".to_vec(), 3.0), Some(-1.0));
+    assert_eq!(set.len(), 4);
+    assert_eq!(members(set.iter()), vec!["a", "b", "c", "z"]);
+
+    assert_eq!(set.remove(b"b"), Some(2.0));
+    assert_eq!(set.remove(b"b"), None);
+    assert_eq!(set.score(b"c"), Some(2.0));
+    assert_eq!(members(set.iter()), vec!["a", "c", "z"]);
+  }
+
+  #[test]
+  fn test_sorted_set_rank() {
+    let mut set = SortedSet::new();
+    for index in 0..100 {
+      set.insert(format!("{:03}", index).into_bytes(), (100 - index) as f64);
+    }
+    assert_eq!(set.rank(b"099"), Some(0));
+    assert_eq!(set.rank(b"000"), Some(99));
+    assert_eq!(set.rank(b"missing"), None);
+    assert_eq!(set.count_while(|_, score| score <= 10.0), 10);
+  }
+
+  #[test]
+  fn test_sorted_set_range() {
+    let mut set = SortedSet::new();
+    for (score, member) in ["a", "b", "c", "d", "e"].iter().enumerate() {
+      set.insert(member.as_bytes().to_vec(), score as f64);
+    }
+    assert_eq!(members(set.range(1, 4, false)), vec!["b", "c", "d"]);
+    assert_eq!(members(set.range(1, 4, true)), vec!["d", "c", "b"]);
+    assert_eq!(members(set.range(3, 10, true)), vec!["e", "d"]);
+    assert!(members(set.range(4, 2, false)).is_empty());
+  }
+
+  #[test]
+  fn test_sorted_set_eq() {
+    let mut set = SortedSet::new();
+    set.insert(b"a".to_vec(), 0.0);
+    let mut other = SortedSet::new();
+    other.insert(b"a".to_vec(), -0.0);
+    assert_eq!(set, other);
+    assert_eq!(format!("{:?}", set), "{\"a\": 0.0}");
+  }
+}
@@ -0,0 +1,272 @@
+//! Typed values stored into an [Egg].
+//!
+//! [Egg]: crate::core::egg::Egg
+
+use crate::core::sorted_set::SortedSet;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+/// Error message returned when a command is used on a key holding another type of value.
+pub const WRONG_TYPE_ERROR: &str =
+  "WRONGTYPE Operation against a key holding the wrong kind of value";
+
+/// Maximum length of a [Value::String] grown by commands appending or writing at an offset, in bytes.
+pub const MAX_STRING_LENGTH: usize = 512 * 1024 * 1024;
+
+/// Value stored into an [Egg].
+///
+/// Strings, hash fields, hash values, list elements and set members are binary safe and stored as raw bytes.
+///
+/// [Egg]: crate::core::egg::Egg
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+  /// Raw bytes.
+  String(Vec<u8>),
+  /// Map of fields to values.
+  Hash(HashMap<Vec<u8>, Vec<u8>>),
+  /// Sequence of elements, efficient at both ends.
+  List(VecDeque<Vec<u8>>),
+  /// Unordered collection of unique members.
+  Set(HashSet<Vec<u8>>),
+  /// Collection of unique members ordered by score.
+  SortedSet(SortedSet),
+}
+
+impl Value {
+  /// Return the raw bytes of a [Value::String], [None] for other types.
+  pub fn as_string(&self) -> Option<&[u8]> {
+    match self {
+      Value::String(bytes) => Some(bytes),
+      _ => None,
+    }
+  }
+
+  /// Return the mutable raw bytes of a [Value::String], [None] for other types.
+  pub fn as_string_mut(&mut self) -> Option<&mut Vec<u8>> {
+    match self {
+      Value::String(bytes) => Some(bytes),
+      _ => None,
+    }
+  }
+  /// Return the map of a [Value::Hash], [None] for other types.
+  #[cfg(test)]
+  pub fn as_hash(&self) -> Option<&HashMap<Vec<u8>, Vec<u8>>> {
+    match self {
+      Value::Hash(hash) => Some(hash),
+      _ => None,
+    }
+  }
+  /// Return the mutable map of a [Value::Hash], [None] for other types.
+  pub fn as_hash_mut(&mut self) -> Option<&mut HashMap<Vec<u8>, Vec<u8>>> {
+    match self {
+      Value::Hash(hash) => Some(hash),
+      _ => None,
+    }
+  }
+  /// Return the elements of a [Value::List], [None] for other types.
+  pub fn as_list(&self) -> Option<&VecDeque<Vec<u8>>> {
+    match self {
+      Value::List(list) => Some(list),
+      _ => None,
+    }
+  }
+  /// Return the mutable elements of a [Value::List], [None] for other types.
+  pub fn as_list_mut(&mut self) -> Option<&mut VecDeque<Vec<u8>>> {
+    match self {
+      Value::List(list) => Some(list),
+      _ => None,
+    }
+  }
+  /// Return the members of a [Value::Set], [None] for other types.
+  #[cfg(test)]
+  pub fn as_set(&self) -> Option<&HashSet<Vec<u8>>> {
+    match self {
+      Value::Set(set) => Some(set),
+      _ => None,
+    }
+  }
+  /// Return the mutable members of a [Value::Set], [None] for other types.
+  pub fn as_set_mut(&mut self) -> Option<&mut HashSet<Vec<u8>>> {
+    match self {
+      Value::Set(set) => Some(set),
+      _ => None,
+    }
+  }
+  /// Return the [SortedSet] of a [Value::SortedSet], [None] for other types.
+  #[cfg(test)]
+  pub fn as_sorted_set(&self) -> Option<&SortedSet> {
+    match self {
+      Value::SortedSet(sorted_set) => Some(sorted_set),
+      _ => None,
+    }
+  }
+  /// Return the mutable [SortedSet] of a [Value::SortedSet], [None] for other types.
+  pub fn as_sorted_set_mut(&mut self) -> Option<&mut SortedSet> {
+    match self {
+      Value::SortedSet(sorted_set) => Some(sorted_set),
+      _ => None,
+    }
+  }
+  /// Return the name of the type of this [Value], as returned by the TYPE command.
+  pub fn type_name(&self) -> &'static str {
+    match self {
+      Value::String(_) => "string",
+      Value::Hash(_) => "hash",
+      Value::List(_) => "list",
+      Value::Set(_) => "set",
+      Value::SortedSet(_) => "zset",
+    }
+  }
+}
+
+impl From<&[u8]> for Value {
+  fn from(bytes: &[u8]) -> Self {
+    Value::String(bytes.to_vec())
+  }
+}
+
+impl From<Vec<u8>> for Value {
+  fn from(bytes: Vec<u8>) -> Self {
+    Value::String(bytes)
+  }
+}
+
+impl From<HashMap<Vec<u8>, Vec<u8>>> for Value {
+  fn from(hash: HashMap<Vec<u8>, Vec<u8>>) -> Self {
+    Value::Hash(hash)
+  }
+}
+
+impl From<VecDeque<Vec<u8>>> for Value {
+  fn from(list: VecDeque<Vec<u8>>) -> Self {
+    Value::List(list)
+  }
+}
+
+impl From<HashSet<Vec<u8>>> for Value {
+  fn from(set: HashSet<Vec<u8>>) -> Self {
+    Value::Set(set)
+  }
+}
+
+impl From<SortedSet> for Value {
+  fn from(sorted_set: SortedSet) -> Self {
+    Value::SortedSet(sorted_set)
+  }
+}
+
+impl fmt::Display for Value {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Value::String(bytes) => write!(f, "{}", String::from_utf8_lossy(bytes)),
+      Value::Hash(hash) => {
+        let entries: Vec<String> = hash
+          .iter()
+          .map(|(field, value)| {
+            format!(
+              "{}: {}",
+              String::from_utf8_lossy(field),
+              String::from_utf8_lossy(value)
+            )
+          })
+          .collect();
+        write!(f, "{{{}}}", entries.join(", "))
+      }
+      Value::List(list) => {
+        let elements: Vec<String> = list
+          .iter()
+          .map(|element| String::from_utf8_lossy(element).to_string())
+          .collect();
+        write!(f, "[{}]", elements.join(", "))
+      }
+      Value::Set(set) => {
+        let members: Vec<String> = set
+          .iter()
+          .map(|member| String::from_utf8_lossy(member).to_string())
+          .collect();
+        write!(f, "{{{}}}", members.join(", "))
+      }
+      Value::SortedSet(sorted_set) => {
+        let entries: Vec<String> = sorted_set
+          .iter()
+          .map(|(member, score)| format!("{}: {}", String::from_utf8_lossy(member), score))
+          .collect();
+        write!(f, "{{{}}}", entries.join(", "))
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::sorted_set::SortedSet;
+  use crate::core::value::Value;
+  use std::collections::{HashMap, HashSet, VecDeque};
+
+  #[test]
+  fn test_value_string() {
+    let value = Value::from(&b"value"[..]);
+    assert_eq!(value.as_string(), Some(&b"value"[..]));
+    assert_eq!(value.as_hash(), None);
+    assert_eq!(format!("{}", value), "value");
+    assert_eq!(value.type_name(), "string");
+  }
+
+  #[test]
+  fn test_value_hash() {
+    let mut hash = HashMap::new();
+    hash.insert(b"field".to_vec(), b"value".to_vec());
+    let mut value = Value::from(hash.clone());
+    assert_eq!(value.as_string(), None);
+    assert_eq!(value.as_hash(), Some(&hash));
+    assert_eq!(format!("{}", value), "{field: value}");
+    assert_eq!(value.type_name(), "hash");
+
+    value
+      .as_hash_mut()
+      .unwrap()
+      .insert(b"other".to_vec(), b"value".to_vec());
+    assert_eq!(value.as_hash().unwrap().len(), 2);
+  }
+
+  #[test]
+  fn test_value_list() {
+    let list: VecDeque<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()].into();
+    let mut value = Value::from(list.clone());
+    assert_eq!(value.as_hash(), None);
+    assert_eq!(value.as_list(), Some(&list));
+    assert_eq!(format!("{}", value), "[a, b]");
+    assert_eq!(value.type_name(), "list");
+
+    value.as_list_mut().unwrap().push_front(b"c".to_vec());
+    assert_eq!(value.as_list().unwrap().len(), 3);
+  }
+
+  #[test]
+  fn test_value_set() {
+    let set: HashSet<Vec<u8>> = vec![b"a".to_vec()].into_iter().collect();
+    let mut value = Value::from(set.clone());
+    assert_eq!(value.as_list(), None);
+    assert_eq!(value.as_set(), Some(&set));
+    assert_eq!(format!("{}", value), "{a}");
+    assert_eq!(value.type_name(), "set");
+
+    value.as_set_mut().unwrap().insert(b"b".to_vec());
+    assert_eq!(value.as_set().unwrap().len(), 2);
+  }
+
+  #[test]
+  fn test_value_sorted_set() {
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(b"b".to_vec(), 2.0);
+    sorted_set.insert(b"a".to_vec(), 1.5);
+    let mut value = Value::from(sorted_set.clone());
+    assert_eq!(value.as_set(), None);
+    assert_eq!(value.as_sorted_set(), Some(&sorted_set));
+    assert_eq!(format!("{}", value), "{a: 1.5, b: 2}");
+    assert_eq!(value.type_name(), "zset");
+
+    value.as_sorted_set_mut().unwrap().remove(b"a");
+    assert_eq!(value.as_sorted_set().unwrap().len(), 1);
+  }
+}
@@ -5,9 +5,9 @@
 //!
 //! For now Sparrow runs as the following:
 //! - The engine is ran in one thread and executes commands received
-//! through an input consumer and sends the output using a sender.
+//!   through an input consumer and sends the output using a sender.
 //! - The TCP socket server is ran asynchronously using [async_std] in the main thread. It receives commands from socket connections
-//! and send them to the engine using an input producer. The outputs are retrieved using the engine output sender.
+//!   and send them to the engine using an input producer. The outputs are retrieved using the engine output sender.
 //!
 //! # Examples
 //!
@@ -20,14 +20,15 @@
 //! let engine_task = task::spawn(async move { engine.run().await });
 //!
 //! let tcp_task =
-// task::spawn(async move { run_tcp_server(config.tcp_server_port, engine_sender).await });
-
+//!   task::spawn(async move { run_tcp_server(config.tcp_server_addresses(), 256, engine_sender).await });
+//!
 //! try_join!(engine_task, tcp_task).map(|_| ())
 //! ```
 mod cli;
 mod core;
 mod errors;
 mod logger;
+mod persistence;
 mod tcp_server;
 
 use crate::cli::{run_cli, Config};
@@ -72,17 +73,31 @@ async fn run(config: Config) -> Result<()> {
 
   // Create a new engine
   log::debug!("Setting up engine");
-  let mut engine = Engine::new();
+  let mut engine = Engine::with_databases(config.databases);
   let engine_sender = engine.init();
 
+  // Restore data before accepting any connection
+  // The append-only file takes precedence over the snapshot because it is the most up to date
+  engine.set_snapshot_filepath(&config.snapshot_filepath);
+  if config.aof_enabled {
+    log::debug!("Enabling append-only file persistence");
+    engine
+      .enable_aof(&config.aof_filepath, config.aof_fsync)
+      .await?;
+  } else {
+    engine.load_snapshot()?;
+  }
+
   // Run the engine
   log::debug!("Spawning engine task");
   let engine_task = task::spawn(async move { engine.run().await });
 
   // Run the TCP server
   log::debug!("Spawning TCP server task");
+  let addresses = config.tcp_server_addresses();
+  let max_connections = config.tcp_server_max_connections;
   let tcp_task =
-    task::spawn(async move { run_tcp_server(config.tcp_server_port, engine_sender).await });
+    task::spawn(async move { run_tcp_server(addresses, max_connections, engine_sender).await });
 
   try_join!(engine_task, tcp_task).map(|_| ())
 }
@@ -0,0 +1,347 @@
+//! Append-only file persistence.
+//!
+//! Every mutating command executed by the engine is appended to a file using the RESP format.
+//! The file is replayed at startup to rebuild the in-memory state.
+
+use crate::errors::Result;
+use async_std::fs::{File, OpenOptions};
+use async_std::io::{self, BufReader, BufWriter, Read};
+use async_std::path::Path;
+use async_std::prelude::*;
+use sparrow_resp::{decode, encode, Data};
+use std::fmt;
+use std::io::ErrorKind;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// Interval between two fsync calls when using [FsyncPolicy::EverySecond].
+const FSYNC_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Policy used to flush the append-only file to the disk.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FsyncPolicy {
+  /// Fsync after every appended command.
+  Always,
+  /// Fsync at most once every second.
+  EverySecond,
+  /// Never fsync and let the operating system flush data.
+  Never,
+}
+
+impl FromStr for FsyncPolicy {
+  type Err = String;
+
+  fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    match s {
+      "always" => Ok(FsyncPolicy::Always),
+      "everysec" => Ok(FsyncPolicy::EverySecond),
+      "never" => Ok(FsyncPolicy::Never),
+      unknown => Err(format!(
+        "Unknown fsync policy: {}. Expected always, everysec or never.",
+        unknown
+      )),
+    }
+  }
+}
+
+impl fmt::Display for FsyncPolicy {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FsyncPolicy::Always => write!(f, "always"),
+      FsyncPolicy::EverySecond => write!(f, "everysec"),
+      FsyncPolicy::Never => write!(f, "never"),
+    }
+  }
+}
+
+/// Append-only file logging mutating commands.
+pub struct AppendOnlyFile {
+  writer: BufWriter<File>,
+  policy: FsyncPolicy,
+  /// Whether data has been written since the last fsync.
+  dirty: bool,
+  last_fsync: Instant,
+}
+
+impl AppendOnlyFile {
+  /// Open an [AppendOnlyFile], creating it if it does not exist.
+  ///
+  /// # Arguments
+  /// * `filepath` - Path of the append-only file
+  /// * `policy` - [FsyncPolicy] used to flush data to the disk
+  pub async fn open(filepath: impl AsRef<Path>, policy: FsyncPolicy) -> Result<AppendOnlyFile> {
+    let file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(filepath)
+      .await?;
+    Ok(AppendOnlyFile {
+      writer: BufWriter::new(file),
+      policy,
+      dirty: false,
+      last_fsync: Instant::now(),
+    })
+  }
+
+  /// Append a command to the file.
+  ///
+  /// The command is written to the operating system right away
+  /// and fsynced according to the [FsyncPolicy].
+  ///
+  /// # Arguments
+  /// * `data` - [Data] encoding the command
+  pub async fn append(&mut self, data: &Data) -> Result<()> {
+    encode(data, &mut self.writer).await?;
+    self.writer.flush().await?;
+    self.dirty = true;
+    if self.policy == FsyncPolicy::Always {
+      self.fsync().await?;
+    }
+    Ok(())
+  }
+
+  /// Fsync the file if the [FsyncPolicy::EverySecond] interval has elapsed.
+  ///
+  /// This method is expected to be called periodically.
+  pub async fn fsync_if_due(&mut self) -> Result<()> {
+    if self.policy == FsyncPolicy::EverySecond
+      && self.dirty
+      && self.last_fsync.elapsed() >= FSYNC_INTERVAL
+    {
+      self.fsync().await?;
+    }
+    Ok(())
+  }
+
+  /// Fsync the file.
+  async fn fsync(&mut self) -> Result<()> {
+    self.writer.get_ref().sync_data().await?;
+    self.dirty = false;
+    self.last_fsync = Instant::now();
+    Ok(())
+  }
+}
+
+/// Reader counting the bytes read from an inner reader, to locate the decoded commands.
+struct CountingReader<R> {
+  inner: R,
+  /// Number of bytes read so far.
+  count: u64,
+  /// Last byte read, if any.
+  last_byte: Option<u8>,
+}
+
+impl<R: Read + Unpin> Read for CountingReader<R> {
+  fn poll_read(
+    mut self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: &mut [u8],
+  ) -> Poll<io::Result<usize>> {
+    let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+    if let Poll::Ready(Ok(n)) = poll {
+      self.count += n as u64;
+      if n > 0 {
+        self.last_byte = Some(buf[n - 1]);
+      }
+    }
+    poll
+  }
+}
+
+/// Return the position in the file of the next byte to decode.
+fn position<R>(reader: &BufReader<CountingReader<R>>) -> u64 {
+  reader.get_ref().count - reader.buffer().len() as u64
+}
+
+/// Return whether a decoding error is caused by a command truncated at the end of the file.
+///
+/// # Arguments
+/// * `reader` - Reader the command was decoded from
+/// * `err` - Decoding error
+/// * `len` - Length of the file
+fn is_truncated<R>(reader: &BufReader<CountingReader<R>>, err: &io::Error, len: u64) -> bool {
+  match err.kind() {
+    ErrorKind::BrokenPipe | ErrorKind::UnexpectedEof => true,
+    // The last line of the file is cut short
+    _ => position(reader) == len && reader.get_ref().last_byte != Some(b'\n'),
+  }
+}
+
+/// Read every command stored in an append-only file and process them in order.
+///
+/// Return the number of processed commands, `0` if the file does not exist.
+/// A command truncated at the end of the file (e.g. after a crash) is ignored
+/// and removed from the file, so that new commands can be appended after the last complete one.
+///
+/// # Arguments
+/// * `filepath` - Path of the append-only file
+/// * `process` - Function called with every read command
+pub async fn replay(filepath: impl AsRef<Path>, mut process: impl FnMut(Data)) -> Result<usize> {
+  let filepath = filepath.as_ref();
+  if !filepath.exists().await {
+    return Ok(0);
+  }
+
+  let file = File::open(filepath).await?;
+  let len = file.metadata().await?.len();
+  let mut reader = BufReader::new(CountingReader {
+    inner: file,
+    count: 0,
+    last_byte: None,
+  });
+  let mut count = 0;
+  // End of the last complete command
+  let mut end = 0;
+  loop {
+    match decode(&mut reader).await {
+      Ok(data) => {
+        process(data);
+        count += 1;
+        end = position(&reader);
+      }
+      // Nothing left to read
+      Err(err) if err.kind() == ErrorKind::BrokenPipe && position(&reader) == end => break,
+      Err(err) if is_truncated(&reader, &err, len) => {
+        log::warn!(
+          "Removing truncated command at the end of the append-only file {}",
+          filepath.display()
+        );
+        let file = OpenOptions::new().write(true).open(filepath).await?;
+        file.set_len(end).await?;
+        file.sync_all().await?;
+        break;
+      }
+      Err(err) => {
+        return Err(
+          format!(
+            "Corrupted append-only file {} after {} commands: {}",
+            filepath.display(),
+            count,
+            err
+          )
+          .into(),
+        )
+      }
+    }
+  }
+  Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::persistence::aof::{replay, AppendOnlyFile, FsyncPolicy};
+  use async_std::fs;
+  use sparrow_resp::Data;
+  use std::env;
+
+  async fn read_commands(filepath: &std::path::Path) -> crate::errors::Result<Vec<Data>> {
+    let mut commands = vec![];
+    replay(filepath, |data| commands.push(data)).await?;
+    Ok(commands)
+  }
+
+  fn command(inputs: &[&str]) -> Data {
+    Data::Array(
+      inputs
+        .iter()
+        .map(|input| Data::BulkString(input.as_bytes().to_vec()))
+        .collect(),
+    )
+  }
+
+  #[test]
+  fn test_fsync_policy_from_str() {
+    assert_eq!("always".parse(), Ok(FsyncPolicy::Always));
+    assert_eq!("everysec".parse(), Ok(FsyncPolicy::EverySecond));
+    assert_eq!("never".parse(), Ok(FsyncPolicy::Never));
+    assert!("sometimes".parse::<FsyncPolicy>().is_err());
+  }
+
+  #[async_std::test]
+  async fn test_append_and_read_commands() {
+    let filepath = env::temp_dir().join("sparrow_test_append_and_read_commands.aof");
+    let _ = fs::remove_file(&filepath).await;
+
+    let commands = vec![
+      command(&["SET", "key", "some value"]),
+      command(&["REM", "key"]),
+    ];
+    let mut aof = AppendOnlyFile::open(&filepath, FsyncPolicy::Always)
+      .await
+      .unwrap();
+    for data in commands.iter() {
+      aof.append(data).await.unwrap();
+    }
+
+    assert_eq!(read_commands(&filepath).await.unwrap(), commands);
+    fs::remove_file(&filepath).await.unwrap();
+  }
+
+  #[async_std::test]
+  async fn test_read_commands_missing_file() {
+    let filepath = env::temp_dir().join("sparrow_test_read_commands_missing_file.aof");
+    assert!(read_commands(&filepath).await.unwrap().is_empty());
+  }
+
+  #[async_std::test]
+  async fn test_read_commands_truncated() {
+    let filepath = env::temp_dir().join("sparrow_test_read_commands_truncated.aof");
+    fs::write(
+      &filepath,
+      "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n*2\r\n$3\r\nGET\r\n$3\r\nk",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+      read_commands(&filepath).await.unwrap(),
+      vec![command(&["GET", "key"])]
+    );
+    fs::remove_file(&filepath).await.unwrap();
+  }
+
+  #[async_std::test]
+  async fn test_append_after_truncated() {
+    let filepath = env::temp_dir().join("sparrow_test_append_after_truncated.aof");
+    let get = command(&["GET", "key"]);
+    let set = command(&["SET", "key", "some value"]);
+    for truncated in [
+      "*2\r\n$3\r\nGET\r\n$3\r\nk",
+      "*2\r\n$3\r\nGET\r\n",
+      "*2\r\n$3\r\nGET\r\n$3\r",
+      "*2",
+    ] {
+      fs::write(
+        &filepath,
+        format!("*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n{}", truncated),
+      )
+      .await
+      .unwrap();
+
+      // Restart, append commands after the truncated one and restart again
+      for expected in [vec![get.clone()], vec![get.clone(), set.clone()]] {
+        assert_eq!(read_commands(&filepath).await.unwrap(), expected);
+        let mut aof = AppendOnlyFile::open(&filepath, FsyncPolicy::Always)
+          .await
+          .unwrap();
+        aof.append(&set).await.unwrap();
+      }
+      assert_eq!(
+        read_commands(&filepath).await.unwrap(),
+        vec![get.clone(), set.clone(), set.clone()]
+      );
+    }
+    fs::remove_file(&filepath).await.unwrap();
+  }
+
+  #[async_std::test]
+  async fn test_read_commands_corrupted() {
+    let filepath = env::temp_dir().join("sparrow_test_read_commands_corrupted.aof");
+    fs::write(&filepath, "*1\r\n$3\r\nGET\r\n?corrupted\r\n")
+      .await
+      .unwrap();
+    assert!(read_commands(&filepath).await.is_err());
+    fs::remove_file(&filepath).await.unwrap();
+  }
+}
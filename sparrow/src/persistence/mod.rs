@@ -0,0 +1,9 @@
+//! Persistence features.
+//!
+//! Provides utilities to save the in-memory data on disk and to load it back at startup.
+
+mod aof;
+mod snapshot;
+
+pub use aof::{replay, AppendOnlyFile, FsyncPolicy};
+pub use snapshot::{load as load_snapshot, save as save_snapshot};
@@ -0,0 +1,500 @@
+//! Point-in-time snapshot persistence.
+//!
+//! A snapshot is a compact binary dump of every [Egg] of the numbered [Nest]s of the engine,
+//! using the following layout:
+//! - Header: the `SPARROW` magic bytes followed by the format version (`u16`)
+//! - Eggs: a list of entries, each starting with a value type byte. A [SELECT_DB] byte followed
+//!   by a database index (`u32`) assigns the following entries to that database.
+//! - Footer: the [END_OF_EGGS] byte followed by the CRC32 checksum (`u32`) of all previous bytes
+//!
+//! Entries before any [SELECT_DB] byte belong to the first database, which is how version 1
+//! snapshots, written before multiple databases were supported, are loaded.
+//!
+//! Each entry stores the key, the creation date, the optional expiration date and the value.
+//! Integers are little-endian, dates are unix timestamps in milliseconds and
+//! byte strings are prefixed by their length (`u32`). Collections are prefixed by their
+//! number of elements (`u32`).
+//!
+//! [Egg]: crate::core::egg::Egg
+//! [Nest]: crate::core::nest::Nest
+
+use crate::core::{Egg, Nest, SortedSet, Value};
+use crate::errors::Result;
+use chrono::prelude::{DateTime, TimeZone, Utc};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::convert::TryInto;
+use std::fs;
+use std::path::Path;
+
+/// Magic bytes starting every snapshot file.
+const MAGIC: &[u8] = b"SPARROW";
+/// Version of the snapshot format.
+const VERSION: u16 = 2;
+/// Type byte of an entry storing a string value.
+const STRING_VALUE: u8 = 0;
+/// Type byte of an entry storing a hash value.
+const HASH_VALUE: u8 = 1;
+/// Type byte of an entry storing a list value.
+const LIST_VALUE: u8 = 2;
+/// Type byte of an entry storing a set value.
+const SET_VALUE: u8 = 3;
+/// Type byte of an entry storing a sorted set value.
+const SORTED_SET_VALUE: u8 = 4;
+/// Byte assigning the following entries to a database.
+const SELECT_DB: u8 = 0xFE;
+/// Byte marking the end of the entries.
+const END_OF_EGGS: u8 = 0xFF;
+
+/// Write a snapshot of numbered [Nest]s into a file.
+///
+/// The snapshot is written to a temporary file that is renamed once complete,
+/// so that an existing snapshot is never left half-written.
+/// Expired eggs and empty databases are skipped. Return the number of saved eggs.
+///
+/// # Arguments
+/// * `nests` - [Nest]s to save, indexed by database
+/// * `filepath` - Path of the snapshot file
+pub fn save(nests: &[Nest], filepath: impl AsRef<Path>) -> Result<usize> {
+  let filepath = filepath.as_ref();
+  let now = Utc::now();
+
+  let mut bytes = Vec::new();
+  bytes.extend_from_slice(MAGIC);
+  bytes.extend_from_slice(&VERSION.to_le_bytes());
+  let mut count = 0;
+  for (db, nest) in nests.iter().enumerate() {
+    let mut eggs = nest.iter().filter(|egg| !egg.is_expired(&now)).peekable();
+    if eggs.peek().is_none() {
+      continue;
+    }
+    bytes.push(SELECT_DB);
+    write_len(&mut bytes, db)?;
+    for egg in eggs {
+      write_egg(&mut bytes, egg)?;
+      count += 1;
+    }
+  }
+  bytes.push(END_OF_EGGS);
+  let checksum = crc32fast::hash(&bytes);
+  bytes.extend_from_slice(&checksum.to_le_bytes());
+
+  let tmp_filepath = filepath.with_extension("tmp");
+  fs::write(&tmp_filepath, &bytes)?;
+  fs::rename(&tmp_filepath, filepath)?;
+  Ok(count)
+}
+
+/// Append the entry of an [Egg].
+fn write_egg(bytes: &mut Vec<u8>, egg: &Egg) -> Result<()> {
+  bytes.push(match egg.value() {
+    Value::String(_) => STRING_VALUE,
+    Value::Hash(_) => HASH_VALUE,
+    Value::List(_) => LIST_VALUE,
+    Value::Set(_) => SET_VALUE,
+    Value::SortedSet(_) => SORTED_SET_VALUE,
+  });
+  write_bytes(bytes, egg.key().as_bytes())?;
+  bytes.extend_from_slice(&egg.created_at().timestamp_millis().to_le_bytes());
+  match egg.expires_at() {
+    Some(expires_at) => {
+      bytes.push(1);
+      bytes.extend_from_slice(&expires_at.timestamp_millis().to_le_bytes());
+    }
+    None => bytes.push(0),
+  }
+  match egg.value() {
+    Value::String(value) => write_bytes(bytes, value)?,
+    Value::Hash(hash) => {
+      write_len(bytes, hash.len())?;
+      for (field, value) in hash {
+        write_bytes(bytes, field)?;
+        write_bytes(bytes, value)?;
+      }
+    }
+    Value::List(list) => {
+      write_len(bytes, list.len())?;
+      for element in list {
+        write_bytes(bytes, element)?;
+      }
+    }
+    Value::Set(set) => {
+      write_len(bytes, set.len())?;
+      for member in set {
+        write_bytes(bytes, member)?;
+      }
+    }
+    Value::SortedSet(sorted_set) => {
+      write_len(bytes, sorted_set.len())?;
+      for (member, score) in sorted_set.iter() {
+        write_bytes(bytes, member)?;
+        bytes.extend_from_slice(&score.to_le_bytes());
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Load numbered [Nest]s from a snapshot file.
+///
+/// Return [None] if the file does not exist. The whole file is checked before
+/// building the [Nest]s so that a corrupted snapshot is never partially loaded.
+/// Eggs that expired since the snapshot was written are skipped.
+///
+/// # Arguments
+/// * `filepath` - Path of the snapshot file
+/// * `databases` - Number of [Nest]s to return, which must cover every database of the file
+pub fn load(filepath: impl AsRef<Path>, databases: usize) -> Result<Option<Vec<Nest>>> {
+  let filepath = filepath.as_ref();
+  if !filepath.exists() {
+    return Ok(None);
+  }
+  let bytes = fs::read(filepath)?;
+
+  // Check header and checksum before reading eggs
+  if bytes.len() < MAGIC.len() + 2 + 1 + 4 || !bytes.starts_with(MAGIC) {
+    return Err(
+      format!(
+        "Invalid snapshot file {}: missing header",
+        filepath.display()
+      )
+      .into(),
+    );
+  }
+  let (content, checksum) = bytes.split_at(bytes.len() - 4);
+  if crc32fast::hash(content).to_le_bytes() != checksum {
+    return Err(
+      format!(
+        "Corrupted snapshot file {}: invalid checksum",
+        filepath.display()
+      )
+      .into(),
+    );
+  }
+
+  let mut reader = Reader {
+    bytes: content,
+    position: MAGIC.len(),
+  };
+  let version = u16::from_le_bytes(reader.read_array()?);
+  if version == 0 || version > VERSION {
+    return Err(
+      format!(
+        "Unsupported snapshot file {}: version {}, expected 1 to {}",
+        filepath.display(),
+        version,
+        VERSION
+      )
+      .into(),
+    );
+  }
+
+  let now = Utc::now();
+  let mut nests: Vec<Nest> = (0..databases).map(|_| Nest::new()).collect();
+  let mut db = 0;
+  loop {
+    let value_type = match reader.read_u8()? {
+      END_OF_EGGS => break,
+      SELECT_DB => {
+        db = reader.read_len()?;
+        if db >= databases {
+          return Err(
+            format!(
+              "Cannot load snapshot file {}: database {} is out of range, only {} configured",
+              filepath.display(),
+              db,
+              databases
+            )
+            .into(),
+          );
+        }
+        continue;
+      }
+      value_type @ (STRING_VALUE | HASH_VALUE | LIST_VALUE | SET_VALUE | SORTED_SET_VALUE) => {
+        value_type
+      }
+      unknown => return Err(format!("Corrupted snapshot: unknown value type {}", unknown).into()),
+    };
+    let key = String::from_utf8(reader.read_bytes()?.to_vec())?;
+    let created_at = reader.read_date()?;
+    let expires_at = match reader.read_u8()? {
+      0 => None,
+      _ => Some(reader.read_date()?),
+    };
+    let value = match value_type {
+      STRING_VALUE => Value::String(reader.read_bytes()?.to_vec()),
+      HASH_VALUE => {
+        let len = reader.read_len()?;
+        let mut hash = HashMap::with_capacity(len);
+        for _ in 0..len {
+          let field = reader.read_bytes()?.to_vec();
+          hash.insert(field, reader.read_bytes()?.to_vec());
+        }
+        Value::Hash(hash)
+      }
+      LIST_VALUE => {
+        let len = reader.read_len()?;
+        let mut list = VecDeque::with_capacity(len);
+        for _ in 0..len {
+          list.push_back(reader.read_bytes()?.to_vec());
+        }
+        Value::List(list)
+      }
+      SET_VALUE => {
+        let len = reader.read_len()?;
+        let mut set = HashSet::with_capacity(len);
+        for _ in 0..len {
+          set.insert(reader.read_bytes()?.to_vec());
+        }
+        Value::Set(set)
+      }
+      _ => {
+        let len = reader.read_len()?;
+        let mut sorted_set = SortedSet::new();
+        for _ in 0..len {
+          let member = reader.read_bytes()?.to_vec();
+          let score = f64::from_le_bytes(reader.read_array()?);
+          if score.is_nan() {
+            return Err("Corrupted snapshot: invalid score".into());
+          }
+          sorted_set.insert(member, score);
+        }
+        Value::SortedSet(sorted_set)
+      }
+    };
+    let egg = Egg::restore(key, value, created_at, expires_at);
+    if !egg.is_expired(&now) {
+      nests[db].set(egg);
+    }
+  }
+  Ok(Some(nests))
+}
+
+/// Append length-prefixed bytes.
+fn write_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) -> Result<()> {
+  write_len(buffer, bytes.len())?;
+  buffer.extend_from_slice(bytes);
+  Ok(())
+}
+
+/// Append a length or a number of elements.
+fn write_len(buffer: &mut Vec<u8>, len: usize) -> Result<()> {
+  let len: u32 = len
+    .try_into()
+    .map_err(|_| "Cannot write snapshot: value is too large")?;
+  buffer.extend_from_slice(&len.to_le_bytes());
+  Ok(())
+}
+
+/// Cursor reading a snapshot content.
+struct Reader<'a> {
+  bytes: &'a [u8],
+  position: usize,
+}
+
+impl<'a> Reader<'a> {
+  /// Read the given number of bytes.
+  fn read(&mut self, len: usize) -> Result<&'a [u8]> {
+    let end = self
+      .position
+      .checked_add(len)
+      .filter(|end| *end <= self.bytes.len())
+      .ok_or("Corrupted snapshot: unexpected end of file")?;
+    let bytes = &self.bytes[self.position..end];
+    self.position = end;
+    Ok(bytes)
+  }
+  /// Read a fixed-size array of bytes.
+  fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+    Ok(self.read(N)?.try_into()?)
+  }
+  fn read_u8(&mut self) -> Result<u8> {
+    Ok(self.read(1)?[0])
+  }
+  /// Read length-prefixed bytes.
+  fn read_bytes(&mut self) -> Result<&'a [u8]> {
+    let len = self.read_len()?;
+    self.read(len)
+  }
+  /// Read a length or a number of elements.
+  fn read_len(&mut self) -> Result<usize> {
+    Ok(u32::from_le_bytes(self.read_array()?) as usize)
+  }
+  /// Read a unix timestamp in milliseconds.
+  fn read_date(&mut self) -> Result<DateTime<Utc>> {
+    let timestamp = i64::from_le_bytes(self.read_array()?);
+    Utc
+      .timestamp_millis_opt(timestamp)
+      .single()
+      .ok_or_else(|| format!("Corrupted snapshot: invalid date {}", timestamp).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::{Egg, Nest, SortedSet};
+  use crate::persistence::snapshot::{load, save};
+  use chrono::{Duration, Utc};
+  use std::collections::{HashMap, HashSet, VecDeque};
+  use std::env;
+  use std::fs;
+
+  const TEST_VALUE: &[u8] = &[0x00, 0xff, b'v', b'a', b'l'];
+
+  #[test]
+  fn test_save_and_load() {
+    let filepath = env::temp_dir().join("sparrow_test_save_and_load.snapshot");
+    let now = Utc::now();
+
+    let mut nest = Nest::new();
+    nest.set(Egg::new("persistent", TEST_VALUE));
+    let mut volatile = Egg::new("volatile", TEST_VALUE);
+    volatile.set_expires_at(Some(now + Duration::seconds(60)));
+    nest.set(volatile);
+    let mut expired = Egg::new("expired", TEST_VALUE);
+    expired.set_expires_at(Some(now - Duration::seconds(60)));
+    nest.set(expired);
+
+    assert_eq!(save(&[nest.clone()], &filepath).unwrap(), 2);
+
+    let mut loaded = load(&filepath, 1).unwrap().unwrap().remove(0);
+    assert_eq!(loaded.len(), 2);
+    let persistent = nest.get("persistent").unwrap().clone();
+    let restored = loaded.get("persistent").unwrap();
+    assert_eq!(restored, &persistent);
+    assert_eq!(
+      restored.created_at().timestamp_millis(),
+      persistent.created_at().timestamp_millis()
+    );
+    assert_eq!(restored.expires_at(), None);
+    assert_eq!(
+      loaded
+        .get("volatile")
+        .unwrap()
+        .expires_at()
+        .unwrap()
+        .timestamp_millis(),
+      (now + Duration::seconds(60)).timestamp_millis()
+    );
+    assert!(loaded.get("expired").is_none());
+
+    fs::remove_file(&filepath).unwrap();
+  }
+
+  #[test]
+  fn test_save_and_load_types() {
+    let filepath = env::temp_dir().join("sparrow_test_save_and_load_types.snapshot");
+
+    let mut hash = HashMap::new();
+    hash.insert(b"field".to_vec(), TEST_VALUE.to_vec());
+    hash.insert(TEST_VALUE.to_vec(), b"value".to_vec());
+    let mut nest = Nest::new();
+    nest.set(Egg::new("hash", hash));
+    nest.set(Egg::new("empty", &b""[..]));
+    let list: VecDeque<Vec<u8>> = vec![TEST_VALUE.to_vec(), b"b".to_vec()].into();
+    nest.set(Egg::new("list", list));
+    let set: HashSet<Vec<u8>> = vec![TEST_VALUE.to_vec(), b"b".to_vec()]
+      .into_iter()
+      .collect();
+    nest.set(Egg::new("set", set));
+    let mut sorted_set = SortedSet::new();
+    sorted_set.insert(TEST_VALUE.to_vec(), -1.5);
+    sorted_set.insert(b"b".to_vec(), f64::INFINITY);
+    nest.set(Egg::new("sorted set", sorted_set));
+
+    assert_eq!(save(&[nest.clone()], &filepath).unwrap(), 5);
+
+    let mut loaded = load(&filepath, 1).unwrap().unwrap().remove(0);
+    for key in ["hash", "empty", "list", "set", "sorted set"] {
+      assert_eq!(loaded.get(key), nest.get(key).cloned().as_ref());
+    }
+
+    fs::remove_file(&filepath).unwrap();
+  }
+
+  #[test]
+  fn test_load_missing_file() {
+    let filepath = env::temp_dir().join("sparrow_test_load_missing_file.snapshot");
+    assert!(load(&filepath, 1).unwrap().is_none());
+  }
+
+  #[test]
+  fn test_save_and_load_databases() {
+    let filepath = env::temp_dir().join("sparrow_test_save_and_load_databases.snapshot");
+    let mut nests = vec![Nest::new(), Nest::new(), Nest::new()];
+    nests[0].set(Egg::new("key", &b"first"[..]));
+    nests[2].set(Egg::new("key", &b"third"[..]));
+    nests[2].set(Egg::new("other", TEST_VALUE));
+    assert_eq!(save(&nests, &filepath).unwrap(), 3);
+
+    // Databases are loaded at their index
+    let mut loaded = load(&filepath, 4).unwrap().unwrap();
+    assert_eq!(loaded.len(), 4);
+    assert_eq!(
+      loaded.iter().map(Nest::len).collect::<Vec<_>>(),
+      vec![1, 0, 2, 0]
+    );
+    assert_eq!(loaded[2].get("key"), nests[2].get("key").cloned().as_ref());
+
+    // Every database of the file must be configured
+    let err = load(&filepath, 2).err().unwrap();
+    assert!(format!("{}", err).contains("database 2 is out of range"));
+
+    fs::remove_file(&filepath).unwrap();
+  }
+
+  #[test]
+  fn test_load_version_1() {
+    let filepath = env::temp_dir().join("sparrow_test_load_version_1.snapshot");
+
+    // Version 1 has no database selection, its eggs belong to the first database
+    let mut bytes = b"SPARROW".to_vec();
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(&3u32.to_le_bytes());
+    bytes.extend_from_slice(b"key");
+    bytes.extend_from_slice(&Utc::now().timestamp_millis().to_le_bytes());
+    bytes.push(0);
+    bytes.extend_from_slice(&(TEST_VALUE.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(TEST_VALUE);
+    bytes.push(0xFF);
+    let checksum = crc32fast::hash(&bytes);
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    fs::write(&filepath, &bytes).unwrap();
+
+    let mut loaded = load(&filepath, 2).unwrap().unwrap();
+    assert_eq!(
+      loaded[0].get("key").unwrap().value().as_string().unwrap(),
+      TEST_VALUE
+    );
+    assert_eq!(loaded[1].len(), 0);
+
+    fs::remove_file(&filepath).unwrap();
+  }
+
+  #[test]
+  fn test_load_corrupted() {
+    let filepath = env::temp_dir().join("sparrow_test_load_corrupted.snapshot");
+    let mut nest = Nest::new();
+    nest.set(Egg::new("key", TEST_VALUE));
+    save(&[nest], &filepath).unwrap();
+
+    // Flip a byte of the value
+    let mut bytes = fs::read(&filepath).unwrap();
+    let index = bytes.len() - 6;
+    bytes[index] ^= 0xFF;
+    fs::write(&filepath, &bytes).unwrap();
+    let err = load(&filepath, 1).err().unwrap();
+    assert!(format!("{}", err).contains("invalid checksum"));
+
+    // Truncated file
+    fs::write(&filepath, &bytes[..bytes.len() / 2]).unwrap();
+    assert!(load(&filepath, 1).is_err());
+
+    // Not a snapshot
+    fs::write(&filepath, b"not a snapshot file").unwrap();
+    let err = load(&filepath, 1).err().unwrap();
+    assert!(format!("{}", err).contains("missing header"));
+
+    fs::remove_file(&filepath).unwrap();
+  }
+}
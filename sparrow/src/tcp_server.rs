@@ -1,29 +1,62 @@
 //! TCP socket server.
-use crate::core::EngineInput;
+use crate::core::{EngineInput, Session};
 use crate::errors::Result;
 use crate::logger::BACKSPACE_CHARACTER;
-use async_std::channel::{unbounded, Sender};
-use async_std::io::{BufReader, BufWriter};
-use async_std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use async_std::channel::{unbounded, Receiver, Sender};
+use async_std::io::{BufReader, BufWriter, Read};
+use async_std::net::{TcpListener, TcpStream};
 use async_std::prelude::*;
 use async_std::task;
+use futures::future::{select, try_join_all, Either};
+use futures::{pin_mut, AsyncBufReadExt};
 use sparrow_resp::{decode, encode, Data};
+use std::collections::VecDeque;
 use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 /// Run Sparrow TCP socket server.
 ///
 /// This function is blocking and runs [accept_loop] and [connection_loop] with [async_std]
-/// asynchronous backend.Result
-pub async fn run_tcp_server(port: u16, engine_sender: Sender<EngineInput>) -> Result<()> {
-  accept_loop(format!("127.0.0.1:{}", port), engine_sender).await
+/// asynchronous backend. One [accept_loop] runs for every address and they share the same
+/// connection limit.
+///
+/// # Arguments
+/// * `addresses` - Socket addresses to listen on
+/// * `max_connections` - Maximum number of simultaneous client connections
+/// * `engine_sender` - Sender used to submit inputs to the engine
+pub async fn run_tcp_server(
+  addresses: Vec<SocketAddr>,
+  max_connections: usize,
+  engine_sender: Sender<EngineInput>,
+) -> Result<()> {
+  let slots = Arc::new(ConnectionSlots::new(max_connections));
+  let mut listeners = Vec::with_capacity(addresses.len());
+  for address in addresses {
+    let listener = TcpListener::bind(address)
+      .await
+      .map_err(|err| format!("Cannot bind TCP server to {}: {}", address, err))?;
+    listeners.push(listener);
+  }
+  try_join_all(
+    listeners
+      .into_iter()
+      .map(|listener| accept_loop(listener, slots.clone(), engine_sender.clone())),
+  )
+  .await
+  .map(|_| ())
 }
 
 /// Run tcp socket accept loop.
 ///
 /// An [async-std] async task is spawned for every new connection.
-async fn accept_loop(addr: impl ToSocketAddrs, engine_sender: Sender<EngineInput>) -> Result<()> {
-  let listener = TcpListener::bind(addr).await?;
+/// Connections exceeding the limit receive a [Data::Error] and are closed.
+async fn accept_loop(
+  listener: TcpListener,
+  slots: Arc<ConnectionSlots>,
+  engine_sender: Sender<EngineInput>,
+) -> Result<()> {
   log::info!(
     "TCP server is ready to accept connections at {}",
     listener.local_addr()?
@@ -32,57 +65,410 @@ async fn accept_loop(addr: impl ToSocketAddrs, engine_sender: Sender<EngineInput
   let mut incoming = listener.incoming();
   while let Some(stream) = incoming.next().await {
     let stream = stream?;
-    log::info!(
-      "{}[{}] Accepted connection",
-      BACKSPACE_CHARACTER,
-      stream.peer_addr()?
-    );
+    let peer_addr = stream.peer_addr()?;
+    let slot = match slots.acquire() {
+      Some(slot) => slot,
+      None => {
+        log::warn!(
+          "{}[{}] Rejected connection: max number of clients reached",
+          BACKSPACE_CHARACTER,
+          peer_addr
+        );
+        task::spawn(async move {
+          if let Err(err) = reject_connection(stream).await {
+            log::error!("{}", err);
+          }
+        });
+        continue;
+      }
+    };
+    log::info!("{}[{}] Accepted connection", BACKSPACE_CHARACTER, peer_addr);
     let engine_sender = engine_sender.clone();
     task::spawn(async move {
       if let Err(err) = connection_loop(stream, engine_sender).await {
         log::error!("{}", err);
       }
+      // The slot is released once the connection is closed
+      drop(slot);
     });
   }
   Ok(())
 }
 
+/// Reply to a connection exceeding the limit with a [Data::Error] then close it.
+async fn reject_connection(stream: TcpStream) -> Result<()> {
+  let mut writer = BufWriter::new(&stream);
+  let error = Data::Error("Max number of clients reached".to_string());
+  encode(&error, &mut writer).await?;
+  writer.flush().await?;
+  Ok(())
+}
+
+/// Counter of open connections shared by the accept loops.
+struct ConnectionSlots {
+  open: AtomicUsize,
+  max: usize,
+}
+
+impl ConnectionSlots {
+  /// Return new [ConnectionSlots] allowing a given number of open connections.
+  fn new(max: usize) -> ConnectionSlots {
+    ConnectionSlots {
+      open: AtomicUsize::new(0),
+      max,
+    }
+  }
+
+  /// Reserve a slot for a new connection, [None] if the limit is reached.
+  fn acquire(self: &Arc<Self>) -> Option<ConnectionSlot> {
+    self
+      .open
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |open| {
+        (open < self.max).then(|| open + 1)
+      })
+      .ok()
+      .map(|_| ConnectionSlot(self.clone()))
+  }
+}
+
+/// Slot held by an open connection, released when dropped.
+struct ConnectionSlot(Arc<ConnectionSlots>);
+
+impl Drop for ConnectionSlot {
+  fn drop(&mut self) {
+    self.0.open.fetch_sub(1, Ordering::SeqCst);
+  }
+}
+
 /// Handle a [TcpStream] connection.
 ///
 /// The stream is wrapped into a [BufReader] that is decoded into a [Data] using Sparrow-RESP [decode] function.
+///
+/// Requests are pipelined: every complete frame already buffered is submitted to the engine in order,
+/// then all the replies are written in the same order before a single flush.
+///
+/// The socket is watched while waiting for the engine, so that a client disconnecting while it is
+/// blocked by a blocking command closes the connection and is dropped by the engine.
 async fn connection_loop(stream: TcpStream, engine_sender: Sender<EngineInput>) -> Result<()> {
   let id = stream.peer_addr()?.to_string();
+  let session = Session::new(&id);
   let (sender, receiver) = unbounded();
 
   let stream = Arc::new(stream);
   let mut reader = BufReader::new(&*stream);
   let mut writer = BufWriter::new(&*stream);
+  // Replies in the requests order, those of requests that failed to decode are known before
+  // reaching the engine
+  let mut replies = VecDeque::new();
   loop {
-    // Output will be sent through the writer
-    let output = match decode(&mut reader).await {
+    let mut disconnected = !submit_requests(
+      &id,
+      &mut reader,
+      &session,
+      &sender,
+      &engine_sender,
+      &mut replies,
+    )
+    .await?;
+
+    // The engine processes inputs in order so outputs are received in the requests order
+    while let Some(reply) = replies.pop_front() {
+      let output = match reply {
+        Some(output) => output,
+        None if disconnected => receiver.recv().await?,
+        None => match wait_output(&receiver, &mut reader).await? {
+          Wakeup::Output(output) => output,
+          Wakeup::Readable => {
+            replies.push_front(None);
+            disconnected = !submit_requests(
+              &id,
+              &mut reader,
+              &session,
+              &sender,
+              &engine_sender,
+              &mut replies,
+            )
+            .await?;
+            continue;
+          }
+          Wakeup::Closed => {
+            log::info!("{}[{}] Client disconnected", BACKSPACE_CHARACTER, id);
+            return Ok(());
+          }
+        },
+      };
+      log::info!("{}[{}] {:?}", BACKSPACE_CHARACTER, id, output);
+      encode(&output, &mut writer).await?;
+    }
+    writer.flush().await?;
+
+    if disconnected {
+      log::info!("{}[{}] Client disconnected", BACKSPACE_CHARACTER, id);
+      break;
+    }
+  }
+  Ok(())
+}
+
+/// Submit the requests available on a connection to the engine.
+///
+/// A [None] reply is queued for every submitted request, the reply of a request that failed to
+/// decode is queued directly. Return `false` if the client disconnected.
+async fn submit_requests<R: Read + Unpin + Send>(
+  id: &str,
+  reader: &mut BufReader<R>,
+  session: &Session,
+  sender: &Sender<Data>,
+  engine_sender: &Sender<EngineInput>,
+  replies: &mut VecDeque<Option<Data>>,
+) -> Result<bool> {
+  for request in read_requests(reader).await {
+    match request {
       Ok(input) => {
-        let id = id.clone();
         log::info!("{}[{}] {:?}", BACKSPACE_CHARACTER, id, input);
-        let sender = sender.clone();
-        let input = EngineInput::new(id, input, sender);
+        let input = EngineInput::new(session.clone(), input, sender.clone());
         engine_sender.send(input).await?;
-        let output = receiver.recv().await?;
-        output
+        replies.push_back(None);
       }
       Err(err) => match err.kind() {
-        ErrorKind::BrokenPipe => {
-          log::info!("{}[{}] Client disconnected", BACKSPACE_CHARACTER, id);
-          break;
-        }
+        ErrorKind::BrokenPipe => return Ok(false),
         _ => {
           log::error!("{}[{}] {}", BACKSPACE_CHARACTER, id, err);
-          Data::Error(format!("{}", err))
+          replies.push_back(Some(Data::Error(format!("{}", err))));
         }
       },
-    };
-    log::info!("{}[{}] {:?}", BACKSPACE_CHARACTER, id, output);
-    encode(&output, &mut writer).await?;
-    writer.flush().await?;
+    }
+  }
+  Ok(true)
+}
+
+/// Event ending the wait for an output of the engine.
+enum Wakeup {
+  /// The engine sent an output.
+  Output(Data),
+  /// The client sent data, which is buffered but not consumed.
+  Readable,
+  /// The client disconnected.
+  Closed,
+}
+
+/// Wait for the next output of the engine, unless the client sends data or disconnects first.
+async fn wait_output<R: Read + Unpin + Send>(
+  receiver: &Receiver<Data>,
+  reader: &mut BufReader<R>,
+) -> Result<Wakeup> {
+  let output = async { Ok(Wakeup::Output(receiver.recv().await?)) };
+  // Filling the buffer does not consume it, so no request is lost if the output comes first
+  let readable = async {
+    Ok(match reader.fill_buf().await?.is_empty() {
+      true => Wakeup::Closed,
+      false => Wakeup::Readable,
+    })
+  };
+  pin_mut!(output, readable);
+  match select(output, readable).await {
+    Either::Left((wakeup, _)) | Either::Right((wakeup, _)) => wakeup,
+  }
+}
+
+/// Decode the requests available on a connection.
+///
+/// Wait for a first request, then decode every request already buffered.
+/// Decoding stops after the first error, which is returned as the last element.
+async fn read_requests<R: Read + Unpin + Send>(
+  reader: &mut BufReader<R>,
+) -> Vec<std::io::Result<Data>> {
+  let mut requests = vec![decode(reader).await];
+  while requests.last().is_some_and(|request| request.is_ok()) && !reader.buffer().is_empty() {
+    requests.push(decode(reader).await);
+  }
+  requests
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::core::Engine;
+  use crate::tcp_server::{accept_loop, connection_loop, ConnectionSlots};
+  use async_std::io::{BufReader, BufWriter};
+  use async_std::net::{TcpListener, TcpStream};
+  use async_std::prelude::*;
+  use async_std::task;
+  use sparrow_client::{Connection, Pool};
+  use sparrow_resp::{decode, encode, Data};
+  use std::sync::atomic::Ordering;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  fn command(inputs: &[&str]) -> Data {
+    Data::Array(
+      inputs
+        .iter()
+        .map(|input| Data::BulkString(input.as_bytes().to_vec()))
+        .collect(),
+    )
+  }
+
+  #[async_std::test]
+  async fn test_connection_loop_pipelining() {
+    let mut engine = Engine::new();
+    let engine_sender = engine.init();
+    task::spawn(async move { engine.run().await });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    task::spawn(async move {
+      let (stream, _) = listener.accept().await.unwrap();
+      connection_loop(stream, engine_sender).await.unwrap();
+    });
+
+    let stream = TcpStream::connect(address).await.unwrap();
+    let mut reader = BufReader::new(stream.clone());
+    let mut writer = BufWriter::new(stream);
+
+    // Every request is written before a single flush
+    for i in 0..1000 {
+      let key = format!("key{}", i);
+      let value = format!("value{}", i);
+      encode(&command(&["SET", &key, &value]), &mut writer)
+        .await
+        .unwrap();
+      encode(&command(&["GET", &key]), &mut writer).await.unwrap();
+    }
+    encode(&command(&["TOTO"]), &mut writer).await.unwrap();
+    writer.flush().await.unwrap();
+
+    for i in 0..1000 {
+      assert_eq!(
+        decode(&mut reader).await.unwrap(),
+        Data::SimpleString("OK".to_string())
+      );
+      assert_eq!(
+        decode(&mut reader).await.unwrap(),
+        Data::BulkString(format!("value{}", i).into_bytes())
+      );
+    }
+    assert_eq!(
+      decode(&mut reader).await.unwrap(),
+      Data::Error("Command not found: TOTO".to_string())
+    );
+  }
+
+  #[async_std::test]
+  async fn test_pool_session_state() {
+    let mut engine = Engine::new();
+    let engine_sender = engine.init();
+    task::spawn(async move { engine.run().await });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let slots = Arc::new(ConnectionSlots::new(16));
+    task::spawn(async move { accept_loop(listener, slots, engine_sender).await });
+    let pool = Pool::new(&address, 1);
+
+    // Connections without state on the server are reused
+    pool.get().await.unwrap().set("key", "db 0").await.unwrap();
+    assert_eq!(pool.idle_connections(), 1);
+
+    // Connections with a selected database, an open transaction or watched keys are not
+    let mut other = Connection::connect(&address).await.unwrap();
+    for commands in [
+      &[&["SELECT", "1"][..], &["SET", "key", "modified"]][..],
+      &[&["multi"], &["SET", "key", "modified"]],
+      &[&["WATCH", "key"]],
+    ] {
+      let mut connection = pool.get().await.unwrap();
+      for inputs in commands {
+        connection.request(*inputs).await.unwrap();
+      }
+      drop(connection);
+      assert_eq!(pool.idle_connections(), 0);
+      other.set("key", "db 0").await.unwrap();
+
+      let mut connection = pool.get().await.unwrap();
+      assert!(!connection.has_session_state());
+      assert_eq!(connection.get("key").await.unwrap(), Some(b"db 0".to_vec()));
+      for inputs in [&["MULTI"][..], &["SET", "key", "db 0"]] {
+        connection.request(inputs).await.unwrap();
+      }
+      assert!(matches!(
+        connection.request(["EXEC"]).await.unwrap(),
+        Data::Array(_)
+      ));
+    }
+  }
+
+  #[async_std::test]
+  async fn test_connection_loop_blocked_client_disconnect() {
+    let mut engine = Engine::new();
+    let engine_sender = engine.init();
+    task::spawn(async move { engine.run().await });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let slots = Arc::new(ConnectionSlots::new(2));
+    let accept_slots = slots.clone();
+    task::spawn(async move { accept_loop(listener, accept_slots, engine_sender).await });
+
+    let blocked = TcpStream::connect(&address).await.unwrap();
+    let mut writer = BufWriter::new(&blocked);
+    encode(&command(&["BLPOP", "list", "0"]), &mut writer)
+      .await
+      .unwrap();
+    writer.flush().await.unwrap();
+    drop(writer);
+    task::sleep(Duration::from_millis(50)).await;
+    drop(blocked);
+    task::sleep(Duration::from_millis(50)).await;
+
+    // The connection of the disconnected client is closed and the pushed element is not popped
+    assert_eq!(slots.open.load(Ordering::SeqCst), 0);
+    let mut connection = Connection::connect(&address).await.unwrap();
+    connection.request(["RPUSH", "list", "a"]).await.unwrap();
+    assert_eq!(
+      connection
+        .request(["LRANGE", "list", "0", "-1"])
+        .await
+        .unwrap(),
+      Data::Array(vec![Data::BulkString(b"a".to_vec())])
+    );
+  }
+
+  #[test]
+  fn test_connection_slots() {
+    let slots = Arc::new(ConnectionSlots::new(2));
+    let first = slots.acquire().unwrap();
+    let _second = slots.acquire().unwrap();
+    assert!(slots.acquire().is_none());
+
+    drop(first);
+    assert!(slots.acquire().is_some());
+  }
+
+  #[async_std::test]
+  async fn test_accept_loop_max_connections() {
+    let mut engine = Engine::new();
+    let engine_sender = engine.init();
+    task::spawn(async move { engine.run().await });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let slots = Arc::new(ConnectionSlots::new(1));
+    task::spawn(async move { accept_loop(listener, slots, engine_sender).await });
+
+    let first = TcpStream::connect(address).await.unwrap();
+    let mut first_reader = BufReader::new(first.clone());
+    let mut first_writer = BufWriter::new(first);
+    encode(&command(&["GET", "key"]), &mut first_writer)
+      .await
+      .unwrap();
+    first_writer.flush().await.unwrap();
+    assert_eq!(decode(&mut first_reader).await.unwrap(), Data::Null);
+
+    let second = TcpStream::connect(address).await.unwrap();
+    let mut second_reader = BufReader::new(second);
+    assert_eq!(
+      decode(&mut second_reader).await.unwrap(),
+      Data::Error("Max number of clients reached".to_string())
+    );
   }
-  Ok(())
 }